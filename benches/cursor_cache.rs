@@ -0,0 +1,82 @@
+//! Measures loading the cursor images with `Window::preload_cursors`.
+//!
+//! The first pass loads every icon from the cursor theme, the later ones only hit the cache,
+//! including for the icons the theme doesn't provide. Run it from a graphical session with:
+//!
+//! ```sh
+//! cargo bench --bench cursor_cache
+//! ```
+//!
+//! On Wayland compositors supporting the cursor shape protocol, winit doesn't load the icons the
+//! compositor draws itself, so both passes do next to nothing there.
+
+use std::time::{Duration, Instant};
+
+use winit::event_loop::EventLoop;
+use winit::window::{CursorIcon, WindowBuilder};
+
+/// The number of passes over the icons once they are cached.
+const PASSES: u32 = 1000;
+
+fn main() {
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_visible(false)
+        .build(&event_loop)
+        .unwrap();
+
+    let start = Instant::now();
+    window.preload_cursors(CURSORS);
+    let cold = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..PASSES {
+        window.preload_cursors(CURSORS);
+    }
+    let cached = start.elapsed() / PASSES;
+
+    println!("{} cursor icons", CURSORS.len());
+    println!("first pass:  {:>10.1} us/icon", per_icon(cold));
+    println!("cached pass: {:>10.3} us/icon", per_icon(cached));
+}
+
+fn per_icon(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1e6 / CURSORS.len() as f64
+}
+
+const CURSORS: &[CursorIcon] = &[
+    CursorIcon::Default,
+    CursorIcon::ContextMenu,
+    CursorIcon::Help,
+    CursorIcon::Pointer,
+    CursorIcon::Progress,
+    CursorIcon::Wait,
+    CursorIcon::Cell,
+    CursorIcon::Crosshair,
+    CursorIcon::Text,
+    CursorIcon::VerticalText,
+    CursorIcon::Alias,
+    CursorIcon::Copy,
+    CursorIcon::Move,
+    CursorIcon::NoDrop,
+    CursorIcon::NotAllowed,
+    CursorIcon::Grab,
+    CursorIcon::Grabbing,
+    CursorIcon::EResize,
+    CursorIcon::NResize,
+    CursorIcon::NeResize,
+    CursorIcon::NwResize,
+    CursorIcon::SResize,
+    CursorIcon::SeResize,
+    CursorIcon::SwResize,
+    CursorIcon::WResize,
+    CursorIcon::EwResize,
+    CursorIcon::NsResize,
+    CursorIcon::NeswResize,
+    CursorIcon::NwseResize,
+    CursorIcon::ColResize,
+    CursorIcon::RowResize,
+    CursorIcon::AllScroll,
+    CursorIcon::ZoomIn,
+    CursorIcon::ZoomOut,
+];
@@ -74,7 +74,7 @@ fn main() -> Result<(), impl std::error::Error> {
                 }
                 _ => (),
             }
-        } else if let Event::RedrawRequested(wid) = event {
+        } else if let Event::RedrawRequested { window_id: wid, .. } = event {
             if let Some(window) = windows.get(&wid) {
                 fill::fill_window(window);
             }
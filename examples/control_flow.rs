@@ -117,7 +117,7 @@ fn main() -> Result<(), impl std::error::Error> {
                     control_flow.set_exit();
                 }
             }
-            Event::RedrawRequested(_window_id) => {
+            Event::RedrawRequested { .. } => {
                 fill::fill_window(&window);
             }
             _ => (),
@@ -49,7 +49,7 @@ fn main() -> Result<(), impl std::error::Error> {
             } => {
                 control_flow.set_exit();
             }
-            Event::RedrawRequested(_) => {
+            Event::RedrawRequested { .. } => {
                 fill::fill_window(&window);
             }
             _ => (),
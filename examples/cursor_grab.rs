@@ -70,7 +70,7 @@ fn main() -> Result<(), impl std::error::Error> {
                 },
                 _ => (),
             },
-            Event::RedrawRequested(_) => fill::fill_window(&window),
+            Event::RedrawRequested { .. } => fill::fill_window(&window),
             _ => (),
         }
     })
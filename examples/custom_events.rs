@@ -47,7 +47,7 @@ fn main() -> Result<(), impl std::error::Error> {
                 event: WindowEvent::CloseRequested,
                 ..
             } => control_flow.set_exit(),
-            Event::RedrawRequested(_) => {
+            Event::RedrawRequested { .. } => {
                 fill::fill_window(&window);
             }
             _ => (),
@@ -61,7 +61,7 @@ fn main() -> Result<(), impl std::error::Error> {
             }
             _ => (),
         },
-        Event::RedrawRequested(wid) => {
+        Event::RedrawRequested { window_id: wid, .. } => {
             if wid == window_1.id() {
                 fill::fill_window(&window_1);
             } else if wid == window_2.id() {
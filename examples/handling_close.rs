@@ -82,7 +82,7 @@ fn main() -> Result<(), impl std::error::Error> {
                     _ => (),
                 }
             }
-            Event::RedrawRequested(_) => {
+            Event::RedrawRequested { .. } => {
                 fill::fill_window(&window);
             }
             _ => (),
@@ -100,7 +100,7 @@ fn main() -> Result<(), impl std::error::Error> {
                     println!("\nIME purpose: {ime_purpose:?}\n");
                 }
             }
-            Event::RedrawRequested(_) => {
+            Event::RedrawRequested { .. } => {
                 fill::fill_window(&window);
             }
             _ => (),
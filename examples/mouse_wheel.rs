@@ -59,7 +59,7 @@ In other words, the deltas indicate the direction in which to move the content (
                 },
                 _ => (),
             },
-            Event::RedrawRequested(_) => {
+            Event::RedrawRequested { .. } => {
                 fill::fill_window(&window);
             }
             _ => (),
@@ -59,7 +59,7 @@ fn main() -> Result<(), impl std::error::Error> {
                     _ => (),
                 }
             }
-            Event::RedrawRequested(window_id) => {
+            Event::RedrawRequested { window_id, .. } => {
                 if let Some(window) = windows.get(&window_id) {
                     fill::fill_window(window);
                 }
@@ -79,7 +79,7 @@ mod example {
                     current_token = Some(token);
                 }
 
-                Event::RedrawRequested(id) => {
+                Event::RedrawRequested { window_id: id, .. } => {
                     if let Some(window) = windows.get(&id) {
                         super::fill::fill_window(window);
                     }
@@ -69,7 +69,7 @@ fn main() -> Result<(), impl std::error::Error> {
                 }
                 _ => (),
             },
-            Event::RedrawRequested(_) => {
+            Event::RedrawRequested { .. } => {
                 println!("\nredrawing!\n");
                 fill::fill_window(&window);
             }
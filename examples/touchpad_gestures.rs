@@ -44,7 +44,7 @@ fn main() -> Result<(), impl std::error::Error> {
                 }
                 _ => (),
             }
-        } else if let Event::RedrawRequested(_) = event {
+        } else if let Event::RedrawRequested { .. } = event {
             fill::fill_window(&window);
         }
     })
@@ -32,7 +32,7 @@ fn main() -> Result<(), impl std::error::Error> {
             Event::AboutToWait => {
                 window.request_redraw();
             }
-            Event::RedrawRequested(_) => {
+            Event::RedrawRequested { .. } => {
                 // Notify the windowing system that we'll be presenting to the window.
                 window.pre_present_notify();
                 fill::fill_window(&window);
@@ -61,7 +61,7 @@ fn main() -> Result<(), impl std::error::Error> {
             Event::AboutToWait => {
                 window.request_redraw();
             }
-            Event::RedrawRequested(_) => {
+            Event::RedrawRequested { .. } => {
                 fill::fill_window(&window);
             }
             _ => (),
@@ -48,7 +48,7 @@ fn main() -> std::process::ExitCode {
                 Event::AboutToWait => {
                     window.request_redraw();
                 }
-                Event::RedrawRequested(_) => {
+                Event::RedrawRequested { .. } => {
                     fill::fill_window(&window);
                 }
                 _ => (),
@@ -55,7 +55,7 @@ fn main() -> Result<(), impl std::error::Error> {
                 window.set_resize_increments(new_increments);
             }
             Event::AboutToWait => window.request_redraw(),
-            Event::RedrawRequested(_) => {
+            Event::RedrawRequested { .. } => {
                 fill::fill_window(&window);
             }
             _ => (),
@@ -73,6 +73,13 @@ fn main() -> Result<(), impl std::error::Error> {
                         Key::Character("w") => {
                             let _ = windows.remove(&window_id);
                         }
+                        Key::Character("m") => {
+                            windows.get(&window_id).unwrap().merge_all_windows();
+                        }
+                        Key::Character("b") => {
+                            let window = windows.get(&window_id).unwrap();
+                            window.set_tab_bar_visible(!window.is_tab_bar_visible());
+                        }
                         Key::ArrowRight => {
                             windows.get(&window_id).unwrap().select_next_tab();
                         }
@@ -96,7 +103,7 @@ fn main() -> Result<(), impl std::error::Error> {
                     _ => (),
                 }
             }
-            Event::RedrawRequested(window_id) => {
+            Event::RedrawRequested { window_id, .. } => {
                 if let Some(window) = windows.get(&window_id) {
                     fill::fill_window(window);
                 }
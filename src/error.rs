@@ -39,6 +39,29 @@ pub enum RunLoopError {
     AlreadyRunning,
     /// Application has exit with an error status.
     ExitFailure(i32),
+    /// The display server closed the connection because the application violated its protocol.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Returned when the compositor sends a protocol error, e.g. when a surface is
+    ///   committed with a buffer before it was configured. With
+    ///   [`pump_events`](crate::platform::pump_events) only the exit code is available, but the
+    ///   error is always logged.
+    /// - **Other platforms:** Never returned.
+    ProtocolError(ProtocolError),
+}
+
+/// A protocol error reported by the display server.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProtocolError {
+    /// The interface of the object the error is about, e.g. `xdg_surface`.
+    pub interface: String,
+    /// The id of the object the error is about.
+    pub object_id: u32,
+    /// The error code, as defined by the interface.
+    pub code: u32,
+    /// The description of the error given by the display server.
+    pub message: String,
 }
 
 impl NotSupportedError {
@@ -101,11 +124,23 @@ impl fmt::Display for RunLoopError {
             RunLoopError::NotSupported(e) => e.fmt(f),
             RunLoopError::Os(e) => e.fmt(f),
             RunLoopError::ExitFailure(status) => write!(f, "Exit Failure: {status}"),
+            RunLoopError::ProtocolError(e) => e.fmt(f),
         }
     }
 }
 
+impl fmt::Display for ProtocolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "protocol error {} on {}@{}: {}",
+            self.code, self.interface, self.object_id, self.message
+        )
+    }
+}
+
 impl error::Error for OsError {}
 impl error::Error for ExternalError {}
 impl error::Error for NotSupportedError {}
 impl error::Error for RunLoopError {}
+impl error::Error for ProtocolError {}
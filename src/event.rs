@@ -18,7 +18,7 @@
 //!     }
 //!
 //!     for w in (redraw windows) {
-//!         event_handler(RedrawRequested(w), ..., &mut control_flow);
+//!         event_handler(RedrawRequested { window_id: w, .. }, ..., &mut control_flow);
 //!     }
 //!
 //!     event_handler(AboutToWait, ..., &mut control_flow);
@@ -33,6 +33,7 @@
 //!
 //! [`EventLoop::run(...)`]: crate::event_loop::EventLoop::run
 //! [`ControlFlow::WaitUntil`]: crate::event_loop::ControlFlow::WaitUntil
+use std::ops::Range;
 use std::path::PathBuf;
 use std::sync::{Mutex, Weak};
 #[cfg(not(wasm_platform))]
@@ -48,9 +49,10 @@ use crate::window::Window;
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize},
     event_loop::AsyncRequestSerial,
-    keyboard::{self, ModifiersKeyState, ModifiersKeys, ModifiersState},
+    keyboard::{self, LockKeyState, ModifiersKeyState, ModifiersKeys, ModifiersState},
+    monitor::MonitorHandle,
     platform_impl,
-    window::{ActivationToken, Theme, WindowId},
+    window::{ActivationToken, DragAction, Theme, WindowId},
 };
 
 /// Describes a generic event.
@@ -78,6 +80,16 @@ pub enum Event<T: 'static> {
         event: DeviceEvent,
     },
 
+    /// Emitted when a monitor has been connected or disconnected.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Android / iOS / Orbital / Web:** Unsupported.
+    MonitorEvent {
+        monitor: MonitorHandle,
+        event: MonitorEvent,
+    },
+
     /// Emitted when an event is sent from [`EventLoopProxy::send_event`](crate::event_loop::EventLoopProxy::send_event)
     UserEvent(T),
 
@@ -231,7 +243,102 @@ pub enum Event<T: 'static> {
     ///
     /// Winit will aggregate duplicate redraw requests into a single event, to
     /// help avoid duplicating rendering work.
-    RedrawRequested(WindowId),
+    RedrawRequested {
+        window_id: WindowId,
+        /// An estimate of when the next frame has to be ready to be shown on the next refresh of
+        /// the display, so the application can scale its rendering work to fit.
+        ///
+        /// This is only a hint and may already be in the past when the event is handled.
+        ///
+        /// ## Platform-specific
+        ///
+        /// - **Wayland:** Derived from the frame callback and the refresh rate of the monitor
+        ///   the window is on, so only set when the redraw was throttled by a frame callback.
+        /// - **Windows:** Derived from the DWM composition timing.
+        /// - **macOS / iOS / X11 / Web / Android / Orbital:** Always `None`.
+        deadline: Option<Instant>,
+    },
+
+    /// Emitted when the user session is ending because the user logs out or the system shuts
+    /// down.
+    ///
+    /// The application may be terminated at any time after this event without receiving
+    /// [`LoopExiting`], so this is the last chance to save its state. The session end can't be
+    /// prevented.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** Unsupported, the application is terminated normally and receives
+    ///   [`LoopExiting`] instead.
+    /// - **Android / iOS / Orbital / Wayland / Web / X11:** Unsupported.
+    ///
+    /// [`LoopExiting`]: Self::LoopExiting
+    SessionEnd,
+
+    /// Emitted when the system is running low on memory.
+    ///
+    /// Applications should free whatever memory they can spare, such as caches, or they risk
+    /// being terminated by the system.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS:** Emitted on `applicationDidReceiveMemoryWarning:`.
+    /// - **Android:** Emitted on `onLowMemory`. The level passed to `onTrimMemory` is not
+    ///   available through `android-activity` and isn't reported.
+    /// - **macOS / Orbital / Wayland / Web / Windows / X11:** Unsupported.
+    MemoryWarning,
+
+    /// Emitted when the application as a whole gained or lost the focus.
+    ///
+    /// Contains `true` once one of the application's windows got focused while none was, and
+    /// `false` once none of them is focused anymore, e.g. because the user switched to another
+    /// application. Unlike [`WindowEvent::Focused`], moving the focus between the windows of the
+    /// application doesn't emit this event. This is useful to reduce activity, e.g. to mute audio,
+    /// while the application is in the background.
+    ///
+    /// The state is derived from [`WindowEvent::Focused`] of all windows: gaining focus is
+    /// emitted right before the corresponding [`WindowEvent::Focused`], losing focus right before
+    /// the next [`AboutToWait`]. An application without any window never receives this event.
+    ///
+    /// Note that this is unrelated to [`Suspended`] and [`Resumed`], which describe whether the
+    /// application may render at all.
+    ///
+    /// [`AboutToWait`]: Self::AboutToWait
+    /// [`Suspended`]: Self::Suspended
+    /// [`Resumed`]: Self::Resumed
+    ApplicationFocused(bool),
+
+    /// Emitted when the system asks the application to open files.
+    ///
+    /// This happens when the user opens files with the application, e.g. from the file manager,
+    /// both when that launches the application and while it is already running. Files the
+    /// application was launched with are delivered after [`Resumed`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** Emitted on `application:openURLs:` for `file://` URLs.
+    /// - **iOS:** Emitted on `application:openURL:options:` for `file://` URLs.
+    /// - **Windows / X11 / Wayland:** Unsupported, the files are passed on the command line as
+    ///   configured in the file association or desktop entry, see [`std::env::args_os`].
+    ///   DDE requests on Windows aren't handled.
+    /// - **Android / Orbital / Web:** Unsupported.
+    ///
+    /// [`Resumed`]: Self::Resumed
+    OpenFiles(Vec<PathBuf>),
+
+    /// Emitted when the system asks the application to open URLs other than files.
+    ///
+    /// This happens for URL schemes the application registered itself as a handler for.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS:** Emitted on `application:openURLs:` for non-`file://` URLs.
+    /// - **iOS:** Emitted on `application:openURL:options:` for non-`file://` URLs.
+    /// - **Windows / X11 / Wayland:** Unsupported, the URLs are passed on the command line as
+    ///   configured in the URL scheme registration or desktop entry, see [`std::env::args_os`].
+    ///   DDE requests on Windows aren't handled.
+    /// - **Android / Orbital / Web:** Unsupported.
+    OpenUrls(Vec<String>),
 
     /// Emitted when the event loop is being shut down.
     ///
@@ -248,9 +355,21 @@ impl<T> Event<T> {
             UserEvent(_) => Err(self),
             WindowEvent { window_id, event } => Ok(WindowEvent { window_id, event }),
             DeviceEvent { device_id, event } => Ok(DeviceEvent { device_id, event }),
+            MonitorEvent { monitor, event } => Ok(MonitorEvent { monitor, event }),
             NewEvents(cause) => Ok(NewEvents(cause)),
             AboutToWait => Ok(AboutToWait),
-            RedrawRequested(wid) => Ok(RedrawRequested(wid)),
+            RedrawRequested {
+                window_id,
+                deadline,
+            } => Ok(RedrawRequested {
+                window_id,
+                deadline,
+            }),
+            SessionEnd => Ok(SessionEnd),
+            MemoryWarning => Ok(MemoryWarning),
+            ApplicationFocused(focused) => Ok(ApplicationFocused(focused)),
+            OpenFiles(paths) => Ok(OpenFiles(paths)),
+            OpenUrls(urls) => Ok(OpenUrls(urls)),
             LoopExiting => Ok(LoopExiting),
             Suspended => Ok(Suspended),
             Resumed => Ok(Resumed),
@@ -305,6 +424,40 @@ pub enum WindowEvent {
         token: ActivationToken,
     },
 
+    /// The text of the primary selection was read.
+    ///
+    /// The `text` is `None` if there's no selection, or it isn't available as text.
+    ///
+    /// Delivered in response to [`Window::request_primary_selection_text`].
+    ///
+    /// [`Window::request_primary_selection_text`]: crate::window::Window::request_primary_selection_text
+    PrimarySelectionText {
+        serial: AsyncRequestSerial,
+        text: Option<String>,
+    },
+
+    /// The clipboard was read in the requested mime type.
+    ///
+    /// The `data` is `None` if the clipboard is empty, or it isn't available in that mime type.
+    ///
+    /// Delivered in response to [`Window::request_clipboard_data`].
+    ///
+    /// [`Window::request_clipboard_data`]: crate::window::Window::request_clipboard_data
+    ClipboardData {
+        serial: AsyncRequestSerial,
+        data: Option<Vec<u8>>,
+    },
+
+    /// The mime types the clipboard is available in were read.
+    ///
+    /// Delivered in response to [`Window::request_clipboard_mime_types`].
+    ///
+    /// [`Window::request_clipboard_mime_types`]: crate::window::Window::request_clipboard_mime_types
+    ClipboardMimeTypes {
+        serial: AsyncRequestSerial,
+        mime_types: Vec<String>,
+    },
+
     /// The size of the window has changed. Contains the client area's new dimensions.
     Resized(PhysicalSize<u32>),
 
@@ -339,11 +492,93 @@ pub enum WindowEvent {
     /// hovered.
     HoveredFileCancelled,
 
+    /// A drag entered the window, offering its data in `mime_types`.
+    ///
+    /// The drop is refused until a mime type is accepted with [`Window::set_drag_accept`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Only for drags of other clients.
+    /// - **iOS / Android / Web / Orbital / Windows / macOS / X11:** Unsupported.
+    ///
+    /// [`Window::set_drag_accept`]: crate::window::Window::set_drag_accept
+    DragEntered {
+        mime_types: Vec<String>,
+        position: PhysicalPosition<f64>,
+    },
+
+    /// The drag moved over the window.
+    DragMoved { position: PhysicalPosition<f64> },
+
+    /// The drag left the window, or was dropped without accepting any mime type.
+    DragLeft,
+
+    /// The drag was dropped onto the window, and its data read in the accepted `mime_type`.
+    ///
+    /// The `data` is `None` if it couldn't be read. After a [`DragAction::Move`], the source
+    /// deletes its data.
+    ///
+    /// The `action` is the one picked by the system for the drop, or `None` if none was picked.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** The `action` is `None` when the compositor doesn't support actions, or
+    ///   asks the user to choose one.
+    DragDropped {
+        mime_type: String,
+        data: Option<Vec<u8>>,
+        action: Option<DragAction>,
+    },
+
     /// The window gained or lost focus.
     ///
     /// The parameter is true if the window has gained focus, and false if it has lost focus.
+    ///
+    /// With focus-follows-mouse (or "sloppy focus") window managers, the focus moves as the
+    /// pointer passes over windows, so this event may be sent often and for short periods of time.
+    /// The reason for the focus change isn't known, so applications that do expensive work on focus
+    /// changes may want to defer it until the focus has settled.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Temporary focus changes caused by keyboard grabs, like the window switcher of the
+    ///   window manager, are not reported.
     Focused(bool),
 
+    /// The window has been activated or deactivated.
+    ///
+    /// The parameter is true if the window has become the active window, and false otherwise.
+    /// Applications which draw inactive windows differently should use this event rather than
+    /// [`Focused`], which tracks the keyboard focus.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Sent when the compositor changes the activated state of the window.
+    /// - **Others:** Unsupported, activation always coincides with [`Focused`].
+    ///
+    /// [`Focused`]: Self::Focused
+    Activated(bool),
+
+    /// The cursor grab set with [`Window::set_cursor_grab`] became active or was released.
+    ///
+    /// The grab may be released by the system, e.g. when the user presses Escape on Web, so
+    /// applications controlling a camera with the pointer should stop doing so once `grabbed`
+    /// is `false`.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Sent when the compositor activates or deactivates the lock or
+    ///   confinement, which it does by itself when the focus changes.
+    /// - **Web:** Sent on `pointerlockchange`.
+    /// - **X11:** Only sent with `grabbed: false` when the grab couldn't be restored as the
+    ///   window regained focus, as another client holds one. The grab mode is then reset to
+    ///   [`CursorGrabMode::None`].
+    /// - **iOS / Android / Orbital / Windows / macOS:** Unsupported.
+    ///
+    /// [`Window::set_cursor_grab`]: crate::window::Window::set_cursor_grab
+    /// [`CursorGrabMode::None`]: crate::window::CursorGrabMode::None
+    PointerGrabChanged { grabbed: bool },
+
     /// An event from the keyboard has been received.
     ///
     /// ## Platform-specific
@@ -369,6 +604,14 @@ pub enum WindowEvent {
     /// The keyboard modifiers have changed.
     ModifiersChanged(Modifiers),
 
+    /// The state of the keyboard lock keys has changed while the window had keyboard focus.
+    ///
+    /// The current state can also be queried with [`Window::lock_key_state`], which lists the
+    /// platforms this is reported on.
+    ///
+    /// [`Window::lock_key_state`]: crate::window::Window::lock_key_state
+    LockKeysChanged(LockKeyState),
+
     /// An event from an input method.
     ///
     /// **Note:** You have to explicitly enable this event using [`Window::set_ime_allowed`].
@@ -498,8 +741,13 @@ pub enum WindowEvent {
     /// This is different to window visibility as it depends on whether the window is closed,
     /// minimised, set invisible, or fully occluded by another window.
     ///
+    /// Rendering to an occluded window is wasted work, so applications may want to stop
+    /// drawing until the window is no longer occluded.
+    ///
     /// Platform-specific behavior:
     ///
+    /// - **X11:** The window is also reported as occluded when it gets unmapped, e.g. when it's
+    ///   minimized or on another workspace.
     /// - **Web:** Doesn't take into account CSS [`border`], [`padding`], or [`transform`].
     /// - **iOS / Android / Wayland / Windows / Orbital:** Unsupported.
     ///
@@ -542,8 +790,29 @@ impl DeviceId {
 /// Note that these events are delivered regardless of input focus.
 #[derive(Clone, Debug, PartialEq)]
 pub enum DeviceEvent {
-    Added,
-    Removed,
+    /// A device has been connected.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Sent when a seat gains a capability, including for the capabilities the
+    ///   seats have when the event loop starts. All the devices of a seat share the same
+    ///   [`DeviceId`], so the `kind` tells which one appeared.
+    /// - **macOS / iOS / Android / Web / Orbital:** Unsupported.
+    Added {
+        kind: DeviceKind,
+    },
+
+    /// A device has been disconnected.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows:** The `kind` is always [`DeviceKind::Other`], as a removed device can't be
+    ///   queried anymore.
+    /// - **Wayland:** Sent when a seat loses a capability.
+    /// - **macOS / iOS / Android / Web / Orbital:** Unsupported.
+    Removed {
+        kind: DeviceKind,
+    },
 
     /// Change in physical position of a pointing device.
     ///
@@ -580,6 +849,34 @@ pub enum DeviceEvent {
     },
 }
 
+/// The kind of device reported by [`DeviceEvent::Added`] and [`DeviceEvent::Removed`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeviceKind {
+    Keyboard,
+    /// A mouse, touchpad or other device moving a cursor.
+    Pointer,
+    Touch,
+    /// Any other device, or a device whose kind isn't known.
+    Other,
+}
+
+/// Describes a change in the set of connected monitors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MonitorEvent {
+    /// The monitor has been connected and is now part of
+    /// [`EventLoopWindowTarget::available_monitors`].
+    ///
+    /// [`EventLoopWindowTarget::available_monitors`]: crate::event_loop::EventLoopWindowTarget::available_monitors
+    Connected,
+
+    /// The monitor has been disconnected.
+    ///
+    /// The handle can still be compared with previously obtained ones, but it no longer refers
+    /// to an available monitor.
+    Disconnected,
+}
+
 /// Describes a keyboard input as a raw device event.
 ///
 /// Note that holding down a key may produce repeated `RawKeyEvent`s. The
@@ -702,10 +999,35 @@ pub struct KeyEvent {
     /// On Windows, Linux and macOS, this type contains the key without modifiers and the text with all
     /// modifiers applied.
     ///
-    /// On Android, iOS, Redox and Web, this type is a no-op.
+    /// On every platform except iOS and Web, it also carries the raw scancode.
     pub(crate) platform_specific: platform_impl::KeyEventExtra,
 }
 
+impl KeyEvent {
+    /// The raw scancode reported by the platform for this key event.
+    ///
+    /// Unlike [`physical_key`], the value is passed through unchanged, so keys that winit has no
+    /// [`KeyCode`] for can still be told apart. It is not affected by the keyboard layout or the
+    /// input method, but its encoding differs between platforms, so it shouldn't be persisted
+    /// and compared across them.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11 / Wayland:** The Linux evdev code, i.e. the X11 / Wayland keycode minus 8.
+    /// - **Windows:** The set 1 scancode, with `0xE000` added for extended (`0xE0`-prefixed) keys.
+    /// - **macOS:** The virtual key code (`kVK_*`).
+    /// - **Android:** The value from `KeyEvent.getScanCode`.
+    /// - **Orbital:** The scancode sent by the display server.
+    /// - **iOS / Web:** Unsupported, always returns `None`.
+    ///
+    /// [`physical_key`]: Self::physical_key
+    /// [`KeyCode`]: keyboard::KeyCode
+    #[inline]
+    pub fn raw_scancode(&self) -> Option<u32> {
+        self.platform_specific.raw_scancode()
+    }
+}
+
 /// Describes keyboard modifiers event.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Modifiers {
@@ -793,9 +1115,9 @@ impl From<ModifiersState> for Modifiers {
 /// the character you want to apply the accent to. In this case, some platforms will generate the following event sequence:
 /// ```ignore
 /// // Press "`" key
-/// Ime::Preedit("`", Some((0, 0)))
+/// Ime::Preedit("`", Some((0, 0)), vec![])
 /// // Press "E" key
-/// Ime::Preedit("", None) // Synthetic event generated by winit to clear preedit.
+/// Ime::Preedit("", None, vec![]) // Synthetic event generated by winit to clear preedit.
 /// Ime::Commit("é")
 /// ```
 ///
@@ -806,15 +1128,15 @@ impl From<ModifiersState> for Modifiers {
 /// sequence could be obtained:
 /// ```ignore
 /// // Press "A" key
-/// Ime::Preedit("a", Some((1, 1)))
+/// Ime::Preedit("a", Some((1, 1)), vec![])
 /// // Press "B" key
-/// Ime::Preedit("a b", Some((3, 3)))
+/// Ime::Preedit("a b", Some((3, 3)), vec![])
 /// // Press left arrow key
-/// Ime::Preedit("a b", Some((1, 1)))
+/// Ime::Preedit("a b", Some((1, 1)), vec![])
 /// // Press space key
-/// Ime::Preedit("啊b", Some((3, 3)))
+/// Ime::Preedit("啊b", Some((3, 3)), vec![PreeditSpan { range: 0..3, style: PreeditStyle::Highlight }])
 /// // Press space key
-/// Ime::Preedit("", None) // Synthetic event generated by winit to clear preedit.
+/// Ime::Preedit("", None, vec![]) // Synthetic event generated by winit to clear preedit.
 /// Ime::Commit("啊不")
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -834,13 +1156,42 @@ pub enum Ime {
     /// this indicates that preedit was cleared.
     ///
     /// The cursor position is byte-wise indexed.
-    Preedit(String, Option<(usize, usize)>),
+    ///
+    /// The last value holds the styled spans of the preedit string, which should be reproduced
+    /// when drawing the composition, e.g. to underline the clause being converted. It's empty when
+    /// the IME doesn't provide any styling.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS / Orbital / Wayland:** The styling is not reported.
+    Preedit(String, Option<(usize, usize)>, Vec<PreeditSpan>),
 
     /// Notifies when text should be inserted into the editor widget.
     ///
     /// Right before this event winit will send empty [`Self::Preedit`] event.
     Commit(String),
 
+    /// Notifies when the text around the cursor should be deleted.
+    ///
+    /// The lengths are byte-wise and relative to the cursor, or the selection if there's one,
+    /// excluding the preedit text. This is used by input methods to replace already committed
+    /// text, e.g. for reconversion, and is sent before the [`Commit`](Self::Commit) that replaces
+    /// it.
+    ///
+    /// Input methods usually only do this when the application told them about the text
+    /// surrounding the cursor.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Sent for `zwp_text_input_v3::delete_surrounding_text`.
+    /// - **Others:** Unsupported.
+    DeleteSurrounding {
+        /// The number of bytes to delete before the cursor.
+        before_length: usize,
+        /// The number of bytes to delete after the cursor.
+        after_length: usize,
+    },
+
     /// Notifies when the IME was disabled.
     ///
     /// After receiving this event you won't get any more [`Preedit`](Self::Preedit) or
@@ -850,6 +1201,29 @@ pub enum Ime {
     Disabled,
 }
 
+/// A styled part of the [`Ime::Preedit`] string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PreeditSpan {
+    /// The byte-wise indexed range of the preedit string the style applies to.
+    pub range: Range<usize>,
+
+    /// How the text in the range should be drawn.
+    pub style: PreeditStyle,
+}
+
+/// The style of a [`PreeditSpan`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PreeditStyle {
+    /// The text is underlined, usually to mark text that is still being composed.
+    Underline,
+
+    /// The text is highlighted, usually to mark the clause that is currently being converted.
+    Highlight,
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TouchPhase {
@@ -983,6 +1357,22 @@ pub enum MouseButton {
     Middle,
     Back,
     Forward,
+    /// Any other button, numbered from zero with `Left`, `Right`, `Middle`, `Back` and `Forward`
+    /// taking `0` to `4`.
+    ///
+    /// The first extra button is therefore `Other(5)`, the next one `Other(6)` and so on. Which
+    /// physical button comes first is up to the system and its drivers, so the same button of a
+    /// mouse may get another number on another platform.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11 / Wayland:** `BTN_SIDE` and `BTN_BACK` are `Back`, `BTN_EXTRA` and `BTN_FORWARD`
+    ///   are `Forward`, and the extra buttons start with `BTN_TASK`. On X11 this relies on the
+    ///   button numbering of the evdev and libinput drivers.
+    /// - **Windows:** Only reports `Other(5)` and higher when the driver sends the extra buttons
+    ///   as `XBUTTON` messages; many gaming mice remap them to key presses instead.
+    /// - **Wayland:** Buttons outside of the mouse range of `BTN_*` codes are reported with their
+    ///   raw evdev code.
     Other(u16),
 }
 
@@ -996,6 +1386,16 @@ pub enum PointerButton {
 }
 
 /// Describes a difference in the mouse scroll wheel state.
+///
+/// Horizontal scrolling is always reported in the first component, whether it comes from a
+/// tilting scroll wheel, a separate thumb wheel or a touchpad. Tilting the wheel to the right
+/// gives a negative value, and it is never reported as a [`MouseButton::Back`] or
+/// [`MouseButton::Forward`] press.
+///
+/// ## Platform-specific
+///
+/// - **Windows / Wayland / X11:** A tilt wheel and a thumb wheel on the same mouse can't be told
+///   apart, as the system reports both as the same horizontal axis.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MouseScrollDelta {
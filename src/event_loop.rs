@@ -7,9 +7,14 @@
 //!
 //! See the root-level documentation for information on how to create and use an event loop to
 //! handle events.
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
 use std::ops::Deref;
+use std::rc::Rc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, ThreadId};
 use std::{error, fmt};
 
 use raw_window_handle::{HasRawDisplayHandle, RawDisplayHandle};
@@ -19,7 +24,9 @@ use std::time::{Duration, Instant};
 use web_time::{Duration, Instant};
 
 use crate::error::RunLoopError;
-use crate::{event::Event, monitor::MonitorHandle, platform_impl};
+use crate::event::{DeviceEvent, MouseScrollDelta, PointerEvent, TouchPhase, WindowEvent};
+use crate::window::WindowId;
+use crate::{dpi::PhysicalSize, event::Event, monitor::MonitorHandle, platform_impl};
 
 /// Provides a way to retrieve events from the system and from the windows that were registered to
 /// the events loop.
@@ -38,6 +45,9 @@ use crate::{event::Event, monitor::MonitorHandle, platform_impl};
 /// [`Window`]: crate::window::Window
 pub struct EventLoop<T: 'static> {
     pub(crate) event_loop: platform_impl::EventLoop<T>,
+    pub(crate) coalesce: CoalescePolicy,
+    pub(crate) application_focus: Rc<RefCell<ApplicationFocus>>,
+    pub(crate) user_events: UserEventQueueOwner,
     pub(crate) _marker: PhantomData<*mut ()>, // Not Send nor Sync
 }
 
@@ -56,9 +66,11 @@ pub struct EventLoopWindowTarget<T: 'static> {
 ///
 /// This is used to make specifying options that affect the whole application
 /// easier. But note that constructing multiple event loops is not supported.
-#[derive(Default)]
 pub struct EventLoopBuilder<T: 'static> {
     pub(crate) platform_specific: platform_impl::PlatformSpecificEventLoopAttributes,
+    coalesce: CoalescePolicy,
+    user_event_limit: usize,
+    user_event_overflow: UserEventOverflow,
     _p: PhantomData<T>,
 }
 
@@ -70,6 +82,16 @@ impl EventLoopBuilder<()> {
     }
 }
 
+impl<T> Default for EventLoopBuilder<T> {
+    fn default() -> Self {
+        Self::with_user_event()
+    }
+}
+
+/// The number of user events that may be pending by default, see
+/// [`EventLoopBuilder::with_user_event_limit`].
+pub const DEFAULT_USER_EVENT_LIMIT: usize = 1 << 16;
+
 static EVENT_LOOP_CREATED: AtomicBool = AtomicBool::new(false);
 
 impl<T> EventLoopBuilder<T> {
@@ -79,10 +101,42 @@ impl<T> EventLoopBuilder<T> {
     pub fn with_user_event() -> Self {
         Self {
             platform_specific: Default::default(),
+            coalesce: Default::default(),
+            user_event_limit: DEFAULT_USER_EVENT_LIMIT,
+            user_event_overflow: Default::default(),
             _p: PhantomData,
         }
     }
 
+    /// Sets whether high-frequency events are merged before they are delivered.
+    ///
+    /// The default is [`CoalescePolicy::Raw`]. See [`CoalescePolicy`] for the events that are
+    /// affected.
+    #[inline]
+    pub fn with_coalescing(&mut self, policy: CoalescePolicy) -> &mut Self {
+        self.coalesce = policy;
+        self
+    }
+
+    /// Sets how many user events sent through an [`EventLoopProxy`] may wait for delivery, and
+    /// what happens to the events sent beyond that.
+    ///
+    /// This keeps an application that can't keep up with the events it sends itself, e.g. from a
+    /// worker thread producing results faster than they are handled, from using up all memory.
+    /// A warning is logged when events get dropped.
+    ///
+    /// The default is [`DEFAULT_USER_EVENT_LIMIT`] events and [`UserEventOverflow::DropNewest`].
+    #[inline]
+    pub fn with_user_event_limit(
+        &mut self,
+        limit: usize,
+        overflow: UserEventOverflow,
+    ) -> &mut Self {
+        self.user_event_limit = limit;
+        self.user_event_overflow = overflow;
+        self
+    }
+
     /// Builds a new event loop.
     ///
     /// ***For cross-platform compatibility, the [`EventLoop`] must be created on the main thread,
@@ -125,6 +179,12 @@ impl<T> EventLoopBuilder<T> {
         #[allow(clippy::unnecessary_mut_passed)]
         EventLoop {
             event_loop: platform_impl::EventLoop::new(&mut self.platform_specific),
+            coalesce: self.coalesce,
+            application_focus: Default::default(),
+            user_events: UserEventQueueOwner(Arc::new(UserEventQueue::new(
+                self.user_event_limit,
+                self.user_event_overflow,
+            ))),
             _marker: PhantomData,
         }
     }
@@ -178,7 +238,37 @@ pub enum ControlFlow {
     /// native refresh rate should instead use [`Poll`] and the VSync functionality of a graphics API
     /// to reduce odds of missed frames.
     ///
+    /// Once the time is reached, the next iteration starts with [`StartCause::ResumeTimeReached`],
+    /// which contains the requested resume time, while the actual one can be taken with
+    /// [`Instant::now`]. A steady tick that doesn't drift is obtained by scheduling each deadline
+    /// relative to the previous `requested_resume` rather than to the current time:
+    ///
+    /// ```no_run
+    /// # use std::time::{Duration, Instant};
+    /// # use winit::event::{Event, StartCause};
+    /// # use winit::event_loop::{ControlFlow, EventLoop};
+    /// const TICK: Duration = Duration::from_millis(16);
+    ///
+    /// let event_loop = EventLoop::new();
+    /// event_loop.run(move |event, _, control_flow| match event {
+    ///     Event::NewEvents(StartCause::Init) => {
+    ///         control_flow.set_wait_until(Instant::now() + TICK);
+    ///     }
+    ///     Event::NewEvents(StartCause::ResumeTimeReached { requested_resume, .. }) => {
+    ///         // Advance the simulation by one tick here.
+    ///         control_flow.set_wait_until(requested_resume + TICK);
+    ///     }
+    ///     _ => (),
+    /// });
+    /// ```
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11 / Wayland:** The wait has a resolution of one millisecond, so the loop may wake up
+    ///   to a millisecond after the requested time.
+    ///
     /// [`Poll`]: Self::Poll
+    /// [`StartCause::ResumeTimeReached`]: crate::event::StartCause::ResumeTimeReached
     WaitUntil(Instant),
 
     /// Send a [`LoopExiting`] event and stop the event loop. This variant is *sticky* - once set,
@@ -316,13 +406,36 @@ impl<T> EventLoop<T> {
     where
         F: 'static + FnMut(Event<T>, &EventLoopWindowTarget<T>, &mut ControlFlow),
     {
+        let event_handler = self.wrap_event_handler(event_handler);
         self.event_loop.run(event_handler)
     }
 
+    /// Apply the event processing done by winit itself around the user's event handler.
+    pub(crate) fn wrap_event_handler<F>(
+        &self,
+        event_handler: F,
+    ) -> impl FnMut(Event<T>, &EventLoopWindowTarget<T>, &mut ControlFlow)
+    where
+        F: FnMut(Event<T>, &EventLoopWindowTarget<T>, &mut ControlFlow),
+    {
+        let user_events = self.user_events.0.clone();
+        let mut event_handler = coalesce_events(
+            self.coalesce,
+            track_application_focus(self.application_focus.clone(), event_handler),
+        );
+        move |event, event_loop, control_flow| {
+            if let Event::UserEvent(_) = event {
+                user_events.delivered();
+            }
+            event_handler(event, event_loop, control_flow)
+        }
+    }
+
     /// Creates an [`EventLoopProxy`] that can be used to dispatch user events to the main event loop.
     pub fn create_proxy(&self) -> EventLoopProxy<T> {
         EventLoopProxy {
             event_loop_proxy: self.event_loop.create_proxy(),
+            queue: self.user_events.0.clone(),
         }
     }
 }
@@ -381,6 +494,155 @@ impl<T> EventLoopWindowTarget<T> {
         #[cfg(any(x11_platform, wasm_platform, wayland_platform, windows))]
         self.p.listen_device_events(_allowed);
     }
+
+    /// Change the name the application identifies its windows with.
+    ///
+    /// This updates all existing windows and is used for windows created later on, unless they
+    /// set a name themselves. The names have the same meaning as in `with_name` of
+    /// [`WindowBuilderExtX11`] and [`WindowBuilderExtWayland`]: the `general` name should match the
+    /// `.desktop` file of the application.
+    ///
+    /// Desktop environments might only read the name when a window is mapped, so changes can take
+    /// effect late or not at all, e.g. for taskbar grouping that was already decided.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Sets `WM_CLASS` to `general` and `instance`, in the same way as `with_name`.
+    ///   Windows built with `with_class` are updated as well.
+    /// - **Wayland:** Sets the `app_id` to `general`, `instance` is ignored.
+    /// - **Windows:** Unsupported, the application user model ID can only be set before the first
+    ///   window is shown.
+    /// - **macOS / iOS:** Unsupported, the name comes from the application bundle.
+    /// - **Android / Web / Orbital:** Unsupported.
+    ///
+    #[cfg_attr(
+        not(x11_platform),
+        doc = "[`WindowBuilderExtX11`]: #only-available-on-x11"
+    )]
+    #[cfg_attr(
+        not(wayland_platform),
+        doc = "[`WindowBuilderExtWayland`]: #only-available-on-wayland"
+    )]
+    #[cfg_attr(
+        x11_platform,
+        doc = "[`WindowBuilderExtX11`]: crate::platform::x11::WindowBuilderExtX11"
+    )]
+    #[cfg_attr(
+        wayland_platform,
+        doc = "[`WindowBuilderExtWayland`]: crate::platform::wayland::WindowBuilderExtWayland"
+    )]
+    #[inline]
+    pub fn set_application_name(&self, _general: &str, _instance: &str) {
+        #[cfg(any(x11_platform, wayland_platform))]
+        self.p
+            .set_application_name(platform_impl::ApplicationName::new(
+                _general.to_owned(),
+                _instance.to_owned(),
+            ));
+    }
+
+    /// Returns the maximum time between two clicks for them to count as a double-click.
+    ///
+    /// Returns `None` if the platform doesn't expose this setting.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11 / Wayland / Web / iOS / Android / Orbital:** Unsupported.
+    #[inline]
+    pub fn double_click_time(&self) -> Option<Duration> {
+        #[cfg(any(windows, macos_platform))]
+        {
+            self.p.double_click_time()
+        }
+        #[cfg(not(any(windows, macos_platform)))]
+        {
+            None
+        }
+    }
+
+    /// Returns how long the text caret stays visible or hidden during one blink.
+    ///
+    /// `Some(Duration::ZERO)` means the user has turned caret blinking off. Returns `None` if the
+    /// platform doesn't expose this setting.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS / X11 / Wayland / Web / iOS / Android / Orbital:** Unsupported.
+    #[inline]
+    pub fn caret_blink_time(&self) -> Option<Duration> {
+        #[cfg(windows)]
+        {
+            self.p.caret_blink_time()
+        }
+        #[cfg(not(windows))]
+        {
+            None
+        }
+    }
+
+    /// Returns the area around the first click that the second click of a double-click must land
+    /// in, in physical pixels.
+    ///
+    /// Returns `None` if the platform doesn't expose this setting.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS / X11 / Wayland / Web / iOS / Android / Orbital:** Unsupported.
+    #[inline]
+    pub fn double_click_distance(&self) -> Option<PhysicalSize<u32>> {
+        #[cfg(windows)]
+        {
+            self.p.double_click_distance()
+        }
+        #[cfg(not(windows))]
+        {
+            None
+        }
+    }
+
+    /// Returns how far the pointer has to move with a button held before the motion should be
+    /// treated as a drag, in physical pixels.
+    ///
+    /// Returns `None` if the platform doesn't expose this setting.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **macOS / X11 / Wayland / Web / iOS / Android / Orbital:** Unsupported.
+    #[inline]
+    pub fn drag_threshold(&self) -> Option<PhysicalSize<u32>> {
+        #[cfg(windows)]
+        {
+            self.p.drag_threshold()
+        }
+        #[cfg(not(windows))]
+        {
+            None
+        }
+    }
+
+    /// Returns the name and capabilities of the compositor or window manager, to adapt to it at
+    /// runtime instead of guessing from the desktop in use.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** The protocol doesn't expose the name of the compositor, so the one from
+    ///   `XDG_CURRENT_DESKTOP` is used instead. The capabilities are derived from the advertised
+    ///   globals, which are listed in [`CompositorInfo::globals`].
+    /// - **X11:** The capabilities are derived from the hints the window manager lists in
+    ///   `_NET_SUPPORTED`.
+    /// - **Windows / macOS / Web / iOS / Android / Orbital:** Unsupported, returns the default
+    ///   value.
+    #[inline]
+    pub fn compositor_info(&self) -> CompositorInfo {
+        #[cfg(any(x11_platform, wayland_platform))]
+        {
+            self.p.compositor_info()
+        }
+        #[cfg(not(any(x11_platform, wayland_platform)))]
+        {
+            CompositorInfo::default()
+        }
+    }
 }
 
 unsafe impl<T> HasRawDisplayHandle for EventLoopWindowTarget<T> {
@@ -393,12 +655,14 @@ unsafe impl<T> HasRawDisplayHandle for EventLoopWindowTarget<T> {
 /// Used to send custom events to [`EventLoop`].
 pub struct EventLoopProxy<T: 'static> {
     event_loop_proxy: platform_impl::EventLoopProxy<T>,
+    queue: Arc<UserEventQueue>,
 }
 
 impl<T: 'static> Clone for EventLoopProxy<T> {
     fn clone(&self) -> Self {
         Self {
             event_loop_proxy: self.event_loop_proxy.clone(),
+            queue: self.queue.clone(),
         }
     }
 }
@@ -410,9 +674,20 @@ impl<T: 'static> EventLoopProxy<T> {
     ///
     /// Returns an `Err` if the associated [`EventLoop`] no longer exists.
     ///
+    /// If as many events as allowed by [`EventLoopBuilder::with_user_event_limit`] are already
+    /// waiting for delivery, the event is handled according to the [`UserEventOverflow`] of the
+    /// event loop.
+    ///
     /// [`UserEvent(event)`]: Event::UserEvent
     pub fn send_event(&self, event: T) -> Result<(), EventLoopClosed<T>> {
-        self.event_loop_proxy.send_event(event)
+        if !self.queue.reserve() {
+            return Ok(());
+        }
+
+        self.event_loop_proxy.send_event(event).map_err(|err| {
+            self.queue.delivered();
+            err
+        })
     }
 }
 
@@ -437,6 +712,102 @@ impl<T> fmt::Display for EventLoopClosed<T> {
 
 impl<T: fmt::Debug> error::Error for EventLoopClosed<T> {}
 
+/// Control what happens to user events sent while the event loop already has as many pending as
+/// allowed by [`EventLoopBuilder::with_user_event_limit`].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum UserEventOverflow {
+    /// Drop the event that is being sent.
+    ///
+    /// [`EventLoopProxy::send_event`] still returns `Ok`, as the event loop exists.
+    #[default]
+    DropNewest,
+    /// Block the sending thread until the event loop has delivered enough events.
+    ///
+    /// Events sent from the thread running the event loop are dropped instead, as that thread
+    /// could never deliver them while blocked.
+    Block,
+}
+
+/// The count of the user events that were sent but not delivered yet.
+pub(crate) struct UserEventQueue {
+    limit: usize,
+    overflow: UserEventOverflow,
+    event_loop_thread: ThreadId,
+    state: Mutex<UserEventQueueState>,
+    space_available: Condvar,
+}
+
+#[derive(Default)]
+struct UserEventQueueState {
+    pending: usize,
+    dropped: usize,
+    closed: bool,
+}
+
+impl UserEventQueue {
+    fn new(limit: usize, overflow: UserEventOverflow) -> Self {
+        Self {
+            limit,
+            overflow,
+            event_loop_thread: thread::current().id(),
+            state: Default::default(),
+            space_available: Condvar::new(),
+        }
+    }
+
+    /// Make room for an event, returning `false` if it must be dropped.
+    fn reserve(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if state.pending >= self.limit && !state.closed {
+            let can_block = self.overflow == UserEventOverflow::Block
+                && thread::current().id() != self.event_loop_thread;
+            if !can_block {
+                if state.dropped == 0 {
+                    log::warn!(
+                        "{} user events are waiting for delivery, dropping new ones",
+                        state.pending
+                    );
+                }
+                state.dropped += 1;
+                return false;
+            }
+
+            state = self
+                .space_available
+                .wait_while(state, |state| state.pending >= self.limit && !state.closed)
+                .unwrap();
+        }
+
+        state.pending += 1;
+        true
+    }
+
+    fn delivered(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.pending = state.pending.saturating_sub(1);
+        // Report the dropped events once the event loop caught up, rather than for every event.
+        if state.dropped > 0 && state.pending <= self.limit / 2 {
+            log::warn!("Dropped {} user events", state.dropped);
+            state.dropped = 0;
+        }
+        self.space_available.notify_one();
+    }
+
+    fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+        self.space_available.notify_all();
+    }
+}
+
+/// Releases the threads waiting to send user events once the event loop is gone.
+pub(crate) struct UserEventQueueOwner(Arc<UserEventQueue>);
+
+impl Drop for UserEventQueueOwner {
+    fn drop(&mut self) {
+        self.0.close();
+    }
+}
+
 /// Control when device events are captured.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
 pub enum DeviceEvents {
@@ -449,6 +820,438 @@ pub enum DeviceEvents {
     Never,
 }
 
+/// The compositor or window manager, see [`EventLoopWindowTarget::compositor_info`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CompositorInfo {
+    /// The name of the compositor or window manager, if known.
+    pub name: Option<String>,
+
+    /// The features the compositor or window manager advertises.
+    pub capabilities: CompositorCapabilities,
+
+    /// The interfaces of the globals advertised by the Wayland compositor, sorted and without
+    /// duplicates. Empty on the other platforms.
+    pub globals: Vec<String>,
+}
+
+/// The features advertised by the compositor or window manager, see [`CompositorInfo`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct CompositorCapabilities {
+    /// Whether the decorations of the windows can be drawn by the compositor.
+    ///
+    /// On X11, this is the case whenever a window manager is running.
+    pub supports_ssd: bool,
+
+    /// Whether the compositor can blur what is behind transparent windows.
+    pub supports_blur: bool,
+
+    /// Whether windows can be activated, see [`Window::focus_window`].
+    ///
+    /// [`Window::focus_window`]: crate::window::Window::focus_window
+    pub supports_activation: bool,
+
+    /// Whether the cursor can be confined to or locked in a window, see
+    /// [`Window::set_cursor_grab`].
+    ///
+    /// [`Window::set_cursor_grab`]: crate::window::Window::set_cursor_grab
+    pub supports_pointer_constraints: bool,
+
+    /// Whether the compositor can tell windows about fractional scale factors.
+    pub supports_fractional_scale: bool,
+}
+
+/// Control whether high-frequency events are merged before they are delivered.
+///
+/// With [`Coalesced`], runs of the following events are merged into a single event, as long as
+/// nothing else is delivered in between:
+///
+/// - The cursor motion the backends report for the same window and pointer, as
+///   [`WindowEvent::Pointer`] with [`PointerEvent::Moved`], `WindowEvent::CursorMoved` or
+///   `WindowEvent::PointerMoved`: only the last position is delivered.
+/// - [`WindowEvent::MouseWheel`] with [`TouchPhase::Moved`] for the same window and device: the
+///   deltas are summed if they are all lines or all pixels.
+/// - [`WindowEvent::Resized`] for the same window: only the last size is delivered.
+/// - [`DeviceEvent::MouseMotion`] and [`DeviceEvent::MouseWheel`] for the same device: the deltas
+///   are summed.
+///
+/// The merged event is delivered before the next unrelated event, and at the latest right before
+/// [`Event::AboutToWait`], so no input is held back across loop iterations. All other events are
+/// always delivered as they arrive.
+///
+/// [`Coalesced`]: Self::Coalesced
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum CoalescePolicy {
+    /// Deliver every event as the system reports it.
+    ///
+    /// Note that some systems already merge motion before winit sees it.
+    #[default]
+    Raw,
+    /// Merge runs of motion, scroll and resize events.
+    Coalesced,
+}
+
+/// The focus state of the application, aggregated from the focus of its windows.
+#[derive(Debug, Default)]
+pub(crate) struct ApplicationFocus {
+    focused_windows: HashSet<WindowId>,
+    reported: bool,
+}
+
+/// Wrap the user's event handler to emit [`Event::ApplicationFocused`].
+///
+/// Gaining focus is reported right before the first [`WindowEvent::Focused`] that makes a window
+/// focused. Losing focus is only reported before [`Event::AboutToWait`], so moving the focus from
+/// one window of the application to another doesn't produce any event.
+fn track_application_focus<T: 'static, F>(
+    state: Rc<RefCell<ApplicationFocus>>,
+    mut event_handler: F,
+) -> impl FnMut(Event<T>, &EventLoopWindowTarget<T>, &mut ControlFlow)
+where
+    F: FnMut(Event<T>, &EventLoopWindowTarget<T>, &mut ControlFlow),
+{
+    move |event, event_loop, control_flow| {
+        let changed = {
+            let mut state = state.borrow_mut();
+            match &event {
+                Event::WindowEvent {
+                    window_id,
+                    event: WindowEvent::Focused(true),
+                } => {
+                    state.focused_windows.insert(*window_id);
+                    (!state.reported).then_some(true)
+                }
+                Event::WindowEvent {
+                    window_id,
+                    event: WindowEvent::Focused(false) | WindowEvent::Destroyed,
+                } => {
+                    state.focused_windows.remove(window_id);
+                    None
+                }
+                Event::AboutToWait if state.reported && state.focused_windows.is_empty() => {
+                    Some(false)
+                }
+                _ => None,
+            }
+        };
+
+        if let Some(focused) = changed {
+            state.borrow_mut().reported = focused;
+            event_handler(Event::ApplicationFocused(focused), event_loop, control_flow);
+        }
+
+        event_handler(event, event_loop, control_flow);
+    }
+}
+
+/// Wrap the user's event handler to apply the given [`CoalescePolicy`].
+///
+/// The event loop target `W` is passed through untouched, it's generic so the coalescing can be
+/// tested without an event loop.
+pub(crate) fn coalesce_events<T: 'static, W, F>(
+    policy: CoalescePolicy,
+    mut event_handler: F,
+) -> impl FnMut(Event<T>, &W, &mut ControlFlow)
+where
+    F: FnMut(Event<T>, &W, &mut ControlFlow),
+{
+    let mut pending: Option<Event<T>> = None;
+    move |event, event_loop, control_flow| {
+        if policy == CoalescePolicy::Raw {
+            return event_handler(event, event_loop, control_flow);
+        }
+
+        let event = match pending.as_mut() {
+            Some(previous) => match merge_events(previous, event) {
+                Some(event) => {
+                    event_handler(pending.take().unwrap(), event_loop, control_flow);
+                    event
+                }
+                None => return,
+            },
+            None => event,
+        };
+
+        if is_coalescable(&event) {
+            pending = Some(event);
+        } else {
+            event_handler(event, event_loop, control_flow);
+        }
+    }
+}
+
+fn is_coalescable<T: 'static>(event: &Event<T>) -> bool {
+    matches!(
+        event,
+        Event::WindowEvent {
+            event: WindowEvent::Pointer {
+                event: PointerEvent::Moved(_),
+                ..
+            } | WindowEvent::CursorMoved { .. }
+                | WindowEvent::PointerMoved { .. }
+                | WindowEvent::MouseWheel {
+                    phase: TouchPhase::Moved,
+                    ..
+                }
+                | WindowEvent::Resized(_),
+            ..
+        } | Event::DeviceEvent {
+            event: DeviceEvent::MouseMotion { .. } | DeviceEvent::MouseWheel { .. },
+            ..
+        }
+    )
+}
+
+/// Merge `next` into `previous`, or give it back if the two can't be merged.
+fn merge_events<T: 'static>(previous: &mut Event<T>, next: Event<T>) -> Option<Event<T>> {
+    match (&mut *previous, &next) {
+        (
+            Event::WindowEvent {
+                window_id,
+                event: previous_event,
+            },
+            Event::WindowEvent {
+                window_id: next_window_id,
+                event: next_event,
+            },
+        ) if window_id == next_window_id => match (previous_event, next_event) {
+            // Events that carry a state rather than a delta are replaced by the newer one.
+            (
+                WindowEvent::Pointer {
+                    device_id,
+                    pointer_id,
+                    event: PointerEvent::Moved(_),
+                },
+                WindowEvent::Pointer {
+                    device_id: next_device_id,
+                    pointer_id: next_pointer_id,
+                    event: PointerEvent::Moved(_),
+                },
+            ) if device_id == next_device_id && pointer_id == next_pointer_id => {
+                *previous = next;
+                None
+            }
+            (
+                WindowEvent::CursorMoved { device_id, .. },
+                WindowEvent::CursorMoved {
+                    device_id: next_device_id,
+                    ..
+                },
+            ) if device_id == next_device_id => {
+                *previous = next;
+                None
+            }
+            (
+                WindowEvent::PointerMoved {
+                    device_id, source, ..
+                },
+                WindowEvent::PointerMoved {
+                    device_id: next_device_id,
+                    source: next_source,
+                    ..
+                },
+            ) if device_id == next_device_id && source == next_source => {
+                *previous = next;
+                None
+            }
+            (WindowEvent::Resized(_), WindowEvent::Resized(_)) => {
+                *previous = next;
+                None
+            }
+            (
+                WindowEvent::MouseWheel {
+                    device_id,
+                    delta,
+                    phase: TouchPhase::Moved,
+                },
+                WindowEvent::MouseWheel {
+                    device_id: next_device_id,
+                    delta: next_delta,
+                    phase: TouchPhase::Moved,
+                },
+            ) if device_id == next_device_id => {
+                if add_scroll_deltas(delta, next_delta) {
+                    None
+                } else {
+                    Some(next)
+                }
+            }
+            _ => Some(next),
+        },
+        (
+            Event::DeviceEvent {
+                device_id,
+                event: previous_event,
+            },
+            Event::DeviceEvent {
+                device_id: next_device_id,
+                event: next_event,
+            },
+        ) if device_id == next_device_id => match (previous_event, next_event) {
+            (
+                DeviceEvent::MouseMotion { delta: (x, y) },
+                DeviceEvent::MouseMotion {
+                    delta: (next_x, next_y),
+                },
+            ) => {
+                *x += next_x;
+                *y += next_y;
+                None
+            }
+            (DeviceEvent::MouseWheel { delta }, DeviceEvent::MouseWheel { delta: next_delta }) => {
+                if add_scroll_deltas(delta, next_delta) {
+                    None
+                } else {
+                    Some(next)
+                }
+            }
+            _ => Some(next),
+        },
+        _ => Some(next),
+    }
+}
+
+/// Add `next_delta` to `delta`, returning whether they were of the same kind.
+fn add_scroll_deltas(delta: &mut MouseScrollDelta, next_delta: &MouseScrollDelta) -> bool {
+    match (delta, next_delta) {
+        (MouseScrollDelta::LineDelta(x, y), MouseScrollDelta::LineDelta(next_x, next_y)) => {
+            *x += next_x;
+            *y += next_y;
+            true
+        }
+        (MouseScrollDelta::PixelDelta(delta), MouseScrollDelta::PixelDelta(next_delta)) => {
+            delta.x += next_delta.x;
+            delta.y += next_delta.y;
+            true
+        }
+        _ => false,
+    }
+}
+
+type WindowHandler<T> = dyn FnMut(WindowEvent, &EventLoopWindowTarget<T>, &mut ControlFlow);
+type FallbackHandler<T> = dyn FnMut(Event<T>, &EventLoopWindowTarget<T>, &mut ControlFlow);
+
+/// Dispatch window events to handlers registered per [`WindowId`].
+///
+/// Every [`Event::WindowEvent`] for a window with a registered handler is passed to that handler;
+/// all other events, including window events for windows without a handler, go to the fallback.
+/// The handler of a window is removed after it received [`WindowEvent::Destroyed`].
+///
+/// The router doesn't run the event loop by itself; call [`handle_event`] from the closure passed
+/// to [`EventLoop::run`], which also allows registering handlers for windows created later on.
+///
+/// ```no_run
+/// use winit::event::{Event, WindowEvent};
+/// use winit::event_loop::{EventLoop, WindowRouter};
+/// use winit::window::Window;
+///
+/// let event_loop = EventLoop::new();
+/// let window = Window::new(&event_loop).unwrap();
+///
+/// let mut router = WindowRouter::new(|event, _, _| {
+///     if let Event::AboutToWait = event {
+///         // Application-wide work.
+///     }
+/// });
+/// router.insert(window.id(), move |event, _, control_flow| {
+///     if let WindowEvent::CloseRequested = event {
+///         control_flow.set_exit();
+///     }
+/// });
+///
+/// event_loop.run(move |event, event_loop, control_flow| {
+///     router.handle_event(event, event_loop, control_flow);
+/// });
+/// ```
+///
+/// [`handle_event`]: Self::handle_event
+pub struct WindowRouter<T: 'static> {
+    handlers: HashMap<WindowId, Box<WindowHandler<T>>>,
+    fallback: Box<FallbackHandler<T>>,
+}
+
+impl<T: 'static> WindowRouter<T> {
+    /// Create a router without any window handlers, passing every event to `fallback`.
+    pub fn new<F>(fallback: F) -> Self
+    where
+        F: 'static + FnMut(Event<T>, &EventLoopWindowTarget<T>, &mut ControlFlow),
+    {
+        Self {
+            handlers: HashMap::new(),
+            fallback: Box::new(fallback),
+        }
+    }
+
+    /// Register the handler for the window events of `window_id`.
+    ///
+    /// Returns `true` if this replaced a previously registered handler.
+    pub fn insert<F>(&mut self, window_id: WindowId, handler: F) -> bool
+    where
+        F: 'static + FnMut(WindowEvent, &EventLoopWindowTarget<T>, &mut ControlFlow),
+    {
+        self.handlers.insert(window_id, Box::new(handler)).is_some()
+    }
+
+    /// Unregister the handler of `window_id`, so its events go to the fallback again.
+    ///
+    /// Returns `true` if a handler was registered.
+    pub fn remove(&mut self, window_id: WindowId) -> bool {
+        self.handlers.remove(&window_id).is_some()
+    }
+
+    /// Whether a handler is registered for `window_id`.
+    pub fn contains(&self, window_id: WindowId) -> bool {
+        self.handlers.contains_key(&window_id)
+    }
+
+    /// Pass `event` to the handler of its window, or to the fallback.
+    pub fn handle_event(
+        &mut self,
+        event: Event<T>,
+        event_loop: &EventLoopWindowTarget<T>,
+        control_flow: &mut ControlFlow,
+    ) {
+        match self.route(event) {
+            Route::Window(window_id, event) => {
+                let destroyed = matches!(event, WindowEvent::Destroyed);
+                if let Some(handler) = self.handlers.get_mut(&window_id) {
+                    handler(event, event_loop, control_flow);
+                }
+
+                if destroyed {
+                    self.handlers.remove(&window_id);
+                }
+            }
+            Route::Fallback(event) => (self.fallback)(event, event_loop, control_flow),
+        }
+    }
+
+    fn route(&self, event: Event<T>) -> Route<T> {
+        match event {
+            Event::WindowEvent { window_id, event } if self.contains(window_id) => {
+                Route::Window(window_id, event)
+            }
+            event => Route::Fallback(event),
+        }
+    }
+}
+
+/// The handler a [`WindowRouter`] passes an event to.
+#[derive(Debug, PartialEq)]
+enum Route<T: 'static> {
+    Window(WindowId, WindowEvent),
+    Fallback(Event<T>),
+}
+
+impl<T: 'static> fmt::Debug for WindowRouter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WindowRouter")
+            .field("windows", &self.handlers.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
 /// A unique identifier of the winit's async request.
 ///
 /// This could be used to identify the async request once it's done
@@ -474,3 +1277,272 @@ impl AsyncRequestSerial {
         Self { serial }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dpi::{PhysicalPosition, PhysicalSize};
+    use crate::event::{DeviceId, PointerId};
+    use std::sync::{mpsc, Barrier};
+
+    fn window_event(window_id: u64, event: WindowEvent) -> Event<()> {
+        Event::WindowEvent {
+            window_id: WindowId::from(window_id),
+            event,
+        }
+    }
+
+    fn moved(window_id: u64, x: f64) -> Event<()> {
+        window_event(
+            window_id,
+            WindowEvent::Pointer {
+                device_id: unsafe { DeviceId::dummy() },
+                pointer_id: PointerId::Cursor,
+                event: PointerEvent::Moved(PhysicalPosition::new(x, 0.0)),
+            },
+        )
+    }
+
+    fn cursor_moved(window_id: u64, x: f64) -> Event<()> {
+        window_event(
+            window_id,
+            WindowEvent::CursorMoved {
+                device_id: unsafe { DeviceId::dummy() },
+                position: PhysicalPosition::new(x, 0.0),
+            },
+        )
+    }
+
+    fn pointer_moved(source: PointerId, x: f64) -> Event<()> {
+        window_event(
+            0,
+            WindowEvent::PointerMoved {
+                device_id: unsafe { DeviceId::dummy() },
+                source,
+                location: PhysicalPosition::new(x, 0.0),
+                force: None,
+            },
+        )
+    }
+
+    fn wheel(delta: MouseScrollDelta) -> Event<()> {
+        window_event(
+            0,
+            WindowEvent::MouseWheel {
+                device_id: unsafe { DeviceId::dummy() },
+                delta,
+                phase: TouchPhase::Moved,
+            },
+        )
+    }
+
+    fn motion(x: f64) -> Event<()> {
+        Event::DeviceEvent {
+            device_id: unsafe { DeviceId::dummy() },
+            event: DeviceEvent::MouseMotion { delta: (x, 0.0) },
+        }
+    }
+
+    #[test]
+    fn merge_events_keeps_the_last_pointer_position() {
+        let mut previous = moved(0, 1.0);
+        assert!(merge_events(&mut previous, moved(0, 2.0)).is_none());
+        assert_eq!(previous, moved(0, 2.0));
+    }
+
+    #[test]
+    fn merge_events_keeps_the_last_size() {
+        let resized = |width| window_event(0, WindowEvent::Resized(PhysicalSize::new(width, 1)));
+
+        let mut previous = resized(1);
+        assert!(merge_events(&mut previous, resized(2)).is_none());
+        assert_eq!(previous, resized(2));
+    }
+
+    #[test]
+    fn merge_events_doesnt_merge_events_of_different_windows() {
+        let mut previous = moved(0, 1.0);
+        assert_eq!(
+            merge_events(&mut previous, moved(1, 2.0)),
+            Some(moved(1, 2.0))
+        );
+        assert_eq!(previous, moved(0, 1.0));
+    }
+
+    #[test]
+    fn merge_events_sums_scroll_deltas_of_the_same_kind() {
+        let mut previous = wheel(MouseScrollDelta::LineDelta(1.0, 2.0));
+        assert!(
+            merge_events(&mut previous, wheel(MouseScrollDelta::LineDelta(3.0, 4.0))).is_none()
+        );
+        assert_eq!(previous, wheel(MouseScrollDelta::LineDelta(4.0, 6.0)));
+
+        let pixels = wheel(MouseScrollDelta::PixelDelta(PhysicalPosition::new(
+            1.0, 1.0,
+        )));
+        assert_eq!(merge_events(&mut previous, pixels.clone()), Some(pixels));
+        assert_eq!(previous, wheel(MouseScrollDelta::LineDelta(4.0, 6.0)));
+    }
+
+    #[test]
+    fn merge_events_sums_mouse_motion() {
+        let mut previous = motion(1.0);
+        assert!(merge_events(&mut previous, motion(2.0)).is_none());
+        assert_eq!(previous, motion(3.0));
+    }
+
+    fn coalesce(policy: CoalescePolicy, events: Vec<Event<()>>) -> Vec<Event<()>> {
+        let mut delivered = Vec::new();
+        let mut handler = coalesce_events(policy, |event, _: &(), _| delivered.push(event));
+        for event in events {
+            handler(event, &(), &mut ControlFlow::default());
+        }
+        drop(handler);
+        delivered
+    }
+
+    #[test]
+    fn coalesce_events_delivers_merged_events_before_the_next_one() {
+        let delivered = coalesce(
+            CoalescePolicy::Coalesced,
+            vec![
+                moved(0, 1.0),
+                moved(0, 2.0),
+                Event::AboutToWait,
+                moved(0, 3.0),
+            ],
+        );
+        assert_eq!(delivered, vec![moved(0, 2.0), Event::AboutToWait]);
+    }
+
+    #[test]
+    fn coalesce_events_merges_the_motion_the_backends_send() {
+        let delivered = coalesce(
+            CoalescePolicy::Coalesced,
+            vec![
+                cursor_moved(0, 1.0),
+                cursor_moved(0, 2.0),
+                cursor_moved(1, 3.0),
+                pointer_moved(PointerId::Cursor, 4.0),
+                pointer_moved(PointerId::Cursor, 5.0),
+                pointer_moved(PointerId::Touch { finger: 1 }, 6.0),
+                pointer_moved(PointerId::Touch { finger: 1 }, 7.0),
+                Event::AboutToWait,
+            ],
+        );
+        assert_eq!(
+            delivered,
+            vec![
+                cursor_moved(0, 2.0),
+                cursor_moved(1, 3.0),
+                pointer_moved(PointerId::Cursor, 5.0),
+                pointer_moved(PointerId::Touch { finger: 1 }, 7.0),
+                Event::AboutToWait,
+            ]
+        );
+    }
+
+    #[test]
+    fn coalesce_events_passes_everything_through_when_raw() {
+        let events = vec![moved(0, 1.0), moved(0, 2.0), Event::AboutToWait];
+        assert_eq!(coalesce(CoalescePolicy::Raw, events.clone()), events);
+    }
+
+    #[test]
+    fn window_router_routes_events_of_registered_windows() {
+        let mut router = WindowRouter::<()>::new(|_, _, _| ());
+        assert!(!router.insert(WindowId::from(1), |_, _, _| ()));
+
+        assert_eq!(
+            router.route(window_event(1, WindowEvent::CloseRequested)),
+            Route::Window(WindowId::from(1), WindowEvent::CloseRequested)
+        );
+        assert_eq!(
+            router.route(window_event(2, WindowEvent::CloseRequested)),
+            Route::Fallback(window_event(2, WindowEvent::CloseRequested))
+        );
+        assert_eq!(
+            router.route(Event::AboutToWait),
+            Route::Fallback(Event::AboutToWait)
+        );
+    }
+
+    #[test]
+    fn window_router_tracks_registered_windows() {
+        let mut router = WindowRouter::<()>::new(|_, _, _| ());
+        assert!(!router.insert(WindowId::from(1), |_, _, _| ()));
+        assert!(router.insert(WindowId::from(1), |_, _, _| ()));
+        assert!(router.contains(WindowId::from(1)));
+
+        assert!(router.remove(WindowId::from(1)));
+        assert!(!router.remove(WindowId::from(1)));
+        assert_eq!(
+            router.route(window_event(1, WindowEvent::CloseRequested)),
+            Route::Fallback(window_event(1, WindowEvent::CloseRequested))
+        );
+    }
+
+    #[test]
+    fn user_event_queue_reserves_up_to_the_limit() {
+        let queue = UserEventQueue::new(2, UserEventOverflow::DropNewest);
+        assert!(queue.reserve());
+        assert!(queue.reserve());
+        assert!(!queue.reserve());
+
+        queue.delivered();
+        assert!(queue.reserve());
+    }
+
+    #[test]
+    fn user_event_queue_drops_on_the_event_loop_thread_when_blocking() {
+        let queue = UserEventQueue::new(1, UserEventOverflow::Block);
+        assert!(queue.reserve());
+        assert!(!queue.reserve());
+    }
+
+    #[test]
+    fn user_event_queue_blocks_other_threads_until_delivered() {
+        let queue = Arc::new(UserEventQueue::new(1, UserEventOverflow::Block));
+        assert!(queue.reserve());
+
+        let started = Arc::new(Barrier::new(2));
+        let (reserved_tx, reserved_rx) = mpsc::channel();
+        let sender = {
+            let queue = queue.clone();
+            let started = started.clone();
+            thread::spawn(move || {
+                started.wait();
+                reserved_tx.send(queue.reserve()).unwrap();
+            })
+        };
+        started.wait();
+        assert_eq!(reserved_rx.try_recv(), Err(mpsc::TryRecvError::Empty));
+
+        queue.delivered();
+        assert_eq!(reserved_rx.recv(), Ok(true));
+        sender.join().unwrap();
+        assert_eq!(queue.state.lock().unwrap().pending, 1);
+    }
+
+    #[test]
+    fn user_event_queue_releases_blocked_threads_on_close() {
+        let queue = Arc::new(UserEventQueue::new(1, UserEventOverflow::Block));
+        assert!(queue.reserve());
+
+        let started = Arc::new(Barrier::new(2));
+        let sender = {
+            let queue = queue.clone();
+            let started = started.clone();
+            thread::spawn(move || {
+                started.wait();
+                queue.reserve()
+            })
+        };
+        started.wait();
+
+        drop(UserEventQueueOwner(queue.clone()));
+        // The event is passed on, sending it then reports the closed event loop.
+        assert!(sender.join().unwrap());
+        assert!(queue.reserve());
+    }
+}
@@ -69,6 +69,10 @@
 //
 // --------- END OF W3C SHORT NOTICE ---------------------------------------------------------------
 
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
 pub use smol_str::SmolStr;
 
 /// Contains the platform-native physical key identifier
@@ -522,6 +526,27 @@ pub enum KeyCode {
     AudioVolumeMute,
     AudioVolumeUp,
     WakeUp,
+    /// Found on keyboards with separate play and pause keys.
+    MediaPlay,
+    /// Found on keyboards with separate play and pause keys.
+    MediaPause,
+    MediaRecord,
+    MediaFastForward,
+    MediaRewind,
+    /// Mutes the microphone. Found on the function row of many laptops.
+    MicrophoneMute,
+    /// Lowers the display brightness. Usually on the <kbd>Fn</kbd> layer of laptop keyboards.
+    BrightnessDown,
+    /// Raises the display brightness. Usually on the <kbd>Fn</kbd> layer of laptop keyboards.
+    BrightnessUp,
+    /// Switches between the internal and external displays.
+    DisplayToggle,
+    /// Lowers the keyboard backlight brightness.
+    KeyboardBacklightDown,
+    /// Raises the keyboard backlight brightness.
+    KeyboardBacklightUp,
+    /// Turns the keyboard backlight on or off.
+    KeyboardBacklightToggle,
     // Legacy modifier key. Also called "Super" in certain places.
     Meta,
     // Legacy modifier key.
@@ -648,6 +673,83 @@ pub enum KeyCode {
     F35,
 }
 
+macro_rules! key_code_names {
+    ($($name:ident),* $(,)?) => {
+        impl KeyCode {
+            /// Returns the layout-independent name of the physical key.
+            ///
+            /// This is the name of the variant, which matches the [`KeyboardEvent.code`] value
+            /// of the key for all keys defined there, e.g. `"KeyA"` or `"ShiftLeft"`. It can be
+            /// parsed back with [`str::parse`], which makes it suitable to store key bindings.
+            ///
+            /// Returns `None` for [`KeyCode::Unidentified`].
+            ///
+            /// ```
+            /// use winit::keyboard::KeyCode;
+            ///
+            /// assert_eq!(KeyCode::KeyQ.to_str(), Some("KeyQ"));
+            /// assert_eq!("KeyQ".parse(), Ok(KeyCode::KeyQ));
+            /// ```
+            ///
+            /// [`KeyboardEvent.code`]: https://w3c.github.io/uievents-code/#code-value-tables
+            pub fn to_str(&self) -> Option<&'static str> {
+                match self {
+                    KeyCode::Unidentified(_) => None,
+                    $( KeyCode::$name => Some(stringify!($name)), )*
+                }
+            }
+        }
+
+        impl FromStr for KeyCode {
+            type Err = ParseKeyCodeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $( stringify!($name) => Ok(KeyCode::$name), )*
+                    _ => Err(ParseKeyCodeError),
+                }
+            }
+        }
+    };
+}
+
+key_code_names! {
+    Backquote, Backslash, BracketLeft, BracketRight, Comma, Digit0, Digit1, Digit2, Digit3,
+    Digit4, Digit5, Digit6, Digit7, Digit8, Digit9, Equal, IntlBackslash, IntlRo, IntlYen, KeyA,
+    KeyB, KeyC, KeyD, KeyE, KeyF, KeyG, KeyH, KeyI, KeyJ, KeyK, KeyL, KeyM, KeyN, KeyO, KeyP,
+    KeyQ, KeyR, KeyS, KeyT, KeyU, KeyV, KeyW, KeyX, KeyY, KeyZ, Minus, Period, Quote, Semicolon,
+    Slash, AltLeft, AltRight, Backspace, CapsLock, ContextMenu, ControlLeft, ControlRight,
+    Enter, SuperLeft, SuperRight, ShiftLeft, ShiftRight, Space, Tab, Convert, KanaMode, Lang1,
+    Lang2, Lang3, Lang4, Lang5, NonConvert, Delete, End, Help, Home, Insert, PageDown, PageUp,
+    ArrowDown, ArrowLeft, ArrowRight, ArrowUp, NumLock, Numpad0, Numpad1, Numpad2, Numpad3,
+    Numpad4, Numpad5, Numpad6, Numpad7, Numpad8, Numpad9, NumpadAdd, NumpadBackspace,
+    NumpadClear, NumpadClearEntry, NumpadComma, NumpadDecimal, NumpadDivide, NumpadEnter,
+    NumpadEqual, NumpadHash, NumpadMemoryAdd, NumpadMemoryClear, NumpadMemoryRecall,
+    NumpadMemoryStore, NumpadMemorySubtract, NumpadMultiply, NumpadParenLeft, NumpadParenRight,
+    NumpadStar, NumpadSubtract, Escape, Fn, FnLock, PrintScreen, ScrollLock, Pause, BrowserBack,
+    BrowserFavorites, BrowserForward, BrowserHome, BrowserRefresh, BrowserSearch, BrowserStop,
+    Eject, LaunchApp1, LaunchApp2, LaunchMail, MediaPlayPause, MediaSelect, MediaStop,
+    MediaTrackNext, MediaTrackPrevious, Power, Sleep, AudioVolumeDown, AudioVolumeMute,
+    AudioVolumeUp, WakeUp, MediaPlay, MediaPause, MediaRecord, MediaFastForward, MediaRewind,
+    MicrophoneMute, BrightnessDown, BrightnessUp, DisplayToggle, KeyboardBacklightDown,
+    KeyboardBacklightUp, KeyboardBacklightToggle, Meta, Hyper, Turbo, Abort, Resume, Suspend, Again,
+    Copy, Cut, Find, Open, Paste, Props, Select, Undo, Hiragana, Katakana, F1, F2, F3, F4, F5, F6,
+    F7, F8, F9, F10, F11, F12, F13, F14, F15, F16, F17, F18, F19, F20, F21, F22, F23, F24, F25, F26,
+    F27, F28, F29, F30, F31, F32, F33, F34, F35
+}
+
+/// The error returned when a string is not the name of a [`KeyCode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseKeyCodeError;
+
+impl fmt::Display for ParseKeyCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown key code name")
+    }
+}
+
+impl Error for ParseKeyCodeError {}
+
 /// Key represents the meaning of a keypress.
 ///
 /// This mostly conforms to the UI Events Specification's [`KeyboardEvent.key`] with a few
@@ -826,6 +928,12 @@ pub enum Key<Str = SmolStr> {
     BrightnessDown,
     /// The Brightness Up key. Typically controls the display brightness. (`KEYCODE_BRIGHTNESS_UP`)
     BrightnessUp,
+    /// Lowers the keyboard backlight brightness. (`XF86KbdBrightnessDown`)
+    KeyboardBacklightDown,
+    /// Raises the keyboard backlight brightness. (`XF86KbdBrightnessUp`)
+    KeyboardBacklightUp,
+    /// Turns the keyboard backlight on or off. (`XF86KbdLightOnOff`)
+    KeyboardBacklightToggle,
     /// Toggle removable media to eject (open) and insert (close) state. (`KEYCODE_MEDIA_EJECT`)
     Eject,
     LogOff,
@@ -1419,7 +1527,8 @@ impl Key<SmolStr> {
                 ArrowRight, ArrowUp, End, Home, PageDown, PageUp, Backspace, Clear, Copy, CrSel,
                 Cut, Delete, EraseEof, ExSel, Insert, Paste, Redo, Undo, Accept, Again, Attn,
                 Cancel, ContextMenu, Escape, Execute, Find, Help, Pause, Play, Props, Select,
-                ZoomIn, ZoomOut, BrightnessDown, BrightnessUp, Eject, LogOff, Power, PowerOff,
+                ZoomIn, ZoomOut, BrightnessDown, BrightnessUp, KeyboardBacklightDown,
+                KeyboardBacklightUp, KeyboardBacklightToggle, Eject, LogOff, Power, PowerOff,
                 PrintScreen, Hibernate, Standby, WakeUp, AllCandidates, Alphanumeric, CodeInput,
                 Compose, Convert, FinalMode, GroupFirst, GroupLast, GroupNext, GroupPrevious,
                 ModeChange, NextCandidate, NonConvert, PreviousCandidate, Process, SingleCandidate,
@@ -1488,6 +1597,35 @@ impl Key {
             _ => None,
         }
     }
+
+    /// Returns a name for the key that is suitable to show to the user, e.g. in a key binding UI.
+    ///
+    /// Since [`KeyEvent::logical_key`] follows the active keyboard layout, this is how the key is
+    /// labeled on the user's keyboard: the key in the position of <kbd>Q</kbd> on a QWERTY layout
+    /// is shown as `"A"` on an AZERTY layout. Use [`KeyCode::to_str`] for a layout-independent
+    /// name.
+    ///
+    /// Characters are shown in upper case, dead keys as the character they insert, and all
+    /// other keys by the name of their variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use winit::keyboard::Key;
+    ///
+    /// assert_eq!(Key::Character("q".into()).display_name(), "Q");
+    /// assert_eq!(Key::PageDown.display_name(), "PageDown");
+    /// ```
+    ///
+    /// [`KeyEvent::logical_key`]: crate::event::KeyEvent::logical_key
+    pub fn display_name(&self) -> String {
+        match self {
+            Key::Character(ch) => ch.to_uppercase(),
+            Key::Dead(Some(ch)) => ch.to_string(),
+            Key::Dead(None) => "Dead".to_owned(),
+            key => format!("{key:?}"),
+        }
+    }
 }
 
 /// The location of the key on the keyboard.
@@ -1582,6 +1720,22 @@ bitflags! {
         const ALT = 0b100 << 6;
         /// This is the "windows" key on PC and "command" key on Mac.
         const SUPER = 0b100 << 9;
+        /// The "AltGr" key, used by many layouts to type additional characters.
+        ///
+        /// While it's held, [`CONTROL`] and [`ALT`] are not set on its behalf, so shortcuts
+        /// like `Ctrl+Alt+...` don't trigger while typing characters such as `@` or `{`.
+        ///
+        /// ## Platform-specific
+        ///
+        /// - **Windows:** Only set if the layout has an AltGr key. The system presses a fake
+        ///   left Ctrl along with it, so the left Ctrl key can't be detected while AltGr is held.
+        /// - **X11 / Wayland:** Set while the level 3 modifier (`Mod5`) is active, which is
+        ///   where `ISO_Level3_Shift` is mapped by common layouts.
+        /// - **macOS / iOS / Android / Web / Orbital:** Unsupported.
+        ///
+        /// [`CONTROL`]: Self::CONTROL
+        /// [`ALT`]: Self::ALT
+        const ALT_GRAPH = 0b100 << 12;
     }
 }
 
@@ -1602,6 +1756,40 @@ impl ModifiersState {
     pub fn super_key(&self) -> bool {
         self.intersects(Self::SUPER)
     }
+    /// Returns `true` if the AltGr key is pressed.
+    pub fn alt_graph_key(&self) -> bool {
+        self.intersects(Self::ALT_GRAPH)
+    }
+}
+
+bitflags! {
+    /// Represents the current state of the keyboard lock keys.
+    ///
+    /// Each flag is set if the lock is engaged, which is usually indicated by the LED on the key.
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct LockKeyState: u8 {
+        /// The "caps lock" key.
+        const CAPS_LOCK = 0b001;
+        /// The "num lock" key.
+        const NUM_LOCK = 0b010;
+        /// The "scroll lock" key.
+        const SCROLL_LOCK = 0b100;
+    }
+}
+
+impl LockKeyState {
+    /// Returns `true` if caps lock is engaged.
+    pub fn caps_lock(&self) -> bool {
+        self.intersects(Self::CAPS_LOCK)
+    }
+    /// Returns `true` if num lock is engaged.
+    pub fn num_lock(&self) -> bool {
+        self.intersects(Self::NUM_LOCK)
+    }
+    /// Returns `true` if scroll lock is engaged.
+    pub fn scroll_lock(&self) -> bool {
+        self.intersects(Self::SCROLL_LOCK)
+    }
 }
 
 /// The state of the particular modifiers key.
@@ -1649,6 +1837,7 @@ mod modifiers_serde {
         pub control_key: bool,
         pub alt_key: bool,
         pub super_key: bool,
+        pub alt_graph_key: bool,
     }
 
     impl Serialize for ModifiersState {
@@ -1661,6 +1850,7 @@ mod modifiers_serde {
                 control_key: self.control_key(),
                 alt_key: self.alt_key(),
                 super_key: self.super_key(),
+                alt_graph_key: self.alt_graph_key(),
             };
             s.serialize(serializer)
         }
@@ -1676,12 +1866,14 @@ mod modifiers_serde {
                 control_key,
                 alt_key,
                 super_key,
+                alt_graph_key,
             } = ModifiersStateSerialize::deserialize(deserializer)?;
             let mut m = ModifiersState::empty();
             m.set(ModifiersState::SHIFT, shift_key);
             m.set(ModifiersState::CONTROL, control_key);
             m.set(ModifiersState::ALT, alt_key);
             m.set(ModifiersState::SUPER, super_key);
+            m.set(ModifiersState::ALT_GRAPH, alt_graph_key);
             Ok(m)
         }
     }
@@ -117,6 +117,71 @@ impl MonitorHandle {
         self.inner.name()
     }
 
+    /// Returns a human-readable name of the monitor, like "DELL U2720Q", falling back to
+    /// [`MonitorHandle::name`] when there is none.
+    ///
+    /// Prefer this over [`MonitorHandle::name`] when letting users pick a monitor, since the
+    /// latter is often just the name of the connector the monitor is plugged into.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Uses the monitor name from the monitor's EDID.
+    /// - **Wayland:** Uses the description advertised by the compositor.
+    /// - **Windows:** Uses the friendly name of the monitor's display configuration target.
+    /// - **Android / iOS / macOS / Orbital / Web:** Always falls back to [`MonitorHandle::name`].
+    #[inline]
+    pub fn friendly_name(&self) -> Option<String> {
+        self.inner.friendly_name().or_else(|| self.name())
+    }
+
+    /// Returns the manufacturer of the monitor.
+    ///
+    /// Together with [`MonitorHandle::model`] this allows presenting the monitor to users as
+    /// e.g. "DEL DELL U2720Q" rather than by the name of the connector it's plugged into.
+    ///
+    /// Note that the format of the manufacturer differs between platforms, so it shouldn't be
+    /// compared across them.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11 / Windows:** Returns the three letter PNP manufacturer ID from the monitor's EDID,
+    ///   like `DEL`.
+    /// - **Wayland:** Returns the make advertised by the compositor, which is free-form text. It
+    ///   may be the PNP ID as well as the full name of the manufacturer, like `Dell Inc.`.
+    /// - **Android / iOS / macOS / Orbital / Web:** Always returns `None`.
+    #[inline]
+    pub fn make(&self) -> Option<String> {
+        self.inner.make()
+    }
+
+    /// Returns the model name of the monitor.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Returns the monitor name from the monitor's EDID, if it contains one.
+    /// - **Wayland:** Returns the model advertised by the compositor.
+    /// - **Windows:** Returns the friendly name of the monitor's display configuration target.
+    /// - **Android / iOS / macOS / Orbital / Web:** Always returns `None`.
+    #[inline]
+    pub fn model(&self) -> Option<String> {
+        self.inner.model()
+    }
+
+    /// Returns the physical size of the monitor in millimeters.
+    ///
+    /// Together with [`MonitorHandle::size`] this gives the real pixel density of the monitor.
+    /// Note that the reported size isn't always accurate.
+    ///
+    /// Returns `None` if the size is unknown, e.g. for projectors.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Android / iOS / Orbital / Web / Windows:** Always returns `None`.
+    #[inline]
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        self.inner.physical_size_mm()
+    }
+
     /// Returns the monitor's resolution.
     ///
     /// ## Platform-specific
@@ -138,6 +203,25 @@ impl MonitorHandle {
         self.inner.position()
     }
 
+    /// Returns the part of the monitor that is available to windows, i.e. the monitor area
+    /// without taskbars, docks and panels, as the top-left corner position and size.
+    ///
+    /// The position is in the same coordinate space as [`MonitorHandle::position`].
+    ///
+    /// Returns `None` if the work area is unknown.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Uses `_NET_WORKAREA` of the current desktop, clipped to the monitor. The
+    ///   property spans the whole X screen, so panels on other monitors may reduce it, and it is
+    ///   only refreshed together with the monitor list.
+    /// - **macOS:** Uses the `visibleFrame` of the screen, which also excludes the menu bar.
+    /// - **Wayland / Android / iOS / Orbital / Web:** Always returns `None`.
+    #[inline]
+    pub fn work_area(&self) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+        self.inner.work_area()
+    }
+
     /// The monitor refresh rate used by the system.
     ///
     /// Return `Some` if succeed, or `None` if failed, which usually happens when the monitor
@@ -176,3 +260,77 @@ impl MonitorHandle {
             .map(|video_mode| VideoMode { video_mode })
     }
 }
+
+/// Compare the monitors available before and after a change of the display configuration,
+/// returning the disconnected and the connected ones, where `same` tells if two handles refer
+/// to the same monitor.
+#[cfg_attr(not(any(x11_platform, windows_platform)), allow(dead_code))]
+pub(crate) fn monitor_changes<'a, M>(
+    prev: &'a [M],
+    new: &'a [M],
+    same: impl Fn(&M, &M) -> bool,
+) -> (Vec<&'a M>, Vec<&'a M>) {
+    let is_in = |list: &[M], monitor: &M| list.iter().any(|other| same(other, monitor));
+    let disconnected = prev.iter().filter(|monitor| !is_in(new, monitor)).collect();
+    let connected = new.iter().filter(|monitor| !is_in(prev, monitor)).collect();
+    (disconnected, connected)
+}
+
+/// Decodes a manufacturer PNP ID, as stored in big endian in an EDID, which consists of three
+/// letters encoded with five bits each.
+#[cfg_attr(not(any(x11_platform, windows_platform)), allow(dead_code))]
+pub(crate) fn decode_pnp_id(id: u16) -> Option<String> {
+    [10, 5, 0]
+        .iter()
+        .map(|shift| match (id >> shift) & 0x1f {
+            letter @ 1..=26 => Some((b'A' + letter as u8 - 1) as char),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_pnp_id_decodes_three_letters() {
+        assert_eq!(decode_pnp_id(0x10ac).as_deref(), Some("DEL"));
+        assert_eq!(decode_pnp_id(0x4c2d).as_deref(), Some("SAM"));
+    }
+
+    #[test]
+    fn decode_pnp_id_rejects_invalid_letters() {
+        assert_eq!(decode_pnp_id(0), None);
+        assert_eq!(decode_pnp_id(0x7fff), None);
+    }
+
+    #[test]
+    fn monitor_changes_reports_connected_and_disconnected_monitors() {
+        let (disconnected, connected) =
+            monitor_changes(&["DP-1", "HDMI-1"], &["HDMI-1", "DP-2"], |a, b| a == b);
+
+        assert_eq!(disconnected, [&"DP-1"]);
+        assert_eq!(connected, [&"DP-2"]);
+    }
+
+    #[test]
+    fn monitor_changes_ignores_unchanged_monitors() {
+        let (disconnected, connected) =
+            monitor_changes(&["DP-1", "HDMI-1"], &["HDMI-1", "DP-1"], |a, b| a == b);
+
+        assert!(disconnected.is_empty());
+        assert!(connected.is_empty());
+    }
+
+    #[test]
+    fn monitor_changes_handles_reconnecting_the_only_monitor() {
+        let (disconnected, connected) = monitor_changes(&[], &["DP-1"], |a: &&str, b| a == b);
+        assert!(disconnected.is_empty());
+        assert_eq!(connected, [&"DP-1"]);
+
+        let (disconnected, connected) = monitor_changes(&["DP-1"], &[], |a: &&str, b| a == b);
+        assert_eq!(disconnected, [&"DP-1"]);
+        assert!(connected.is_empty());
+    }
+}
@@ -1,4 +1,5 @@
 use std::os::raw::c_void;
+use std::path::PathBuf;
 
 use objc2::rc::Id;
 
@@ -38,6 +39,32 @@ pub trait WindowExtMacOS {
     /// Sets whether or not the window has shadow.
     fn set_has_shadow(&self, has_shadow: bool);
 
+    /// Returns whether click-and-drag on the window background moves the window.
+    fn is_movable_by_window_background(&self) -> bool;
+
+    /// Enables click-and-drag behavior for the entire window, not just the titlebar.
+    ///
+    /// Only the parts of the view that aren't handling mouse events themselves act as a drag
+    /// area. To make specific regions of a custom titlebar draggable, call
+    /// [`Window::drag_window`] when the left mouse button is pressed in them instead.
+    ///
+    /// [`Window::drag_window`]: crate::window::Window::drag_window
+    fn set_movable_by_window_background(&self, movable: bool);
+
+    /// Makes the titlebar transparent and allows the content to appear behind it.
+    ///
+    /// See [`WindowBuilderExtMacOS::with_titlebar_transparent`] for details.
+    fn set_titlebar_transparent(&self, transparent: bool);
+
+    /// Makes the window content appear behind the titlebar.
+    ///
+    /// Combine with [`WindowExtMacOS::set_titlebar_transparent`] to get a unified toolbar
+    /// layout.
+    fn set_fullsize_content_view(&self, fullsize: bool);
+
+    /// Hides or shows the window titlebar buttons.
+    fn set_titlebar_buttons_hidden(&self, hidden: bool);
+
     /// Group windows together by using the same tabbing identifier.
     ///
     /// <https://developer.apple.com/documentation/appkit/nswindow/1644704-tabbingidentifier>
@@ -47,19 +74,41 @@ pub trait WindowExtMacOS {
     fn tabbing_identifier(&self) -> String;
 
     /// Select next tab.
+    ///
+    /// The window of the newly selected tab receives [`WindowEvent::Focused(true)`], while the
+    /// previously selected one receives [`WindowEvent::Focused(false)`].
+    ///
+    /// [`WindowEvent::Focused(true)`]: crate::event::WindowEvent::Focused
+    /// [`WindowEvent::Focused(false)`]: crate::event::WindowEvent::Focused
     fn select_next_tab(&self);
 
     /// Select previous tab.
+    ///
+    /// See [`WindowExtMacOS::select_next_tab`] for the emitted events.
     fn select_previous_tab(&self);
 
     /// Select the tab with the given index.
     ///
     /// Will no-op when the index is out of bounds.
+    ///
+    /// See [`WindowExtMacOS::select_next_tab`] for the emitted events.
     fn select_tab_at_index(&self, index: usize);
 
     /// Get the number of tabs in the window tab group.
     fn num_tabs(&self) -> usize;
 
+    /// Merge all windows sharing the tabbing identifier of this window into a single tabbed
+    /// window.
+    ///
+    /// <https://developer.apple.com/documentation/appkit/nswindow/1644639-mergeallwindows>
+    fn merge_all_windows(&self);
+
+    /// Returns whether the tab bar of the window tab group is visible.
+    fn is_tab_bar_visible(&self) -> bool;
+
+    /// Shows or hides the tab bar of the window tab group.
+    fn set_tab_bar_visible(&self, visible: bool);
+
     /// Get the window's edit state.
     ///
     /// # Examples
@@ -79,6 +128,16 @@ pub trait WindowExtMacOS {
     /// Put the window in a state which indicates a file save is required.
     fn set_document_edited(&self, edited: bool);
 
+    /// Sets the file the window represents, showing its proxy icon in the titlebar.
+    ///
+    /// Providing `None` removes the proxy icon.
+    ///
+    /// <https://developer.apple.com/documentation/appkit/nswindow/1419631-representedfilename>
+    fn set_represented_filename(&self, path: Option<PathBuf>);
+
+    /// Returns the file the window represents, if any.
+    fn represented_filename(&self) -> Option<PathBuf>;
+
     /// Set option as alt behavior as described in [`OptionAsAlt`].
     ///
     /// This will ignore diacritical marks and accent characters from
@@ -122,6 +181,31 @@ impl WindowExtMacOS for Window {
         self.window.set_has_shadow(has_shadow)
     }
 
+    #[inline]
+    fn is_movable_by_window_background(&self) -> bool {
+        self.window.is_movable_by_window_background()
+    }
+
+    #[inline]
+    fn set_movable_by_window_background(&self, movable: bool) {
+        self.window.set_movable_by_window_background(movable)
+    }
+
+    #[inline]
+    fn set_titlebar_transparent(&self, transparent: bool) {
+        self.window.set_titlebar_transparent(transparent)
+    }
+
+    #[inline]
+    fn set_fullsize_content_view(&self, fullsize: bool) {
+        self.window.set_fullsize_content_view(fullsize)
+    }
+
+    #[inline]
+    fn set_titlebar_buttons_hidden(&self, hidden: bool) {
+        self.window.set_titlebar_buttons_hidden(hidden)
+    }
+
     #[inline]
     fn set_tabbing_identifier(&self, identifier: &str) {
         self.window.set_tabbing_identifier(identifier);
@@ -152,6 +236,21 @@ impl WindowExtMacOS for Window {
         self.window.num_tabs()
     }
 
+    #[inline]
+    fn merge_all_windows(&self) {
+        self.window.merge_all_windows();
+    }
+
+    #[inline]
+    fn is_tab_bar_visible(&self) -> bool {
+        self.window.is_tab_bar_visible()
+    }
+
+    #[inline]
+    fn set_tab_bar_visible(&self, visible: bool) {
+        self.window.set_tab_bar_visible(visible);
+    }
+
     #[inline]
     fn is_document_edited(&self) -> bool {
         self.window.is_document_edited()
@@ -162,6 +261,16 @@ impl WindowExtMacOS for Window {
         self.window.set_document_edited(edited)
     }
 
+    #[inline]
+    fn set_represented_filename(&self, path: Option<PathBuf>) {
+        self.window.set_represented_filename(path)
+    }
+
+    #[inline]
+    fn represented_filename(&self) -> Option<PathBuf> {
+        self.window.represented_filename()
+    }
+
     #[inline]
     fn set_option_as_alt(&self, option_as_alt: OptionAsAlt) {
         self.window.set_option_as_alt(option_as_alt)
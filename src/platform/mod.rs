@@ -13,6 +13,7 @@
 //!
 //! - `run_ondemand` (available on `windows`, `unix`, `macos`, `android`)
 //! - `pump_events` (available on `windows`, `unix`, `macos`, `android`)
+//! - `single_instance` (available on `windows`, `unix` with the `single-instance` feature)
 //!
 //! However only the module corresponding to the platform you're compiling to will be available.
 
@@ -44,6 +45,12 @@ pub mod x11;
 ))]
 pub mod run_ondemand;
 
+#[cfg(all(
+    feature = "single-instance",
+    any(windows_platform, x11_platform, wayland_platform)
+))]
+pub mod single_instance;
+
 #[cfg(any(
     windows_platform,
     macos_platform,
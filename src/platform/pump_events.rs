@@ -184,6 +184,7 @@ impl<T> EventLoopExtPumpEvents for EventLoop<T> {
     where
         F: FnMut(Event<Self::UserEvent>, &EventLoopWindowTarget<Self::UserEvent>, &mut ControlFlow),
     {
+        let event_handler = self.wrap_event_handler(event_handler);
         self.event_loop.pump_events(timeout, event_handler)
     }
 }
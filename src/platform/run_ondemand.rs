@@ -69,6 +69,7 @@ impl<T> EventLoopExtRunOnDemand for EventLoop<T> {
     where
         F: FnMut(Event<Self::UserEvent>, &EventLoopWindowTarget<Self::UserEvent>, &mut ControlFlow),
     {
+        let event_handler = self.wrap_event_handler(event_handler);
         self.event_loop.run_ondemand(event_handler)
     }
 }
@@ -0,0 +1,275 @@
+//! Make sure only one instance of the application runs at a time.
+//!
+//! An application that wants to be single-instance calls [`SingleInstance::acquire`] early
+//! in `main`. The first process claiming the identifier becomes the primary instance; every
+//! process started later forwards its command-line arguments to it and should exit. The
+//! primary instance receives the forwarded arguments through [`SingleInstance::listen`] as
+//! user events, e.g. to open a file in an existing window instead of starting a second editor.
+//!
+//! ```no_run
+//! use winit::event::Event;
+//! use winit::event_loop::EventLoopBuilder;
+//! use winit::platform::single_instance::{ForwardedInstance, SingleInstance};
+//!
+//! let instance = match SingleInstance::acquire("org.example.Editor").unwrap() {
+//!     Some(instance) => instance,
+//!     // The arguments were handed to the running instance.
+//!     None => return,
+//! };
+//!
+//! let event_loop = EventLoopBuilder::<ForwardedInstance>::with_user_event().build();
+//! instance.listen(event_loop.create_proxy(), |forwarded| forwarded);
+//!
+//! event_loop.run(move |event, _, _| {
+//!     if let Event::UserEvent(forwarded) = event {
+//!         println!("Opened again with {:?}", forwarded.args);
+//!     }
+//! });
+//! ```
+//!
+//! This is only available with the `single-instance` cargo feature.
+//!
+//! ## Platform-specific
+//!
+//! - **X11 / Wayland:** Uses a lock file and a Unix socket in `$XDG_RUNTIME_DIR`, falling back to
+//!   the temporary directory with the uid in their names. The activation token of the second
+//!   launch is forwarded as well, so the primary instance can use it to raise its window.
+//! - **Windows:** Uses a named mutex and a named pipe, both scoped to the session: the mutex
+//!   lives in the `Local` namespace and the pipe name carries the session id.
+//! - **macOS:** Not available, an application bundle is only ever launched once by the system.
+//!   Later requests to open files or URLs are delivered as [`Event::OpenFiles`] and
+//!   [`Event::OpenUrls`].
+//!
+//! [`Event::OpenFiles`]: crate::event::Event::OpenFiles
+//! [`Event::OpenUrls`]: crate::event::Event::OpenUrls
+
+use std::ffi::OsString;
+use std::io;
+use std::path::PathBuf;
+
+use crate::event_loop::EventLoopProxy;
+use crate::platform_impl;
+#[cfg(any(x11_platform, wayland_platform))]
+use crate::window::ActivationToken;
+
+/// The claim of this process to be the primary instance of the application.
+///
+/// The claim is held until this is dropped, or, after [`listen`], until the process exits.
+///
+/// [`listen`]: Self::listen
+#[derive(Debug)]
+pub struct SingleInstance {
+    inner: platform_impl::SingleInstance,
+}
+
+/// A later launch of the application, forwarded to the primary instance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardedInstance {
+    /// The command-line arguments of the later launch, including the program name.
+    pub args: Vec<OsString>,
+
+    /// The working directory of the later launch, to resolve relative paths in [`args`].
+    ///
+    /// [`args`]: Self::args
+    pub current_dir: Option<PathBuf>,
+
+    /// The activation token the later launch was started with, see
+    /// [`startup_notify`](crate::platform::startup_notify).
+    #[cfg(any(x11_platform, wayland_platform))]
+    pub activation_token: Option<ActivationToken>,
+}
+
+impl SingleInstance {
+    /// Claim `id` for this process, or forward this launch to the process that holds it.
+    ///
+    /// Returns `Ok(None)` if another instance is running and received the arguments of this
+    /// process, in which case it should exit. The `id` must be unique to the application and
+    /// usable as a file name, e.g. its reverse-DNS application id.
+    pub fn acquire(id: &str) -> io::Result<Option<Self>> {
+        if id.is_empty() || id.contains(['/', '\\']) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the instance id must be a non-empty file name",
+            ));
+        }
+
+        let message = encode(&ForwardedInstance::current());
+        let inner = platform_impl::SingleInstance::acquire(id, &message)?;
+        Ok(inner.map(|inner| Self { inner }))
+    }
+
+    /// Deliver every later launch of the application to the event loop of `proxy`.
+    ///
+    /// The launches are received on a background thread, converted with `to_event` and sent as
+    /// user events. The thread stops once the event loop is gone.
+    pub fn listen<T, F>(self, proxy: EventLoopProxy<T>, to_event: F)
+    where
+        T: 'static + Send,
+        F: 'static + Send + Fn(ForwardedInstance) -> T,
+    {
+        self.inner.listen(move |message| match decode(&message) {
+            Some(forwarded) => proxy.send_event(to_event(forwarded)).is_ok(),
+            None => {
+                log::warn!("Ignoring malformed message from another instance");
+                true
+            }
+        });
+    }
+}
+
+impl ForwardedInstance {
+    fn current() -> Self {
+        Self {
+            args: std::env::args_os().collect(),
+            current_dir: std::env::current_dir().ok(),
+            #[cfg(any(x11_platform, wayland_platform))]
+            activation_token: crate::platform::startup_notify::token_from_env(),
+        }
+    }
+}
+
+// The message is a list of fields, each prefixed with its length as a little-endian `u32`:
+// the working directory, the activation token and the arguments. Empty optional fields are
+// encoded as `u32::MAX`.
+
+fn encode(forwarded: &ForwardedInstance) -> Vec<u8> {
+    let mut message = Vec::new();
+    let mut push = |field: Option<Vec<u8>>| match field {
+        Some(field) => {
+            message.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            message.extend_from_slice(&field);
+        }
+        None => message.extend_from_slice(&u32::MAX.to_le_bytes()),
+    };
+
+    push(
+        forwarded
+            .current_dir
+            .as_ref()
+            .map(|dir| os_string_to_bytes(dir.as_os_str())),
+    );
+    #[cfg(any(x11_platform, wayland_platform))]
+    push(
+        forwarded
+            .activation_token
+            .as_ref()
+            .map(|token| token._token.as_bytes().to_vec()),
+    );
+    #[cfg(not(any(x11_platform, wayland_platform)))]
+    push(None);
+    for arg in &forwarded.args {
+        push(Some(os_string_to_bytes(arg)));
+    }
+
+    message
+}
+
+fn decode(mut message: &[u8]) -> Option<ForwardedInstance> {
+    let mut fields = Vec::new();
+    while !message.is_empty() {
+        let len = u32::from_le_bytes(message.get(..4)?.try_into().ok()?);
+        message = &message[4..];
+        if len == u32::MAX {
+            fields.push(None);
+        } else {
+            let len = len as usize;
+            fields.push(Some(message.get(..len)?));
+            message = &message[len..];
+        }
+    }
+
+    let mut fields = fields.into_iter();
+    let current_dir = fields
+        .next()?
+        .map(|dir| bytes_to_os_string(dir).map(PathBuf::from));
+    let _activation_token = fields
+        .next()?
+        .map(|token| String::from_utf8(token.to_vec()).ok());
+    let args = fields
+        .map(|arg| bytes_to_os_string(arg?))
+        .collect::<Option<_>>()?;
+
+    Some(ForwardedInstance {
+        args,
+        current_dir: current_dir.flatten(),
+        #[cfg(any(x11_platform, wayland_platform))]
+        activation_token: _activation_token.flatten().map(ActivationToken::_new),
+    })
+}
+
+#[cfg(unix)]
+fn os_string_to_bytes(string: &std::ffi::OsStr) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+    string.as_bytes().to_vec()
+}
+
+#[cfg(unix)]
+fn bytes_to_os_string(bytes: &[u8]) -> Option<OsString> {
+    use std::os::unix::ffi::OsStrExt;
+    Some(std::ffi::OsStr::from_bytes(bytes).to_os_string())
+}
+
+#[cfg(windows)]
+fn os_string_to_bytes(string: &std::ffi::OsStr) -> Vec<u8> {
+    use std::os::windows::ffi::OsStrExt;
+    string.encode_wide().flat_map(u16::to_le_bytes).collect()
+}
+
+#[cfg(windows)]
+fn bytes_to_os_string(bytes: &[u8]) -> Option<OsString> {
+    use std::os::windows::ffi::OsStringExt;
+    if bytes.len() % 2 != 0 {
+        return None;
+    }
+
+    let wide: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+        .collect();
+    Some(OsString::from_wide(&wide))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forwarded(args: &[&str], current_dir: Option<&str>) -> ForwardedInstance {
+        ForwardedInstance {
+            args: args.iter().map(OsString::from).collect(),
+            current_dir: current_dir.map(PathBuf::from),
+            #[cfg(any(x11_platform, wayland_platform))]
+            activation_token: None,
+        }
+    }
+
+    #[test]
+    fn decode_reverses_encode() {
+        let forwarded = forwarded(
+            &["app", "--open", "file with spaces.txt"],
+            Some("/home/user"),
+        );
+        assert_eq!(decode(&encode(&forwarded)), Some(forwarded));
+    }
+
+    #[test]
+    fn decode_reverses_encode_without_optional_fields() {
+        let forwarded = forwarded(&[], None);
+        assert_eq!(decode(&encode(&forwarded)), Some(forwarded));
+    }
+
+    #[cfg(any(x11_platform, wayland_platform))]
+    #[test]
+    fn decode_reverses_encode_with_activation_token() {
+        let mut forwarded = forwarded(&["app"], None);
+        forwarded.activation_token = Some(ActivationToken::_new("token".into()));
+        assert_eq!(decode(&encode(&forwarded)), Some(forwarded));
+    }
+
+    #[test]
+    fn decode_rejects_truncated_messages() {
+        let message = encode(&forwarded(&["app", "argument"], Some("/")));
+        for len in [0, 3, message.len() - 1] {
+            assert_eq!(decode(&message[..len]), None, "truncated to {len} bytes");
+        }
+    }
+}
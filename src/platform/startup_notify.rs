@@ -43,7 +43,9 @@ pub trait EventLoopExtStartupNotify {
 pub trait WindowExtStartupNotify {
     /// Request a new activation token.
     ///
-    /// The token will be delivered inside
+    /// The token will be delivered inside [`WindowEvent::ActivationTokenDone`].
+    ///
+    /// [`WindowEvent::ActivationTokenDone`]: crate::event::WindowEvent::ActivationTokenDone
     fn request_activation_token(&self) -> Result<AsyncRequestSerial, NotSupportedError>;
 }
 
@@ -52,6 +54,10 @@ pub trait WindowBuilderExtStartupNotify {
     ///
     /// Not using such a token upon a window could make your window not gaining
     /// focus until the user clicks on the window.
+    ///
+    /// The token also completes the startup notification, which stops the busy indicator of
+    /// the launcher. On X11 this happens once the window is first shown, so a window created
+    /// with [`WindowBuilder::with_visible`]`(false)` keeps the indicator until it's made visible.
     fn with_activation_token(self, token: ActivationToken) -> Self;
 }
 
@@ -81,6 +87,15 @@ impl WindowBuilderExtStartupNotify for WindowBuilder {
     }
 }
 
+/// Read the token from the environment without knowing the backend in use yet.
+#[cfg(feature = "single-instance")]
+pub(crate) fn token_from_env() -> Option<ActivationToken> {
+    env::var(WAYLAND_VAR)
+        .or_else(|_| env::var(X11_VAR))
+        .ok()
+        .map(ActivationToken::_new)
+}
+
 /// Remove the activation environment variables from the current process.
 ///
 /// This is wise to do before running child processes,
@@ -3,14 +3,17 @@ use std::os::raw;
 use sctk::reexports::client::Proxy;
 
 use crate::{
+    dpi::{LogicalPosition, LogicalSize},
+    error::ExternalError,
+    event::DeviceId,
     event_loop::{EventLoopBuilder, EventLoopWindowTarget},
     monitor::MonitorHandle,
     window::{Window, WindowBuilder},
 };
 
 use crate::platform_impl::{
-    ApplicationName, Backend, EventLoopWindowTarget as LinuxEventLoopWindowTarget,
-    Window as LinuxWindow,
+    ApplicationName, Backend, DeviceId as LinuxDeviceId,
+    EventLoopWindowTarget as LinuxEventLoopWindowTarget, Window as LinuxWindow,
 };
 
 pub use crate::window::Theme;
@@ -29,6 +32,15 @@ pub trait EventLoopWindowTargetExtWayland {
     ///
     /// [`EventLoop`]: crate::event_loop::EventLoop
     fn wayland_display(&self) -> Option<*mut raw::c_void>;
+
+    /// Returns the name of the seat the given device belongs to.
+    ///
+    /// All the input devices of a seat share the same [`DeviceId`], so the device ids of input
+    /// events can be used to tell the seats apart.
+    ///
+    /// Returns `None` if the [`EventLoopWindowTarget`] doesn't use wayland, if the device isn't
+    /// a Wayland seat, or if the compositor didn't name the seat.
+    fn seat_name(&self, device_id: DeviceId) -> Option<String>;
 }
 
 impl<T> EventLoopWindowTargetExtWayland for EventLoopWindowTarget<T> {
@@ -47,6 +59,17 @@ impl<T> EventLoopWindowTargetExtWayland for EventLoopWindowTarget<T> {
             _ => None,
         }
     }
+
+    #[inline]
+    fn seat_name(&self, device_id: DeviceId) -> Option<String> {
+        match (&self.p, device_id.0) {
+            (LinuxEventLoopWindowTarget::Wayland(ref p), LinuxDeviceId::Wayland(device_id)) => {
+                p.seat_name(device_id)
+            }
+            #[cfg(x11_platform)]
+            _ => None,
+        }
+    }
 }
 
 /// Additional methods on [`EventLoopBuilder`] that are specific to Wayland.
@@ -90,6 +113,49 @@ pub trait WindowExtWayland {
     ///
     /// The pointer will become invalid when the [`Window`] is destroyed.
     fn wayland_display(&self) -> Option<*mut raw::c_void>;
+
+    /// Sets the buffer scale of the window's surface, decoupling the resolution of the presented
+    /// buffers from [`Window::scale_factor`].
+    ///
+    /// With `Some(scale)`, the buffers attached to the surface must be `scale` times the
+    /// [`Window::inner_size`] in logical pixels, whatever scale factor the compositor reports.
+    /// This lets a renderer draw at a fixed resolution while the UI keeps following the scale
+    /// factor. `None` restores the default: the integer scale factor, or `1` when the compositor
+    /// uses fractional scaling, in which case the buffer is scaled through `wp_viewporter`.
+    ///
+    /// The change applies to the next buffer committed to the surface.
+    fn set_buffer_scale(&self, buffer_scale: Option<u32>);
+
+    /// Sets the part of the presented buffers that the compositor scales to fill the window,
+    /// using `wp_viewporter`.
+    ///
+    /// The rectangle is given as a position and a size in the buffer's coordinates divided by the
+    /// buffer scale. `None` uses the whole buffer. Together with
+    /// [`set_buffer_scale(Some(1))`][Self::set_buffer_scale], this lets a renderer present a
+    /// buffer at a lower resolution and have the compositor upscale it to the window's size. The
+    /// destination of the scaling always follows [`Window::inner_size`].
+    ///
+    /// The change applies to the next buffer committed to the surface.
+    ///
+    /// Returns [`ExternalError::NotSupported`] if the compositor doesn't support `wp_viewporter`
+    /// or the window doesn't use Wayland, and [`ExternalError::Ignored`] if a value isn't
+    /// finite, the position is negative or the size isn't positive.
+    fn set_viewport_source(
+        &self,
+        source: Option<(LogicalPosition<f64>, LogicalSize<f64>)>,
+    ) -> Result<(), ExternalError>;
+
+    /// Fills the window with a solid color, without the need for a renderer.
+    ///
+    /// The color is given as premultiplied RGBA and covers the window until the next buffer is
+    /// committed to its surface, which makes it suitable for showing something right away while
+    /// the renderer initializes. When called before the window is configured, the color is
+    /// applied on the initial configure.
+    ///
+    /// Uses `wp_single_pixel_buffer_v1` scaled with `wp_viewporter`. Returns
+    /// [`ExternalError::NotSupported`] if the compositor lacks either of them or the window
+    /// doesn't use Wayland.
+    fn fill_color(&self, rgba: [u8; 4]) -> Result<(), ExternalError>;
 }
 
 impl WindowExtWayland for Window {
@@ -110,6 +176,40 @@ impl WindowExtWayland for Window {
             _ => None,
         }
     }
+
+    #[inline]
+    fn set_buffer_scale(&self, buffer_scale: Option<u32>) {
+        match self.window {
+            LinuxWindow::Wayland(ref w) => w.set_buffer_scale(buffer_scale),
+            #[cfg(x11_platform)]
+            _ => (),
+        }
+    }
+
+    #[inline]
+    fn set_viewport_source(
+        &self,
+        source: Option<(LogicalPosition<f64>, LogicalSize<f64>)>,
+    ) -> Result<(), ExternalError> {
+        match self.window {
+            LinuxWindow::Wayland(ref w) => w.set_viewport_source(source),
+            #[cfg(x11_platform)]
+            _ => Err(ExternalError::NotSupported(
+                crate::error::NotSupportedError::new(),
+            )),
+        }
+    }
+
+    #[inline]
+    fn fill_color(&self, rgba: [u8; 4]) -> Result<(), ExternalError> {
+        match self.window {
+            LinuxWindow::Wayland(ref w) => w.fill_color(rgba),
+            #[cfg(x11_platform)]
+            _ => Err(ExternalError::NotSupported(
+                crate::error::NotSupportedError::new(),
+            )),
+        }
+    }
 }
 
 /// Additional methods on [`WindowBuilder`] that are specific to Wayland.
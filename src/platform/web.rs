@@ -127,6 +127,14 @@ impl<T> EventLoopExtWebSys for EventLoop<T> {
         F: 'static
             + FnMut(Event<Self::UserEvent>, &EventLoopWindowTarget<Self::UserEvent>, &mut ControlFlow),
     {
-        self.event_loop.spawn(event_handler)
+        let mut event_handler = self.wrap_event_handler(event_handler);
+        // `spawn` returns right away, so keep the user event queue open as long as the event
+        // loop runs rather than closing it when `self` is dropped here.
+        let user_events = self.user_events;
+        self.event_loop
+            .spawn(move |event, event_loop, control_flow| {
+                let _ = &user_events;
+                event_handler(event, event_loop, control_flow)
+            })
     }
 }
@@ -1,16 +1,20 @@
 use std::{ffi::c_void, path::Path};
 
 use crate::{
-    dpi::PhysicalSize,
+    dpi::{PhysicalPosition, PhysicalSize},
     event::{DeviceId, KeyEvent},
-    event_loop::EventLoopBuilder,
+    event_loop::{EventLoopBuilder, EventLoopWindowTarget},
     keyboard::Key,
     monitor::MonitorHandle,
     platform::modifier_supplement::KeyEventExtModifierSupplement,
     platform_impl::WinIcon,
-    window::{BadIcon, Icon, Window, WindowBuilder},
+    window::{BadIcon, HitTestKind, Icon, Window, WindowBuilder},
 };
 
+/// A callback classifying the points of the client area, see
+/// [`WindowExtWindows::set_hittest_callback`].
+pub type HitTestCallback = Box<dyn Fn(PhysicalPosition<f64>) -> Option<HitTestKind> + Send + Sync>;
+
 /// Window Handle type used by Win32 API
 pub type HWND = isize;
 /// Menu Handle type used by Win32 API
@@ -20,6 +24,31 @@ pub type HMONITOR = isize;
 /// Instance Handle type used by Win32 API
 pub type HINSTANCE = isize;
 
+/// Describes a color used by Windows
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Color(u32);
+
+impl Color {
+    /// Use the system's default color
+    pub const SYSTEM_DEFAULT: Color = Color(0xFFFFFFFF);
+
+    /// Special constant only valid for the window border, modeled using `Option<Color>`
+    /// in user facing code.
+    pub(crate) const NONE: Color = Color(0xFFFFFFFE);
+
+    /// Create a new color from the given RGB values
+    pub const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Self((r as u32) | ((g as u32) << 8) | ((b as u32) << 16))
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Self::SYSTEM_DEFAULT
+    }
+}
+
 /// Additional methods on `EventLoop` that are specific to Windows.
 pub trait EventLoopBuilderExtWindows {
     /// Whether to allow the event loop to be created off of the main thread.
@@ -111,6 +140,21 @@ impl<T> EventLoopBuilderExtWindows for EventLoopBuilder<T> {
     }
 }
 
+/// Additional methods on `EventLoopWindowTarget` that are specific to Windows.
+pub trait EventLoopWindowTargetExtWindows {
+    /// Returns the system color used to highlight selected text.
+    ///
+    /// The user can change this color at any time, so avoid caching it for long.
+    fn selection_color(&self) -> Color;
+}
+
+impl<T> EventLoopWindowTargetExtWindows for EventLoopWindowTarget<T> {
+    #[inline]
+    fn selection_color(&self) -> Color {
+        self.p.selection_color()
+    }
+}
+
 /// Additional methods on `Window` that are specific to Windows.
 pub trait WindowExtWindows {
     /// Returns the HINSTANCE of the window
@@ -145,6 +189,33 @@ pub trait WindowExtWindows {
     ///
     /// Enabling the shadow causes a thin 1px line to appear on the top of the window.
     fn set_undecorated_shadow(&self, shadow: bool);
+
+    /// Sets the color of the window border.
+    ///
+    /// Providing `None` will disable the border.
+    ///
+    /// Supported starting with Windows 11 Build 22000.
+    fn set_border_color(&self, color: Option<Color>);
+
+    /// Sets the background color of the title bar.
+    ///
+    /// Supported starting with Windows 11 Build 22000.
+    fn set_title_bar_color(&self, color: Color);
+
+    /// Sets the color of the window title.
+    ///
+    /// Supported starting with Windows 11 Build 22000.
+    fn set_title_text_color(&self, color: Color);
+
+    /// Sets the callback answering `WM_NCHITTEST`, for windows drawing their own title bar.
+    ///
+    /// The callback gets the position of the cursor relative to the client area, and tells
+    /// whether it's over the title bar or a resize handle, which then get the system's move,
+    /// resize and snapping. When it returns `None`, the regions set with
+    /// [`Window::set_hittest_regions`] are used instead. Providing `None` removes the callback.
+    ///
+    /// The callback is called on the thread of the event loop, for every cursor movement.
+    fn set_hittest_callback(&self, callback: Option<HitTestCallback>);
 }
 
 impl WindowExtWindows for Window {
@@ -177,6 +248,26 @@ impl WindowExtWindows for Window {
     fn set_undecorated_shadow(&self, shadow: bool) {
         self.window.set_undecorated_shadow(shadow)
     }
+
+    #[inline]
+    fn set_border_color(&self, color: Option<Color>) {
+        self.window.set_border_color(color.unwrap_or(Color::NONE))
+    }
+
+    #[inline]
+    fn set_title_bar_color(&self, color: Color) {
+        self.window.set_title_bar_color(color)
+    }
+
+    #[inline]
+    fn set_title_text_color(&self, color: Color) {
+        self.window.set_title_text_color(color)
+    }
+
+    #[inline]
+    fn set_hittest_callback(&self, callback: Option<HitTestCallback>) {
+        self.window.set_hittest_callback(callback)
+    }
 }
 
 /// Additional methods on `WindowBuilder` that are specific to Windows.
@@ -231,6 +322,23 @@ pub trait WindowBuilderExtWindows {
     /// The shadow is hidden by default.
     /// Enabling the shadow causes a thin 1px line to appear on the top of the window.
     fn with_undecorated_shadow(self, shadow: bool) -> WindowBuilder;
+
+    /// Sets the color of the window border.
+    ///
+    /// Providing `None` will disable the border.
+    ///
+    /// Supported starting with Windows 11 Build 22000.
+    fn with_border_color(self, color: Option<Color>) -> WindowBuilder;
+
+    /// Sets the background color of the title bar.
+    ///
+    /// Supported starting with Windows 11 Build 22000.
+    fn with_title_bar_color(self, color: Color) -> WindowBuilder;
+
+    /// Sets the color of the window title.
+    ///
+    /// Supported starting with Windows 11 Build 22000.
+    fn with_title_text_color(self, color: Color) -> WindowBuilder;
 }
 
 impl WindowBuilderExtWindows for WindowBuilder {
@@ -281,6 +389,24 @@ impl WindowBuilderExtWindows for WindowBuilder {
         self.platform_specific.decoration_shadow = shadow;
         self
     }
+
+    #[inline]
+    fn with_border_color(mut self, color: Option<Color>) -> WindowBuilder {
+        self.platform_specific.border_color = Some(color.unwrap_or(Color::NONE));
+        self
+    }
+
+    #[inline]
+    fn with_title_bar_color(mut self, color: Color) -> WindowBuilder {
+        self.platform_specific.title_bar_color = Some(color);
+        self
+    }
+
+    #[inline]
+    fn with_title_text_color(mut self, color: Color) -> WindowBuilder {
+        self.platform_specific.title_text_color = Some(color);
+        self
+    }
 }
 
 /// Additional methods on `MonitorHandle` that are specific to Windows.
@@ -12,7 +12,10 @@ use crate::platform_impl::{
     x11::ffi::XVisualInfo, ApplicationName, Backend, Window as LinuxWindow, XLIB_ERROR_HOOKS,
 };
 
-pub use crate::platform_impl::{x11::util::WindowType as XWindowType, XNotSupported};
+pub use crate::platform_impl::{
+    x11::util::{SizeHints as XSizeHints, WindowType as XWindowType},
+    XNotSupported,
+};
 
 /// The first argument in the provided hook will be the pointer to `XDisplay`
 /// and the second one the pointer to [`XErrorEvent`]. The returned `bool` is an
@@ -102,6 +105,22 @@ pub trait WindowExtX11 {
     ///
     /// The pointer will become invalid when the [`Window`] is destroyed.
     fn xcb_connection(&self) -> Option<*mut raw::c_void>;
+
+    /// Sets the `instance` and `class` parts of the `WM_CLASS` property.
+    ///
+    /// The ICCCM requires `WM_CLASS` to be set before the window is mapped, so prefer
+    /// [`WindowBuilderExtX11::with_class`]. Most window managers pick up later changes, but rules
+    /// that already matched the window won't be re-evaluated.
+    ///
+    /// Does nothing if the window doesn't use xlib (if it uses wayland for example).
+    fn set_class(&self, instance: &str, class: &str);
+
+    /// Sets which fields of `WM_NORMAL_HINTS` winit is allowed to send.
+    ///
+    /// See [`WindowBuilderExtX11::with_size_hints`] for details.
+    ///
+    /// Does nothing if the window doesn't use xlib (if it uses wayland for example).
+    fn set_size_hints(&self, size_hints: XSizeHints);
 }
 
 impl WindowExtX11 for Window {
@@ -140,6 +159,24 @@ impl WindowExtX11 for Window {
             _ => None,
         }
     }
+
+    #[inline]
+    fn set_class(&self, instance: &str, class: &str) {
+        match self.window {
+            LinuxWindow::X(ref w) => w.set_class(instance, class),
+            #[cfg(wayland_platform)]
+            _ => (),
+        }
+    }
+
+    #[inline]
+    fn set_size_hints(&self, size_hints: XSizeHints) {
+        match self.window {
+            LinuxWindow::X(ref w) => w.set_size_hints(size_hints),
+            #[cfg(wayland_platform)]
+            _ => (),
+        }
+    }
 }
 
 /// Additional methods on [`WindowBuilder`] that are specific to X11.
@@ -157,7 +194,25 @@ pub trait WindowBuilderExtX11 {
     /// [Desktop Entry Spec](https://specifications.freedesktop.org/desktop-entry-spec/desktop-entry-spec-latest.html#desktop-file-id)
     fn with_name(self, general: impl Into<String>, instance: impl Into<String>) -> Self;
 
+    /// Build window with the given `instance` and `class` parts of `WM_CLASS(STRING)`.
+    ///
+    /// Window managers like i3 match their rules against these values. Unlike
+    /// [`WindowBuilderExtX11::with_name`], this only affects X11 and takes precedence over it
+    /// there, so the Wayland `app_id` can be chosen independently.
+    ///
+    /// The resulting property looks like `WM_CLASS(STRING) = "instance", "class"`.
+    fn with_class(self, instance: impl Into<String>, class: impl Into<String>) -> Self;
+
     /// Build window with override-redirect flag; defaults to false. Only relevant on X11.
+    ///
+    /// An override-redirect window bypasses the window manager entirely: it is not reparented,
+    /// decorated, moved or stacked by the WM, which makes it suitable for menus, tooltips and
+    /// similar popups.
+    ///
+    /// Since the window manager doesn't know about the window, it will never give it input
+    /// focus. Such windows receive pointer events, but keyboard input requires the application
+    /// to grab the keyboard itself. The window also won't appear in taskbars or pagers, and
+    /// its position and size are exactly the ones requested by the application.
     fn with_override_redirect(self, override_redirect: bool) -> Self;
 
     /// Build window with `_NET_WM_WINDOW_TYPE` hints; defaults to `Normal`. Only relevant on X11.
@@ -176,6 +231,22 @@ pub trait WindowBuilderExtX11 {
     /// WindowBuilder::new().with_base_size(PhysicalSize::new(400, 200));
     /// ```
     fn with_base_size<S: Into<Size>>(self, base_size: S) -> Self;
+
+    /// Build window with only the given fields of `WM_NORMAL_HINTS` set; defaults to
+    /// [`XSizeHints::all()`]. Only relevant on X11.
+    ///
+    /// Some tiling window managers treat windows with a minimum or maximum size as floating,
+    /// or apply resize increments in unwanted ways. Leaving a hint out prevents winit from
+    /// ever sending it, regardless of what e.g. [`Window::set_min_inner_size`] is called with.
+    ///
+    /// Note that [`Window::set_resizable`] is implemented by setting the minimum and maximum
+    /// size to the current size, so without [`XSizeHints::MIN_SIZE`] and
+    /// [`XSizeHints::MAX_SIZE`] the window manager can't be told that the window is not
+    /// resizable.
+    ///
+    /// [`Window::set_min_inner_size`]: crate::window::Window::set_min_inner_size
+    /// [`Window::set_resizable`]: crate::window::Window::set_resizable
+    fn with_size_hints(self, size_hints: XSizeHints) -> Self;
 }
 
 impl WindowBuilderExtX11 for WindowBuilder {
@@ -200,6 +271,12 @@ impl WindowBuilderExtX11 for WindowBuilder {
         self
     }
 
+    #[inline]
+    fn with_class(mut self, instance: impl Into<String>, class: impl Into<String>) -> Self {
+        self.platform_specific.x11_class = Some((instance.into(), class.into()));
+        self
+    }
+
     #[inline]
     fn with_override_redirect(mut self, override_redirect: bool) -> Self {
         self.platform_specific.override_redirect = override_redirect;
@@ -217,6 +294,12 @@ impl WindowBuilderExtX11 for WindowBuilder {
         self.platform_specific.base_size = Some(base_size.into());
         self
     }
+
+    #[inline]
+    fn with_size_hints(mut self, size_hints: XSizeHints) -> Self {
+        self.platform_specific.x11_size_hints = size_hints;
+        self
+    }
 }
 
 /// Additional methods on `MonitorHandle` that are specific to X11.
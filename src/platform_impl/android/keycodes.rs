@@ -102,8 +102,15 @@ pub fn to_physical_keycode(keycode: Keycode) -> KeyCode {
         Keycode::VolumeUp => KeyCode::AudioVolumeUp,
         Keycode::VolumeDown => KeyCode::AudioVolumeDown,
         Keycode::VolumeMute => KeyCode::AudioVolumeMute,
-        //Keycode::Mute => None, // Microphone mute
+        Keycode::Mute => KeyCode::MicrophoneMute,
         Keycode::MediaPlayPause => KeyCode::MediaPlayPause,
+        Keycode::MediaPlay => KeyCode::MediaPlay,
+        Keycode::MediaPause => KeyCode::MediaPause,
+        Keycode::MediaRecord => KeyCode::MediaRecord,
+        Keycode::MediaFastForward => KeyCode::MediaFastForward,
+        Keycode::MediaRewind => KeyCode::MediaRewind,
+        Keycode::BrightnessDown => KeyCode::BrightnessDown,
+        Keycode::BrightnessUp => KeyCode::BrightnessUp,
         Keycode::MediaStop => KeyCode::MediaStop,
         Keycode::MediaNext => KeyCode::MediaTrackNext,
         Keycode::MediaPrevious => KeyCode::MediaTrackPrevious,
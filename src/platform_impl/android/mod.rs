@@ -24,10 +24,11 @@ use crate::{
     error,
     event::{self, InnerSizeWriter, StartCause},
     event_loop::{self, ControlFlow, EventLoopWindowTarget as RootELW},
-    keyboard::NativeKey,
+    keyboard::{LockKeyState, NativeKey},
     platform::pump_events::PumpStatus,
     window::{
-        self, CursorGrabMode, ImePurpose, ResizeDirection, Theme, WindowButtons, WindowLevel,
+        self, CursorGrabMode, DragAction, ImePurpose, ResizeDirection, Theme, WindowButtons,
+        WindowLevel,
     },
 };
 use crate::{error::RunLoopError, platform_impl::Fullscreen};
@@ -35,6 +36,7 @@ use crate::{error::RunLoopError, platform_impl::Fullscreen};
 mod keycodes;
 
 static HAS_FOCUS: Lazy<RwLock<bool>> = Lazy::new(|| RwLock::new(true));
+static KEY_REPEAT: Lazy<RwLock<bool>> = Lazy::new(|| RwLock::new(true));
 
 /// Returns the minimum `Option<Duration>`, taking into account that `None`
 /// equates to an infinite timeout, not a zero timeout (so can't just use
@@ -137,7 +139,16 @@ impl RedrawRequester {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
-pub struct KeyEventExtra {}
+pub struct KeyEventExtra {
+    pub raw_scancode: Option<u32>,
+}
+
+impl KeyEventExtra {
+    #[inline]
+    pub fn raw_scancode(&self) -> Option<u32> {
+        self.raw_scancode
+    }
+}
 
 pub struct EventLoop<T: 'static> {
     android_app: AndroidApp,
@@ -310,10 +321,12 @@ impl<T: 'static> EventLoop<T> {
                     }
                 }
                 MainEvent::LowMemory => {
-                    // XXX: how to forward this state to applications?
-                    // It seems like ideally winit should support lifecycle and
-                    // low-memory events, especially for mobile platforms.
-                    warn!("TODO: handle Android LowMemory notification");
+                    sticky_exit_callback(
+                        event::Event::MemoryWarning,
+                        self.window_target(),
+                        &mut control_flow,
+                        callback,
+                    );
                 }
                 MainEvent::Start => {
                     // XXX: how to forward this state to applications?
@@ -433,6 +446,8 @@ impl<T: 'static> EventLoop<T> {
                                 input_status = InputStatus::Unhandled
                             }
                         },
+                        // The window disabled the key repeat.
+                        _ if key.repeat_count() > 0 && !*KEY_REPEAT.read().unwrap() => {}
                         keycode => {
                             let state = match key.action() {
                                 KeyAction::Down => event::ElementState::Pressed,
@@ -455,7 +470,9 @@ impl<T: 'static> EventLoop<T> {
                                         location: keycodes::to_location(keycode),
                                         repeat: key.repeat_count() > 0,
                                         text: None,
-                                        platform_specific: KeyEventExtra {},
+                                        platform_specific: KeyEventExtra {
+                                            raw_scancode: u32::try_from(key.scan_code()).ok(),
+                                        },
                                     },
                                     is_synthetic: false,
                                 },
@@ -507,7 +524,10 @@ impl<T: 'static> EventLoop<T> {
             pending_redraw |= self.redraw_flag.get_and_reset();
             if pending_redraw {
                 pending_redraw = false;
-                let event = event::Event::RedrawRequested(window::WindowId(WindowId));
+                let event = event::Event::RedrawRequested {
+                    window_id: window::WindowId(WindowId),
+                    deadline: None,
+                };
                 sticky_exit_callback(event, self.window_target(), &mut control_flow, callback);
             }
         }
@@ -815,6 +835,18 @@ impl Window {
 
     pub fn pre_present_notify(&self) {}
 
+    #[inline]
+    pub fn pre_present_notify_with_damage(
+        &self,
+        _damage: &[(PhysicalPosition<i32>, PhysicalSize<u32>)],
+    ) {
+    }
+
+    #[inline]
+    pub fn flush(&self) -> Result<(), error::ExternalError> {
+        Ok(())
+    }
+
     pub fn inner_position(&self) -> Result<PhysicalPosition<i32>, error::NotSupportedError> {
         Err(error::NotSupportedError::new())
     }
@@ -853,6 +885,9 @@ impl Window {
 
     pub fn set_transparent(&self, _transparent: bool) {}
 
+    #[inline]
+    pub fn set_opaque_region(&self, _region: Option<&[window::Rect]>) {}
+
     pub fn set_visible(&self, _visibility: bool) {}
 
     pub fn is_visible(&self) -> Option<bool> {
@@ -883,6 +918,11 @@ impl Window {
         false
     }
 
+    #[inline]
+    pub fn restored_inner_size(&self) -> Option<PhysicalSize<u32>> {
+        None
+    }
+
     pub fn set_fullscreen(&self, _monitor: Option<Fullscreen>) {
         warn!("Cannot set fullscreen on Android");
     }
@@ -907,12 +947,44 @@ impl Window {
 
     pub fn set_ime_purpose(&self, _purpose: ImePurpose) {}
 
+    #[inline]
+    pub fn set_ime_surrounding_text(&self, _text: &str, _cursor: usize, _anchor: usize) {}
+
     pub fn focus_window(&self) {}
 
     pub fn request_user_attention(&self, _request_type: Option<window::UserAttentionType>) {}
 
     pub fn set_cursor_icon(&self, _: window::CursorIcon) {}
 
+    pub fn preload_cursors(&self, _: &[window::CursorIcon]) {}
+
+    pub fn set_primary_selection_text(&self, _text: &str) {}
+
+    pub fn request_primary_selection_text(
+        &self,
+    ) -> Result<event_loop::AsyncRequestSerial, error::NotSupportedError> {
+        Err(error::NotSupportedError::new())
+    }
+
+    pub fn set_clipboard_data(&self, _data: Vec<(String, Vec<u8>)>) {}
+
+    pub fn request_clipboard_data(
+        &self,
+        _mime_type: &str,
+    ) -> Result<event_loop::AsyncRequestSerial, error::NotSupportedError> {
+        Err(error::NotSupportedError::new())
+    }
+
+    pub fn request_clipboard_mime_types(
+        &self,
+    ) -> Result<event_loop::AsyncRequestSerial, error::NotSupportedError> {
+        Err(error::NotSupportedError::new())
+    }
+
+    pub fn set_drag_accept(&self, _mime_type: Option<&str>) {}
+
+    pub fn set_drag_action(&self, _action: DragAction) {}
+
     pub fn set_cursor_position(&self, _: Position) -> Result<(), error::ExternalError> {
         Err(error::ExternalError::NotSupported(
             error::NotSupportedError::new(),
@@ -942,12 +1014,24 @@ impl Window {
         ))
     }
 
+    pub fn set_hittest_regions(&self, _regions: &[window::HitTestRegion]) {}
+
     pub fn set_cursor_hittest(&self, _hittest: bool) -> Result<(), error::ExternalError> {
         Err(error::ExternalError::NotSupported(
             error::NotSupportedError::new(),
         ))
     }
 
+    #[inline]
+    pub fn set_input_region(
+        &self,
+        _region: Option<&[window::Rect]>,
+    ) -> Result<(), error::ExternalError> {
+        Err(error::ExternalError::NotSupported(
+            error::NotSupportedError::new(),
+        ))
+    }
+
     pub fn raw_window_handle(&self) -> RawWindowHandle {
         if let Some(native_window) = self.app.native_window().as_ref() {
             native_window.raw_window_handle()
@@ -978,6 +1062,21 @@ impl Window {
         *HAS_FOCUS.read().unwrap()
     }
 
+    #[inline]
+    pub fn set_key_repeat(&self, key_repeat: bool) {
+        *KEY_REPEAT.write().unwrap() = key_repeat;
+    }
+
+    #[inline]
+    pub fn lock_key_state(&self) -> LockKeyState {
+        LockKeyState::empty()
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.has_focus()
+    }
+
     pub fn title(&self) -> String {
         String::new()
     }
@@ -1021,6 +1120,22 @@ impl MonitorHandle {
         Some("Android Device".to_owned())
     }
 
+    pub fn friendly_name(&self) -> Option<String> {
+        None
+    }
+
+    pub fn make(&self) -> Option<String> {
+        None
+    }
+
+    pub fn model(&self) -> Option<String> {
+        None
+    }
+
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        None
+    }
+
     pub fn size(&self) -> PhysicalSize<u32> {
         if let Some(native_window) = self.app.native_window() {
             PhysicalSize::new(native_window.width() as _, native_window.height() as _)
@@ -1033,6 +1148,10 @@ impl MonitorHandle {
         (0, 0).into()
     }
 
+    pub fn work_area(&self) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+        None
+    }
+
     pub fn scale_factor(&self) -> f64 {
         self.app
             .config()
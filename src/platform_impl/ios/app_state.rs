@@ -60,7 +60,7 @@ enum UserCallbackTransitionResult<'a> {
 
 impl Event<Never> {
     fn is_redraw(&self) -> bool {
-        matches!(self, Event::RedrawRequested(_))
+        matches!(self, Event::RedrawRequested { .. })
     }
 }
 
@@ -758,7 +758,12 @@ pub unsafe fn handle_main_events_cleared() {
     let redraw_events: Vec<EventWrapper> = this
         .main_events_cleared_transition()
         .into_iter()
-        .map(|window| EventWrapper::StaticEvent(Event::RedrawRequested(RootWindowId(window.id()))))
+        .map(|window| {
+            EventWrapper::StaticEvent(Event::RedrawRequested {
+                window_id: RootWindowId(window.id()),
+                deadline: None,
+            })
+        })
         .collect();
     drop(this);
 
@@ -110,6 +110,13 @@ unsafe impl Sync for DeviceId {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KeyEventExtra {}
 
+impl KeyEventExtra {
+    #[inline]
+    pub fn raw_scancode(&self) -> Option<u32> {
+        None
+    }
+}
+
 #[derive(Debug)]
 pub enum OsError {}
 
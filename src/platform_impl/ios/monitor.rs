@@ -158,6 +158,22 @@ impl Inner {
         }
     }
 
+    pub fn friendly_name(&self) -> Option<String> {
+        None
+    }
+
+    pub fn make(&self) -> Option<String> {
+        None
+    }
+
+    pub fn model(&self) -> Option<String> {
+        None
+    }
+
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        None
+    }
+
     pub fn size(&self) -> PhysicalSize<u32> {
         let bounds = self.uiscreen.nativeBounds();
         PhysicalSize::new(bounds.size.width as u32, bounds.size.height as u32)
@@ -168,6 +184,10 @@ impl Inner {
         (bounds.origin.x as f64, bounds.origin.y as f64).into()
     }
 
+    pub fn work_area(&self) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+        None
+    }
+
     pub fn scale_factor(&self) -> f64 {
         self.uiscreen.nativeScale() as f64
     }
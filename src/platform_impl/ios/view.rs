@@ -1,8 +1,11 @@
 #![allow(clippy::unnecessary_cast)]
 use std::cell::Cell;
+use std::path::PathBuf;
 use std::ptr::NonNull;
 
-use icrate::Foundation::{CGFloat, CGRect, MainThreadMarker, NSObject, NSObjectProtocol, NSSet};
+use icrate::Foundation::{
+    CGFloat, CGRect, MainThreadMarker, NSObject, NSObjectProtocol, NSSet, NSURL,
+};
 use objc2::declare::{Ivar, IvarDrop};
 use objc2::rc::Id;
 use objc2::runtime::AnyClass;
@@ -43,9 +46,10 @@ declare_class!(
         fn draw_rect(&self, rect: CGRect) {
             let window = self.window().unwrap();
             unsafe {
-                app_state::handle_nonuser_event(EventWrapper::StaticEvent(Event::RedrawRequested(
-                    RootWindowId(window.id()),
-                )));
+                app_state::handle_nonuser_event(EventWrapper::StaticEvent(Event::RedrawRequested {
+                    window_id: RootWindowId(window.id()),
+                    deadline: None,
+                }));
             }
             let _: () = unsafe { msg_send![super(self), drawRect: rect] };
         }
@@ -519,6 +523,32 @@ declare_class!(
         #[method(applicationDidEnterBackground:)]
         fn did_enter_background(&self, _application: &UIApplication) {}
 
+        #[method(application:openURL:options:)]
+        fn open_url(&self, _application: &UIApplication, url: &NSURL, _: *mut NSObject) -> bool {
+            let event = if url.isFileURL() {
+                url.path()
+                    .map(|path| Event::OpenFiles(vec![PathBuf::from(path.to_string())]))
+            } else {
+                url.absoluteString()
+                    .map(|url| Event::OpenUrls(vec![url.to_string()]))
+            };
+
+            match event {
+                Some(event) => {
+                    unsafe { app_state::handle_nonuser_event(EventWrapper::StaticEvent(event)) };
+                    true
+                }
+                None => false,
+            }
+        }
+
+        #[method(applicationDidReceiveMemoryWarning:)]
+        fn did_receive_memory_warning(&self, _application: &UIApplication) {
+            unsafe {
+                app_state::handle_nonuser_event(EventWrapper::StaticEvent(Event::MemoryWarning))
+            }
+        }
+
         #[method(applicationWillTerminate:)]
         fn will_terminate(&self, application: &UIApplication) {
             let mut events = Vec::new();
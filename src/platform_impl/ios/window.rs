@@ -18,7 +18,9 @@ use crate::{
     dpi::{self, LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize, Position, Size},
     error::{ExternalError, NotSupportedError, OsError as RootOsError},
     event::{Event, WindowEvent},
+    event_loop::AsyncRequestSerial,
     icon::Icon,
+    keyboard::LockKeyState,
     platform::ios::{ScreenEdge, ValidOrientations},
     platform_impl::platform::{
         app_state,
@@ -26,8 +28,9 @@ use crate::{
         monitor, EventLoopWindowTarget, Fullscreen, MonitorHandle,
     },
     window::{
-        CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, UserAttentionType,
-        WindowAttributes, WindowButtons, WindowId as RootWindowId, WindowLevel,
+        CursorGrabMode, CursorIcon, DragAction, HitTestRegion, ImePurpose, Rect, ResizeDirection,
+        Theme, UserAttentionType, WindowAttributes, WindowButtons, WindowId as RootWindowId,
+        WindowLevel,
     },
 };
 
@@ -47,6 +50,10 @@ impl Inner {
         debug!("`Window::set_transparent` is ignored on iOS")
     }
 
+    pub fn set_opaque_region(&self, _region: Option<&[Rect]>) {
+        debug!("`Window::set_opaque_region` is ignored on iOS")
+    }
+
     pub fn set_visible(&self, visible: bool) {
         self.window.setHidden(!visible)
     }
@@ -76,6 +83,18 @@ impl Inner {
 
     pub fn pre_present_notify(&self) {}
 
+    #[inline]
+    pub fn pre_present_notify_with_damage(
+        &self,
+        _damage: &[(PhysicalPosition<i32>, PhysicalSize<u32>)],
+    ) {
+    }
+
+    #[inline]
+    pub fn flush(&self) -> Result<(), ExternalError> {
+        Ok(())
+    }
+
     pub fn inner_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
         unsafe {
             let safe_area = self.safe_area_screen_space();
@@ -190,6 +209,31 @@ impl Inner {
         debug!("`Window::set_cursor_icon` ignored on iOS")
     }
 
+    pub fn preload_cursors(&self, _cursors: &[CursorIcon]) {}
+
+    pub fn set_primary_selection_text(&self, _text: &str) {}
+
+    pub fn request_primary_selection_text(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    pub fn set_clipboard_data(&self, _data: Vec<(String, Vec<u8>)>) {}
+
+    pub fn request_clipboard_data(
+        &self,
+        _mime_type: &str,
+    ) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    pub fn request_clipboard_mime_types(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    pub fn set_drag_accept(&self, _mime_type: Option<&str>) {}
+
+    pub fn set_drag_action(&self, _action: DragAction) {}
+
     pub fn set_cursor_position(&self, _position: Position) -> Result<(), ExternalError> {
         Err(ExternalError::NotSupported(NotSupportedError::new()))
     }
@@ -210,10 +254,17 @@ impl Inner {
         Err(ExternalError::NotSupported(NotSupportedError::new()))
     }
 
+    pub fn set_hittest_regions(&self, _regions: &[HitTestRegion]) {}
+
     pub fn set_cursor_hittest(&self, _hittest: bool) -> Result<(), ExternalError> {
         Err(ExternalError::NotSupported(NotSupportedError::new()))
     }
 
+    #[inline]
+    pub fn set_input_region(&self, _region: Option<&[Rect]>) -> Result<(), ExternalError> {
+        Err(ExternalError::NotSupported(NotSupportedError::new()))
+    }
+
     pub fn set_minimized(&self, _minimized: bool) {
         warn!("`Window::set_minimized` is ignored on iOS")
     }
@@ -227,6 +278,11 @@ impl Inner {
         warn!("`Window::set_maximized` is ignored on iOS")
     }
 
+    #[inline]
+    pub fn restored_inner_size(&self) -> Option<PhysicalSize<u32>> {
+        None
+    }
+
     pub fn is_maximized(&self) -> bool {
         warn!("`Window::is_maximized` is ignored on iOS");
         false
@@ -308,6 +364,10 @@ impl Inner {
         warn!("`Window::set_ime_allowed` is ignored on iOS")
     }
 
+    pub fn set_ime_surrounding_text(&self, _text: &str, _cursor: usize, _anchor: usize) {
+        warn!("`Window::set_ime_surrounding_text` is ignored on iOS")
+    }
+
     pub fn focus_window(&self) {
         warn!("`Window::set_focus` is ignored on iOS")
     }
@@ -360,6 +420,19 @@ impl Inner {
         self.window.isKeyWindow()
     }
 
+    pub fn set_key_repeat(&self, _key_repeat: bool) {
+        warn!("`Window::set_key_repeat` is ignored on iOS")
+    }
+
+    pub fn lock_key_state(&self) -> LockKeyState {
+        LockKeyState::empty()
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.has_focus()
+    }
+
     #[inline]
     pub fn set_theme(&self, _theme: Option<Theme>) {
         warn!("`Window::set_theme` is ignored on iOS");
@@ -188,8 +188,8 @@ pub fn scancode_to_keycode(scancode: u32) -> KeyCode {
         164 => KeyCode::MediaPlayPause,
         165 => KeyCode::MediaTrackPrevious,
         166 => KeyCode::MediaStop,
-        // 167 => KeyCode::RECORD,
-        // 168 => KeyCode::REWIND,
+        167 => KeyCode::MediaRecord,
+        168 => KeyCode::MediaRewind,
         // 169 => KeyCode::PHONE,
         // 170 => KeyCode::ISO,
         // 171 => KeyCode::CONFIG,
@@ -216,15 +216,15 @@ pub fn scancode_to_keycode(scancode: u32) -> KeyCode {
         192 => KeyCode::F22,
         193 => KeyCode::F23,
         194 => KeyCode::F24,
-        // 200 => KeyCode::PLAYCD,
-        // 201 => KeyCode::PAUSECD,
+        200 => KeyCode::MediaPlay,
+        201 => KeyCode::MediaPause,
         // 202 => KeyCode::PROG3,
         // 203 => KeyCode::PROG4,
         // 204 => KeyCode::DASHBOARD,
         // 205 => KeyCode::SUSPEND,
         // 206 => KeyCode::CLOSE,
-        // 207 => KeyCode::PLAY,
-        // 208 => KeyCode::FASTFORWARD,
+        207 => KeyCode::MediaPlay,
+        208 => KeyCode::MediaFastForward,
         // 209 => KeyCode::BASSBOOST,
         // 210 => KeyCode::PRINT,
         // 211 => KeyCode::HP,
@@ -240,13 +240,13 @@ pub fn scancode_to_keycode(scancode: u32) -> KeyCode {
         // 221 => KeyCode::SHOP,
         // 222 => KeyCode::ALTERASE,
         // 223 => KeyCode::CANCEL,
-        // 224 => KeyCode::BRIGHTNESSDOW,
-        // 225 => KeyCode::BRIGHTNESSU,
+        224 => KeyCode::BrightnessDown,
+        225 => KeyCode::BrightnessUp,
         // 226 => KeyCode::MEDIA,
-        // 227 => KeyCode::SWITCHVIDEOMODE,
-        // 228 => KeyCode::KBDILLUMTOGGLE,
-        // 229 => KeyCode::KBDILLUMDOWN,
-        // 230 => KeyCode::KBDILLUMUP,
+        227 => KeyCode::DisplayToggle,
+        228 => KeyCode::KeyboardBacklightToggle,
+        229 => KeyCode::KeyboardBacklightDown,
+        230 => KeyCode::KeyboardBacklightUp,
         // 231 => KeyCode::SEND,
         // 232 => KeyCode::REPLY,
         // 233 => KeyCode::FORWARDMAIL,
@@ -264,7 +264,7 @@ pub fn scancode_to_keycode(scancode: u32) -> KeyCode {
         // 245 => KeyCode::DISPLAY_OFF,
         // 246 => KeyCode::WWAN,
         // 247 => KeyCode::RFKILL,
-        // 248 => KeyCode::KEY_MICMUTE,
+        248 => KeyCode::MicrophoneMute,
         _ => KeyCode::Unidentified(NativeKeyCode::Xkb(scancode)),
     }
 }
@@ -397,6 +397,8 @@ pub fn keycode_to_scancode(keycode: KeyCode) -> Option<u32> {
         KeyCode::MediaPlayPause => Some(164),
         KeyCode::MediaTrackPrevious => Some(165),
         KeyCode::MediaStop => Some(166),
+        KeyCode::MediaRecord => Some(167),
+        KeyCode::MediaRewind => Some(168),
         KeyCode::F13 => Some(183),
         KeyCode::F14 => Some(184),
         KeyCode::F15 => Some(185),
@@ -409,6 +411,16 @@ pub fn keycode_to_scancode(keycode: KeyCode) -> Option<u32> {
         KeyCode::F22 => Some(192),
         KeyCode::F23 => Some(193),
         KeyCode::F24 => Some(194),
+        KeyCode::MediaPlay => Some(200),
+        KeyCode::MediaPause => Some(201),
+        KeyCode::MediaFastForward => Some(208),
+        KeyCode::BrightnessDown => Some(224),
+        KeyCode::BrightnessUp => Some(225),
+        KeyCode::DisplayToggle => Some(227),
+        KeyCode::KeyboardBacklightToggle => Some(228),
+        KeyCode::KeyboardBacklightDown => Some(229),
+        KeyCode::KeyboardBacklightUp => Some(230),
+        KeyCode::MicrophoneMute => Some(248),
         _ => None,
     }
 }
@@ -682,9 +694,9 @@ pub fn keysym_to_key(keysym: u32) -> Key {
         // XFree86 - Backlight controls
         keysyms::XF86_MonBrightnessUp => Key::BrightnessUp,
         keysyms::XF86_MonBrightnessDown => Key::BrightnessDown,
-        // keysyms::XF86_KbdLightOnOff => Key::LightOnOff,
-        // keysyms::XF86_KbdBrightnessUp => Key::KeyboardBrightnessUp,
-        // keysyms::XF86_KbdBrightnessDown => Key::KeyboardBrightnessDown,
+        keysyms::XF86_KbdLightOnOff => Key::KeyboardBacklightToggle,
+        keysyms::XF86_KbdBrightnessUp => Key::KeyboardBacklightUp,
+        keysyms::XF86_KbdBrightnessDown => Key::KeyboardBacklightDown,
 
         // XFree86 - "Internet"
         keysyms::XF86_Standby => Key::Standby,
@@ -746,7 +758,8 @@ pub fn keysym_to_key(keysym: u32) -> Key {
         keysyms::XF86_Close => Key::Close,
         keysyms::XF86_Copy => Key::Copy,
         keysyms::XF86_Cut => Key::Cut,
-        // XF86_Display..XF86_Documents
+        keysyms::XF86_Display => Key::DisplaySwap,
+        // XF86_DOS..XF86_Documents
         keysyms::XF86_Excel => Key::LaunchSpreadsheet,
         // XF86_Explorer..XF86iTouch
         keysyms::XF86_LogOff => Key::LogOff,
@@ -795,6 +808,7 @@ pub fn keysym_to_key(keysym: u32) -> Key {
         // XF86_TouchpadToggle..XF86_TouchpadOff
         //
         keysyms::XF86_AudioMute => Key::AudioVolumeMute,
+        keysyms::XF86_AudioMicMute => Key::MicrophoneVolumeMute,
 
         // XF86_Switch_VT_1..XF86_Switch_VT_12
 
@@ -22,7 +22,7 @@ use crate::platform_impl::common::keymap;
 use crate::platform_impl::KeyEventExtra;
 use crate::{
     event::ElementState,
-    keyboard::{Key, KeyCode, KeyLocation},
+    keyboard::{Key, KeyCode, KeyLocation, LockKeyState},
 };
 
 // TODO: Wire this up without using a static `AtomicBool`.
@@ -33,6 +33,9 @@ pub fn reset_dead_keys() {
     RESET_DEAD_KEYS.store(true, Ordering::SeqCst);
 }
 
+/// The real modifier that common layouts map `ISO_Level3_Shift` (AltGr) to.
+const XKB_MOD_NAME_LEVEL3: &[u8] = b"Mod5\0";
+
 static XKBH: Lazy<&'static XkbCommon> = Lazy::new(xkbcommon_handle);
 static XKBCH: Lazy<&'static XkbCommonCompose> = Lazy::new(xkbcommon_compose_handle);
 #[cfg(feature = "x11")]
@@ -385,6 +388,8 @@ impl KbdState {
         let platform_specific = KeyEventExtra {
             key_without_modifiers,
             text_with_all_modifiers,
+            // XKB keycodes are offset by 8 from the evdev ones.
+            raw_scancode: keycode.checked_sub(8),
         };
 
         KeyEvent {
@@ -613,6 +618,8 @@ pub struct ModifiersState {
     pub logo: bool,
     /// The "Num lock" key
     pub num_lock: bool,
+    /// The level 3 modifier, usually on the "AltGr" key
+    pub level3: bool,
 }
 
 impl ModifiersState {
@@ -634,6 +641,7 @@ impl ModifiersState {
         self.caps_lock = mod_name_is_active(ffi::XKB_MOD_NAME_CAPS);
         self.logo = mod_name_is_active(ffi::XKB_MOD_NAME_LOGO);
         self.num_lock = mod_name_is_active(ffi::XKB_MOD_NAME_NUM);
+        self.level3 = mod_name_is_active(XKB_MOD_NAME_LEVEL3);
     }
 }
 
@@ -644,10 +652,20 @@ impl From<ModifiersState> for crate::keyboard::ModifiersState {
         to_mods.set(crate::keyboard::ModifiersState::CONTROL, mods.ctrl);
         to_mods.set(crate::keyboard::ModifiersState::ALT, mods.alt);
         to_mods.set(crate::keyboard::ModifiersState::SUPER, mods.logo);
+        to_mods.set(crate::keyboard::ModifiersState::ALT_GRAPH, mods.level3);
         to_mods
     }
 }
 
+impl From<ModifiersState> for LockKeyState {
+    fn from(mods: ModifiersState) -> LockKeyState {
+        let mut lock_keys = LockKeyState::empty();
+        lock_keys.set(LockKeyState::CAPS_LOCK, mods.caps_lock);
+        lock_keys.set(LockKeyState::NUM_LOCK, mods.num_lock);
+        lock_keys
+    }
+}
+
 #[derive(Debug)]
 pub enum Error {
     /// libxkbcommon is not available
@@ -674,3 +692,88 @@ fn byte_slice_to_smol_str(bytes: &[u8]) -> Option<SmolStr> {
         })
         .ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::keyboard::ModifiersState as RootModifiersState;
+
+    // The evdev keycodes, as used by xkbcommon.
+    const KEY_Q: u32 = 24;
+    const KEY_LEFTCTRL: u32 = 37;
+    const KEY_LEFTALT: u32 = 64;
+    const KEY_RIGHTALT: u32 = 108;
+
+    /// Compile the keymap of `layout`, panicking if xkbcommon or its data files are missing.
+    fn kbd_state_with_layout(layout: &str) -> KbdState {
+        let mut kbd_state = KbdState::new().expect("libxkbcommon is not available");
+        let layout = CString::new(layout).unwrap();
+        let names = ffi::xkb_rule_names {
+            rules: ptr::null(),
+            model: ptr::null(),
+            layout: layout.as_ptr(),
+            variant: ptr::null(),
+            options: ptr::null(),
+        };
+
+        unsafe {
+            let keymap = (XKBH.xkb_keymap_new_from_names)(
+                kbd_state.xkb_context,
+                &names,
+                ffi::xkb_keymap_compile_flags::XKB_KEYMAP_COMPILE_NO_FLAGS,
+            );
+            assert!(
+                !keymap.is_null(),
+                "failed to compile the keymap, is xkeyboard-config installed?"
+            );
+            let state = (XKBH.xkb_state_new)(keymap);
+            kbd_state.post_init(state, keymap);
+        }
+
+        kbd_state
+    }
+
+    fn press(kbd_state: &mut KbdState, keycode: u32) {
+        unsafe {
+            (XKBH.xkb_state_update_key)(
+                kbd_state.xkb_state,
+                keycode,
+                ffi::xkb_key_direction::XKB_KEY_DOWN,
+            );
+            kbd_state.mods_state.update_with(kbd_state.xkb_state);
+        }
+    }
+
+    #[test]
+    #[ignore = "needs libxkbcommon and xkeyboard-config"]
+    fn altgr_is_not_ctrl_alt_on_german_layout() {
+        let mut kbd_state = kbd_state_with_layout("de");
+
+        press(&mut kbd_state, KEY_RIGHTALT);
+        let mods = RootModifiersState::from(kbd_state.mods_state());
+        assert_eq!(mods, RootModifiersState::ALT_GRAPH);
+        assert_eq!(kbd_state.get_utf8_raw(KEY_Q).as_deref(), Some("@"));
+    }
+
+    #[test]
+    #[ignore = "needs libxkbcommon and xkeyboard-config"]
+    fn ctrl_alt_is_not_altgr_on_german_layout() {
+        let mut kbd_state = kbd_state_with_layout("de");
+
+        press(&mut kbd_state, KEY_LEFTCTRL);
+        press(&mut kbd_state, KEY_LEFTALT);
+        let mods = RootModifiersState::from(kbd_state.mods_state());
+        assert_eq!(mods, RootModifiersState::CONTROL | RootModifiersState::ALT);
+    }
+
+    #[test]
+    #[ignore = "needs libxkbcommon and xkeyboard-config"]
+    fn right_alt_is_alt_on_us_layout() {
+        let mut kbd_state = kbd_state_with_layout("us");
+
+        press(&mut kbd_state, KEY_RIGHTALT);
+        let mods = RootModifiersState::from(kbd_state.mods_state());
+        assert_eq!(mods, RootModifiersState::ALT);
+    }
+}
@@ -24,7 +24,11 @@ use std::time::Duration;
 #[cfg(x11_platform)]
 pub use self::x11::XNotSupported;
 #[cfg(x11_platform)]
-use self::x11::{ffi::XVisualInfo, util::WindowType as XWindowType, X11Error, XConnection, XError};
+use self::x11::{
+    ffi::XVisualInfo,
+    util::{SizeHints as XSizeHints, WindowType as XWindowType},
+    X11Error, XConnection, XError,
+};
 #[cfg(x11_platform)]
 use crate::platform::x11::XlibErrorHook;
 use crate::{
@@ -32,25 +36,29 @@ use crate::{
     error::{ExternalError, NotSupportedError, OsError as RootOsError, RunLoopError},
     event::{Event, KeyEvent},
     event_loop::{
-        AsyncRequestSerial, ControlFlow, DeviceEvents, EventLoopClosed,
+        AsyncRequestSerial, CompositorInfo, ControlFlow, DeviceEvents, EventLoopClosed,
         EventLoopWindowTarget as RootELW,
     },
     icon::Icon,
-    keyboard::{Key, KeyCode},
+    keyboard::{Key, KeyCode, LockKeyState},
     platform::{
         modifier_supplement::KeyEventExtModifierSupplement, pump_events::PumpStatus,
         scancode::KeyCodeExtScancode,
     },
     window::{
-        ActivationToken, CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme,
-        UserAttentionType, WindowAttributes, WindowButtons, WindowLevel,
+        ActivationToken, CursorGrabMode, CursorIcon, DragAction, HitTestRegion, ImePurpose, Rect,
+        ResizeDirection, Theme, UserAttentionType, WindowAttributes, WindowButtons, WindowLevel,
     },
 };
 
+#[cfg(feature = "single-instance")]
+pub(crate) use self::single_instance::SingleInstance;
 pub(crate) use crate::icon::RgbaIcon as PlatformIcon;
 pub(crate) use crate::platform_impl::Fullscreen;
 
 pub mod common;
+#[cfg(feature = "single-instance")]
+mod single_instance;
 #[cfg(wayland_platform)]
 pub mod wayland;
 #[cfg(x11_platform)]
@@ -105,6 +113,10 @@ pub struct PlatformSpecificWindowBuilderAttributes {
     pub override_redirect: bool,
     #[cfg(x11_platform)]
     pub x11_window_types: Vec<XWindowType>,
+    #[cfg(x11_platform)]
+    pub x11_class: Option<(String, String)>,
+    #[cfg(x11_platform)]
+    pub x11_size_hints: XSizeHints,
 }
 
 impl Default for PlatformSpecificWindowBuilderAttributes {
@@ -122,6 +134,10 @@ impl Default for PlatformSpecificWindowBuilderAttributes {
             override_redirect: false,
             #[cfg(x11_platform)]
             x11_window_types: vec![XWindowType::Normal],
+            #[cfg(x11_platform)]
+            x11_class: None,
+            #[cfg(x11_platform)]
+            x11_size_hints: XSizeHints::all(),
         }
     }
 }
@@ -157,7 +173,7 @@ pub(crate) enum Window {
     #[cfg(x11_platform)]
     X(x11::Window),
     #[cfg(wayland_platform)]
-    Wayland(wayland::Window),
+    Wayland(Box<wayland::Window>),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -240,6 +256,26 @@ impl MonitorHandle {
         x11_or_wayland!(match self; MonitorHandle(m) => m.name())
     }
 
+    #[inline]
+    pub fn friendly_name(&self) -> Option<String> {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.friendly_name())
+    }
+
+    #[inline]
+    pub fn make(&self) -> Option<String> {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.make())
+    }
+
+    #[inline]
+    pub fn model(&self) -> Option<String> {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.model())
+    }
+
+    #[inline]
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.physical_size_mm())
+    }
+
     #[inline]
     pub fn native_identifier(&self) -> u32 {
         x11_or_wayland!(match self; MonitorHandle(m) => m.native_identifier())
@@ -255,6 +291,11 @@ impl MonitorHandle {
         x11_or_wayland!(match self; MonitorHandle(m) => m.position())
     }
 
+    #[inline]
+    pub fn work_area(&self) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+        x11_or_wayland!(match self; MonitorHandle(m) => m.work_area())
+    }
+
     #[inline]
     pub fn refresh_rate_millihertz(&self) -> Option<u32> {
         x11_or_wayland!(match self; MonitorHandle(m) => m.refresh_rate_millihertz())
@@ -311,7 +352,8 @@ impl Window {
         match *window_target {
             #[cfg(wayland_platform)]
             EventLoopWindowTarget::Wayland(ref window_target) => {
-                wayland::Window::new(window_target, attribs, pl_attribs).map(Window::Wayland)
+                wayland::Window::new(window_target, attribs, pl_attribs)
+                    .map(|window| Window::Wayland(Box::new(window)))
             }
             #[cfg(x11_platform)]
             EventLoopWindowTarget::X(ref window_target) => {
@@ -405,6 +447,11 @@ impl Window {
         x11_or_wayland!(match self; Window(w) => w.set_resize_increments(increments))
     }
 
+    #[inline]
+    pub fn set_opaque_region(&self, region: Option<&[Rect]>) {
+        x11_or_wayland!(match self; Window(w) => w.set_opaque_region(region))
+    }
+
     #[inline]
     pub fn set_resizable(&self, resizable: bool) {
         x11_or_wayland!(match self; Window(w) => w.set_resizable(resizable))
@@ -430,6 +477,49 @@ impl Window {
         x11_or_wayland!(match self; Window(w) => w.set_cursor_icon(cursor))
     }
 
+    #[inline]
+    pub fn preload_cursors(&self, cursors: &[CursorIcon]) {
+        x11_or_wayland!(match self; Window(w) => w.preload_cursors(cursors))
+    }
+
+    #[inline]
+    pub fn set_primary_selection_text(&self, text: &str) {
+        x11_or_wayland!(match self; Window(w) => w.set_primary_selection_text(text))
+    }
+
+    #[inline]
+    pub fn request_primary_selection_text(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        x11_or_wayland!(match self; Window(w) => w.request_primary_selection_text())
+    }
+
+    #[inline]
+    pub fn set_clipboard_data(&self, data: Vec<(String, Vec<u8>)>) {
+        x11_or_wayland!(match self; Window(w) => w.set_clipboard_data(data))
+    }
+
+    #[inline]
+    pub fn request_clipboard_data(
+        &self,
+        mime_type: &str,
+    ) -> Result<AsyncRequestSerial, NotSupportedError> {
+        x11_or_wayland!(match self; Window(w) => w.request_clipboard_data(mime_type))
+    }
+
+    #[inline]
+    pub fn request_clipboard_mime_types(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        x11_or_wayland!(match self; Window(w) => w.request_clipboard_mime_types())
+    }
+
+    #[inline]
+    pub fn set_drag_accept(&self, mime_type: Option<&str>) {
+        x11_or_wayland!(match self; Window(w) => w.set_drag_accept(mime_type))
+    }
+
+    #[inline]
+    pub fn set_drag_action(&self, action: DragAction) {
+        x11_or_wayland!(match self; Window(w) => w.set_drag_action(action))
+    }
+
     #[inline]
     pub fn set_cursor_grab(&self, mode: CursorGrabMode) -> Result<(), ExternalError> {
         x11_or_wayland!(match self; Window(window) => window.set_cursor_grab(mode))
@@ -450,11 +540,21 @@ impl Window {
         x11_or_wayland!(match self; Window(window) => window.drag_resize_window(direction))
     }
 
+    #[inline]
+    pub fn set_hittest_regions(&self, regions: &[HitTestRegion]) {
+        x11_or_wayland!(match self; Window(w) => w.set_hittest_regions(regions))
+    }
+
     #[inline]
     pub fn set_cursor_hittest(&self, hittest: bool) -> Result<(), ExternalError> {
         x11_or_wayland!(match self; Window(w) => w.set_cursor_hittest(hittest))
     }
 
+    #[inline]
+    pub fn set_input_region(&self, region: Option<&[Rect]>) -> Result<(), ExternalError> {
+        x11_or_wayland!(match self; Window(w) => w.set_input_region(region))
+    }
+
     #[inline]
     pub fn scale_factor(&self) -> f64 {
         x11_or_wayland!(match self; Window(w) => w.scale_factor())
@@ -475,6 +575,11 @@ impl Window {
         x11_or_wayland!(match self; Window(w) => w.is_maximized())
     }
 
+    #[inline]
+    pub fn restored_inner_size(&self) -> Option<PhysicalSize<u32>> {
+        x11_or_wayland!(match self; Window(w) => w.restored_inner_size())
+    }
+
     #[inline]
     pub fn set_minimized(&self, minimized: bool) {
         x11_or_wayland!(match self; Window(w) => w.set_minimized(minimized))
@@ -545,14 +650,14 @@ impl Window {
         x11_or_wayland!(match self; Window(w) => w.set_ime_purpose(purpose))
     }
 
+    #[inline]
+    pub fn set_ime_surrounding_text(&self, text: &str, cursor: usize, anchor: usize) {
+        x11_or_wayland!(match self; Window(w) => w.set_ime_surrounding_text(text, cursor, anchor))
+    }
+
     #[inline]
     pub fn focus_window(&self) {
-        match self {
-            #[cfg(x11_platform)]
-            Window::X(ref w) => w.focus_window(),
-            #[cfg(wayland_platform)]
-            Window::Wayland(_) => (),
-        }
+        x11_or_wayland!(match self; Window(w) => w.focus_window())
     }
     pub fn request_user_attention(&self, request_type: Option<UserAttentionType>) {
         x11_or_wayland!(match self; Window(w) => w.request_user_attention(request_type))
@@ -568,6 +673,19 @@ impl Window {
         x11_or_wayland!(match self; Window(w) => w.pre_present_notify())
     }
 
+    #[inline]
+    pub fn pre_present_notify_with_damage(
+        &self,
+        damage: &[(PhysicalPosition<i32>, PhysicalSize<u32>)],
+    ) {
+        x11_or_wayland!(match self; Window(w) => w.pre_present_notify_with_damage(damage))
+    }
+
+    #[inline]
+    pub fn flush(&self) -> Result<(), ExternalError> {
+        x11_or_wayland!(match self; Window(w) => w.flush())
+    }
+
     #[inline]
     pub fn current_monitor(&self) -> Option<MonitorHandle> {
         match self {
@@ -640,6 +758,21 @@ impl Window {
         x11_or_wayland!(match self; Window(window) => window.has_focus())
     }
 
+    #[inline]
+    pub fn set_key_repeat(&self, key_repeat: bool) {
+        x11_or_wayland!(match self; Window(window) => window.set_key_repeat(key_repeat))
+    }
+
+    #[inline]
+    pub fn lock_key_state(&self) -> LockKeyState {
+        x11_or_wayland!(match self; Window(window) => window.lock_key_state())
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        x11_or_wayland!(match self; Window(window) => window.is_active())
+    }
+
     pub fn title(&self) -> String {
         x11_or_wayland!(match self; Window(window) => window.title())
     }
@@ -649,6 +782,14 @@ impl Window {
 pub struct KeyEventExtra {
     pub key_without_modifiers: Key,
     pub text_with_all_modifiers: Option<SmolStr>,
+    pub raw_scancode: Option<u32>,
+}
+
+impl KeyEventExtra {
+    #[inline]
+    pub fn raw_scancode(&self) -> Option<u32> {
+        self.raw_scancode
+    }
 }
 
 impl KeyEventExtModifierSupplement for KeyEvent {
@@ -922,6 +1063,16 @@ impl<T> EventLoopWindowTarget<T> {
         }
     }
 
+    #[inline]
+    pub fn set_application_name(&self, name: ApplicationName) {
+        x11_or_wayland!(match self; EventLoopWindowTarget(evlp) => evlp.set_application_name(name))
+    }
+
+    #[inline]
+    pub fn compositor_info(&self) -> CompositorInfo {
+        x11_or_wayland!(match self; EventLoopWindowTarget(evlp) => evlp.compositor_info())
+    }
+
     pub fn raw_display_handle(&self) -> raw_window_handle::RawDisplayHandle {
         x11_or_wayland!(match self; Self(evlp) => evlp.raw_display_handle())
     }
@@ -969,3 +1120,23 @@ fn is_main_thread() -> bool {
 fn is_main_thread() -> bool {
     std::thread::current().name() == Some("main")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_timeout_treats_none_as_infinite() {
+        let short = Some(Duration::from_millis(1));
+        let long = Some(Duration::from_millis(10));
+
+        assert_eq!(min_timeout(None, None), None);
+        assert_eq!(min_timeout(None, long), long);
+        assert_eq!(min_timeout(short, None), short);
+        assert_eq!(min_timeout(short, long), short);
+        assert_eq!(
+            min_timeout(long, Some(Duration::ZERO)),
+            Some(Duration::ZERO)
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Single-instance support through a lock file and a Unix socket.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{env, thread};
+
+/// How long a later instance waits for the primary one to start listening.
+const CONNECT_ATTEMPTS: u32 = 20;
+const CONNECT_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug)]
+pub struct SingleInstance {
+    // Holds the lock as long as it is open.
+    lock: File,
+    listener: UnixListener,
+}
+
+impl SingleInstance {
+    pub fn acquire(id: &str, message: &[u8]) -> io::Result<Option<Self>> {
+        // The runtime directory belongs to the user, but the temporary directory is shared with
+        // the other users, so their instances are told apart by the uid there.
+        let (dir, name) = match env::var_os("XDG_RUNTIME_DIR") {
+            Some(dir) => (PathBuf::from(dir), id.to_owned()),
+            None => (
+                env::temp_dir(),
+                format!("{id}-{}", unsafe { libc::getuid() }),
+            ),
+        };
+        let socket_path = dir.join(format!("{name}.sock"));
+
+        let lock = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(dir.join(format!("{name}.lock")))?;
+
+        if unsafe { libc::flock(lock.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } == 0 {
+            // We hold the lock, so a socket left behind is from an instance that is gone.
+            let _ = fs::remove_file(&socket_path);
+            let listener = UnixListener::bind(&socket_path)?;
+            return Ok(Some(Self { lock, listener }));
+        }
+
+        let error = io::Error::last_os_error();
+        if error.kind() != io::ErrorKind::WouldBlock {
+            return Err(error);
+        }
+
+        // The primary instance may hold the lock but not be listening yet.
+        let mut attempts = 0;
+        let mut stream = loop {
+            match UnixStream::connect(&socket_path) {
+                Ok(stream) => break stream,
+                Err(_) if attempts < CONNECT_ATTEMPTS => {
+                    attempts += 1;
+                    thread::sleep(CONNECT_INTERVAL);
+                }
+                Err(err) => return Err(err),
+            }
+        };
+        stream.write_all(message)?;
+
+        Ok(None)
+    }
+
+    pub fn listen<F>(self, mut callback: F)
+    where
+        F: 'static + Send + FnMut(Vec<u8>) -> bool,
+    {
+        thread::spawn(move || {
+            let _lock = self.lock;
+            for stream in self.listener.incoming() {
+                let mut message = Vec::new();
+                match stream.and_then(|mut stream| stream.read_to_end(&mut message)) {
+                    Ok(_) => {
+                        if !callback(message) {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        log::warn!("Failed to receive message from another instance: {err}")
+                    }
+                }
+            }
+        });
+    }
+}
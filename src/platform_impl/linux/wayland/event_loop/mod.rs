@@ -1,6 +1,7 @@
 //! The event-loop routines.
 
 use std::cell::RefCell;
+use std::env;
 use std::error::Error;
 use std::io::Result as IOResult;
 use std::marker::PhantomData;
@@ -17,13 +18,18 @@ use sctk::reexports::client::globals;
 use sctk::reexports::client::{Connection, Proxy, QueueHandle, WaylandSource};
 
 use crate::dpi::{LogicalSize, PhysicalSize};
-use crate::error::{OsError as RootOsError, RunLoopError};
+use crate::error::{OsError as RootOsError, ProtocolError, RunLoopError};
 use crate::event::{Event, InnerSizeWriter, StartCause, WindowEvent};
-use crate::event_loop::{ControlFlow, EventLoopWindowTarget as RootEventLoopWindowTarget};
+use crate::event_loop::{
+    CompositorCapabilities, CompositorInfo, ControlFlow,
+    EventLoopWindowTarget as RootEventLoopWindowTarget,
+};
 use crate::platform::pump_events::PumpStatus;
 use crate::platform_impl::platform::min_timeout;
 use crate::platform_impl::platform::sticky_exit_callback;
-use crate::platform_impl::{EventLoopWindowTarget as PlatformEventLoopWindowTarget, OsError};
+use crate::platform_impl::{
+    ApplicationName, EventLoopWindowTarget as PlatformEventLoopWindowTarget, OsError,
+};
 
 mod proxy;
 pub mod sink;
@@ -64,6 +70,9 @@ pub struct EventLoop<T: 'static> {
     /// Connection to the wayland server.
     connection: Connection,
 
+    /// The protocol error that broke the connection, reported when the loop exits.
+    protocol_error: Option<ProtocolError>,
+
     /// Event loop window target.
     window_target: RootEventLoopWindowTarget<T>,
 
@@ -87,6 +96,14 @@ impl<T: 'static> EventLoop<T> {
         // races with the server.
         event_queue.roundtrip(&mut winit_state)?;
 
+        // The outputs announced so far are the initially available monitors, don't report them
+        // as newly connected. The initial seat capabilities are kept, so the user learns which
+        // input devices are available.
+        winit_state
+            .events_sink
+            .window_events
+            .retain(|event| !matches!(event, Event::MonitorEvent { .. }));
+
         // Register Wayland source.
         let wayland_source = WaylandSource::new(event_queue)?;
         let wayland_dispatcher =
@@ -134,6 +151,7 @@ impl<T: 'static> EventLoop<T> {
             buffer_sink: EventSink::default(),
             window_ids: Vec::new(),
             connection,
+            protocol_error: None,
             wayland_dispatcher,
             user_events_sender,
             pending_user_events,
@@ -161,7 +179,10 @@ impl<T: 'static> EventLoop<T> {
                     break Ok(());
                 }
                 PumpStatus::Exit(code) => {
-                    break Err(RunLoopError::ExitFailure(code));
+                    break Err(match self.protocol_error.take() {
+                        Some(error) => RunLoopError::ProtocolError(error),
+                        None => RunLoopError::ExitFailure(code),
+                    });
                 }
                 _ => {
                     continue;
@@ -245,13 +266,15 @@ impl<T: 'static> EventLoop<T> {
                 _ => unreachable!(),
             };
 
-            match queue.dispatch_pending(state) {
-                Ok(dispatched) => dispatched > 0,
-                Err(error) => {
-                    error!("Error dispatching wayland queue: {}", error);
-                    self.control_flow = ControlFlow::ExitWithCode(1);
-                    return;
-                }
+            queue.dispatch_pending(state)
+        };
+        let instant_wakeup = match instant_wakeup {
+            Ok(dispatched) => dispatched > 0,
+            Err(error) => {
+                error!("Error dispatching wayland queue: {}", error);
+                self.check_protocol_error();
+                self.control_flow = ControlFlow::ExitWithCode(1);
+                return;
             }
         };
 
@@ -286,6 +309,7 @@ impl<T: 'static> EventLoop<T> {
             // with an API to do that via some event.
             // Still, we set the exit code to the error's OS error code, or to 1 if not possible.
             let exit_code = error.raw_os_error().unwrap_or(1);
+            self.check_protocol_error();
             self.control_flow = ControlFlow::ExitWithCode(exit_code);
             return;
         }
@@ -488,12 +512,12 @@ impl<T: 'static> EventLoop<T> {
         });
 
         for window_id in window_ids.drain(..) {
-            let request_redraw = self.with_state(|state| {
+            let (request_redraw, deadline) = self.with_state(|state| {
                 let window_requests = state.window_requests.get_mut();
                 if window_requests.get(&window_id).unwrap().take_closed() {
                     mem::drop(window_requests.remove(&window_id));
                     mem::drop(state.windows.get_mut().remove(&window_id));
-                    false
+                    (false, None)
                 } else {
                     let mut window = state
                         .windows
@@ -504,8 +528,10 @@ impl<T: 'static> EventLoop<T> {
                         .unwrap();
 
                     if window.frame_callback_state() == FrameCallbackState::Requested {
-                        false
+                        (false, None)
                     } else {
+                        let deadline = window.frame_deadline();
+
                         // Reset the frame callbacks state.
                         window.frame_callback_reset();
                         let mut redraw_requested = window_requests
@@ -516,14 +542,17 @@ impl<T: 'static> EventLoop<T> {
                         // Redraw the frame while at it.
                         redraw_requested |= window.refresh_frame();
 
-                        redraw_requested
+                        (redraw_requested, deadline)
                     }
                 }
             });
 
             if request_redraw {
                 sticky_exit_callback(
-                    Event::RedrawRequested(crate::window::WindowId(window_id)),
+                    Event::RedrawRequested {
+                        window_id: crate::window::WindowId(window_id),
+                        deadline,
+                    },
                     &self.window_target,
                     &mut control_flow,
                     &mut callback,
@@ -578,6 +607,20 @@ impl<T: 'static> EventLoop<T> {
         })
     }
 
+    /// Remember the protocol error of the compositor, if that is what broke the connection.
+    fn check_protocol_error(&mut self) {
+        if let Some(error) = self.connection.protocol_error() {
+            let error = ProtocolError {
+                interface: error.object_interface,
+                object_id: error.object_id,
+                code: error.code,
+                message: error.message,
+            };
+            error!("The compositor reported a {error}");
+            self.protocol_error = Some(error);
+        }
+    }
+
     fn roundtrip(&mut self) -> Result<usize, RootOsError> {
         let state = match &mut self.window_target.p {
             PlatformEventLoopWindowTarget::Wayland(window_target) => window_target.state.get_mut(),
@@ -621,6 +664,65 @@ impl<T> EventLoopWindowTarget<T> {
         display_handle.display = self.connection.display().id().as_ptr() as *mut _;
         RawDisplayHandle::Wayland(display_handle)
     }
+
+    pub fn seat_name(&self, device_id: DeviceId) -> Option<String> {
+        let state = self.state.borrow();
+        let seat = state
+            .seat_state
+            .seats()
+            .find(|seat| DeviceId::from_seat(seat) == device_id)?;
+        state.seat_state.info(&seat)?.name
+    }
+
+    pub fn set_application_name(&self, name: ApplicationName) {
+        let mut state = self.state.borrow_mut();
+        for window_state in state.windows.get_mut().values() {
+            window_state
+                .lock()
+                .unwrap()
+                .window
+                .set_app_id(name.general.clone());
+        }
+        state.app_id = Some(name.general);
+
+        // Wake up the event loop to send the requests.
+        self.event_loop_awakener.ping();
+    }
+
+    pub fn compositor_info(&self) -> CompositorInfo {
+        let state = self.state.borrow();
+
+        // Globals such as `wl_output` and `wl_seat` are advertised once per instance.
+        let mut globals: Vec<String> = state
+            .registry_state
+            .globals()
+            .map(|global| global.interface.clone())
+            .collect();
+        globals.sort_unstable();
+        globals.dedup();
+        let advertised = |interface: &str| {
+            globals
+                .binary_search_by(|global| global.as_str().cmp(interface))
+                .is_ok()
+        };
+
+        let capabilities = CompositorCapabilities {
+            supports_ssd: advertised("zxdg_decoration_manager_v1"),
+            supports_blur: advertised("org_kde_kwin_blur_manager")
+                || advertised("ext_background_effect_manager_v1"),
+            supports_activation: state.xdg_activation.is_some(),
+            supports_pointer_constraints: state.pointer_constraints.is_some(),
+            supports_fractional_scale: state.fractional_scaling_manager.is_some(),
+        };
+
+        CompositorInfo {
+            name: env::var("XDG_CURRENT_DESKTOP")
+                .ok()
+                .filter(|name| !name.is_empty()),
+            capabilities,
+            globals,
+        }
+    }
 }
 
 // The default routine does floor, but we need round on Wayland.
@@ -2,6 +2,7 @@
 
 //! Winit's Wayland backend.
 
+use sctk::reexports::client::protocol::wl_seat::WlSeat;
 use sctk::reexports::client::protocol::wl_surface::WlSurface;
 use sctk::reexports::client::Proxy;
 
@@ -17,13 +18,21 @@ mod state;
 mod types;
 mod window;
 
-/// Dummy device id, since Wayland doesn't have device events.
+/// Device id of a seat.
+///
+/// Wayland doesn't expose the individual devices, so all the input devices of a seat share it.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct DeviceId;
+pub struct DeviceId(u32);
 
 impl DeviceId {
     pub const unsafe fn dummy() -> Self {
-        DeviceId
+        DeviceId(0)
+    }
+
+    /// Get the device id of the given seat.
+    #[inline]
+    fn from_seat(seat: &WlSeat) -> Self {
+        DeviceId(seat.id().protocol_id())
     }
 }
 
@@ -45,6 +45,33 @@ impl MonitorHandle {
         output_data.with_output_info(|info| info.name.clone())
     }
 
+    #[inline]
+    pub fn friendly_name(&self) -> Option<String> {
+        let output_data = self.proxy.data::<OutputData>().unwrap();
+        output_data.with_output_info(|info| info.description.clone())
+    }
+
+    #[inline]
+    pub fn make(&self) -> Option<String> {
+        let output_data = self.proxy.data::<OutputData>().unwrap();
+        output_data.with_output_info(|info| (!info.make.is_empty()).then(|| info.make.clone()))
+    }
+
+    #[inline]
+    pub fn model(&self) -> Option<String> {
+        let output_data = self.proxy.data::<OutputData>().unwrap();
+        output_data.with_output_info(|info| (!info.model.is_empty()).then(|| info.model.clone()))
+    }
+
+    #[inline]
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        let output_data = self.proxy.data::<OutputData>().unwrap();
+        match output_data.with_output_info(|info| info.physical_size) {
+            (width, height) if width > 0 && height > 0 => Some((width as u32, height as u32)),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn native_identifier(&self) -> u32 {
         let output_data = self.proxy.data::<OutputData>().unwrap();
@@ -73,6 +100,11 @@ impl MonitorHandle {
         output_data.with_output_info(|info| info.location).into()
     }
 
+    #[inline]
+    pub fn work_area(&self) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+        None
+    }
+
     #[inline]
     pub fn refresh_rate_millihertz(&self) -> Option<u32> {
         let output_data = self.proxy.data::<OutputData>().unwrap();
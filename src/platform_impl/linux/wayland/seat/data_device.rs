@@ -0,0 +1,586 @@
+//! Data device protocol, which backs the clipboard and drag and drop.
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::mem;
+use std::ops::Deref;
+use std::os::unix::io::AsRawFd;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use log::warn;
+
+use rustix::pipe::PipeFlags;
+
+use sctk::compositor::SurfaceData;
+use sctk::globals::GlobalData;
+use sctk::reexports::calloop::generic::Generic;
+use sctk::reexports::calloop::{Interest, Mode, PostAction};
+use sctk::reexports::client::globals::{BindError, GlobalList};
+use sctk::reexports::client::protocol::wl_data_device::{self, WlDataDevice};
+use sctk::reexports::client::protocol::wl_data_device_manager::{DndAction, WlDataDeviceManager};
+use sctk::reexports::client::protocol::wl_data_offer::{self, WlDataOffer};
+use sctk::reexports::client::protocol::wl_data_source::{self, WlDataSource};
+use sctk::reexports::client::{delegate_dispatch, event_created_child, Dispatch};
+use sctk::reexports::client::{Connection, Proxy, QueueHandle, WEnum};
+
+use crate::dpi::{LogicalPosition, PhysicalPosition};
+use crate::event::WindowEvent;
+use crate::platform_impl::wayland::state::WinitState;
+use crate::platform_impl::wayland::{self, WindowId};
+use crate::window::DragAction;
+
+/// Wrapper around the data device manager.
+#[derive(Debug)]
+pub struct DataDeviceManager {
+    manager: WlDataDeviceManager,
+}
+
+impl DataDeviceManager {
+    /// Create new data device manager.
+    pub fn new(
+        globals: &GlobalList,
+        queue_handle: &QueueHandle<WinitState>,
+    ) -> Result<Self, BindError> {
+        let manager = globals.bind(queue_handle, 1..=3, GlobalData)?;
+        Ok(Self { manager })
+    }
+}
+
+impl Deref for DataDeviceManager {
+    type Target = WlDataDeviceManager;
+
+    fn deref(&self) -> &Self::Target {
+        &self.manager
+    }
+}
+
+/// The clipboard of all the seats, shared between the event loop and the windows.
+#[derive(Debug, Default)]
+pub struct Clipboard {
+    /// The device of the seat with the latest input, and the serial of that input.
+    latest_input: Option<(WlDataDevice, u32)>,
+
+    /// The offer for the current selection.
+    offer: Option<WlDataOffer>,
+
+    /// The source of the selection set by the application.
+    source: Option<WlDataSource>,
+
+    /// The drag over one of the windows.
+    drag: Option<Drag>,
+}
+
+/// A drag of another client over a window.
+#[derive(Debug)]
+struct Drag {
+    offer: WlDataOffer,
+
+    /// The serial of the `enter` event, used to accept the offer.
+    serial: u32,
+
+    window_id: WindowId,
+
+    /// The mime type the window accepts a drop in.
+    accepted: Option<String>,
+
+    /// The action the window wants performed on drop.
+    action: DragAction,
+}
+
+impl Drag {
+    fn new(offer: WlDataOffer, serial: u32, window_id: WindowId) -> Self {
+        let drag = Self {
+            offer,
+            serial,
+            window_id,
+            accepted: None,
+            action: DragAction::default(),
+        };
+        drag.update_offer();
+        drag
+    }
+
+    /// The action of the window, `None` if it isn't supported.
+    fn dnd_action(&self) -> Option<DndAction> {
+        match self.action {
+            DragAction::Copy => Some(DndAction::Copy),
+            DragAction::Move => Some(DndAction::Move),
+            DragAction::Link => None,
+        }
+    }
+
+    /// The mime type the drop is accepted in, `None` if it's refused.
+    fn accepted_mime_type(&self) -> Option<String> {
+        self.accepted
+            .clone()
+            .filter(|_| self.dnd_action().is_some())
+    }
+
+    /// Tell the source the mime type and action the drop is accepted with.
+    fn update_offer(&self) {
+        let mime_type = self.accepted_mime_type();
+        let action = match mime_type {
+            Some(_) => self.dnd_action().unwrap_or_else(DndAction::empty),
+            None => DndAction::empty(),
+        };
+
+        // Older versions only refuse the drop through the mime type.
+        self.offer.accept(self.serial, mime_type);
+        if self.offer.version() >= 3 {
+            self.offer.set_actions(action, action);
+        }
+    }
+
+    /// The action the compositor picked for the drop, `None` if it didn't pick one.
+    ///
+    /// Before version 3 there are no actions, and `ask` leaves the choice to the user, which
+    /// winit doesn't offer. Finishing a drop without a picked action is a protocol error.
+    fn negotiated_action(&self) -> Option<DragAction> {
+        let action = match self.offer.data::<DataOfferData>() {
+            Some(data) if self.offer.version() >= 3 => *data.action.lock().unwrap(),
+            _ => return None,
+        };
+
+        if action == DndAction::Copy {
+            Some(DragAction::Copy)
+        } else if action == DndAction::Move {
+            Some(DragAction::Move)
+        } else {
+            None
+        }
+    }
+}
+
+impl Clipboard {
+    /// Remember the serial of an input on the seat, the clipboard can only be set with one.
+    pub fn input_received(&mut self, device: &WlDataDevice, serial: u32) {
+        self.latest_input = Some((device.clone(), serial));
+    }
+
+    /// Forget the device of a seat that is gone.
+    pub fn device_removed(&mut self, device: &WlDataDevice) {
+        if matches!(&self.latest_input, Some((latest, _)) if latest == device) {
+            self.latest_input = None;
+        }
+    }
+
+    /// Offer `data` in the clipboard, returning `false` if there was no input to set it with.
+    pub fn set_data(
+        &mut self,
+        manager: &WlDataDeviceManager,
+        queue_handle: &QueueHandle<WinitState>,
+        data: Vec<(String, Vec<u8>)>,
+    ) -> bool {
+        let (device, serial) = match self.latest_input.as_ref() {
+            Some(latest_input) => latest_input,
+            None => return false,
+        };
+
+        let data: Arc<[(String, Vec<u8>)]> = data.into();
+        let source =
+            manager.create_data_source(queue_handle, DataSourceData { data: data.clone() });
+        for (mime_type, _) in data.iter() {
+            source.offer(mime_type.clone());
+        }
+        device.set_selection(Some(&source), *serial);
+
+        if let Some(source) = self.source.replace(source) {
+            source.destroy();
+        }
+
+        true
+    }
+
+    /// The mime types the clipboard is offered in.
+    pub fn mime_types(&self) -> Vec<String> {
+        self.offer
+            .as_ref()
+            .and_then(|offer| offer.data::<DataOfferData>())
+            .map(|data| data.mime_types.lock().unwrap().clone())
+            .unwrap_or_default()
+    }
+
+    /// Read the clipboard in `mime_type` on another thread and pass it to `callback`.
+    ///
+    /// The `callback` gets `None` right away when the clipboard isn't offered in `mime_type`.
+    pub fn read_data<F>(&self, connection: &Connection, mime_type: &str, callback: F)
+    where
+        F: FnOnce(Option<Vec<u8>>) + Send + 'static,
+    {
+        let pipe = self.offer.as_ref().and_then(|offer| {
+            let offered = offer
+                .data::<DataOfferData>()?
+                .mime_types
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|offered| offered == mime_type);
+            if !offered {
+                return None;
+            }
+
+            let (read, write) = rustix::pipe::pipe_with(PipeFlags::CLOEXEC)
+                .map_err(|err| warn!("Failed to create a pipe for the clipboard: {err}"))
+                .ok()?;
+
+            // The write end is duplicated when sending the request, so it can be closed here.
+            offer.receive(mime_type.to_owned(), write.as_raw_fd());
+            Some(read)
+        });
+
+        let read = match pipe {
+            Some(read) => read,
+            None => return callback(None),
+        };
+
+        // The source only starts writing once it got the request.
+        let _ = connection.flush();
+
+        thread::spawn(move || {
+            let mut data = Vec::new();
+            let data = match File::from(read).read_to_end(&mut data) {
+                Ok(_) => Some(data),
+                Err(err) => {
+                    warn!("Failed to read the clipboard: {err}");
+                    None
+                }
+            };
+
+            callback(data);
+        });
+    }
+
+    /// Accept the drop of the drag over the window in `mime_type`, or refuse it with `None`.
+    ///
+    /// Returns `false` if there's no drag over the window.
+    pub fn set_drag_accept(&mut self, window_id: WindowId, mime_type: Option<&str>) -> bool {
+        let drag = match self.drag.as_mut() {
+            Some(drag) if drag.window_id == window_id => drag,
+            _ => return false,
+        };
+
+        if let Some(mime_type) = mime_type {
+            let offered = drag.offer.data::<DataOfferData>().map_or(false, |data| {
+                data.mime_types
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .any(|offered| offered == mime_type)
+            });
+            if !offered {
+                warn!("The drag isn't offered in `{mime_type}`");
+                return false;
+            }
+        }
+
+        drag.accepted = mime_type.map(str::to_owned);
+        drag.update_offer();
+        true
+    }
+
+    /// Set the action performed on drop of the drag over the window.
+    ///
+    /// Returns `false` if there's no drag over the window.
+    pub fn set_drag_action(&mut self, window_id: WindowId, action: DragAction) -> bool {
+        match self.drag.as_mut() {
+            Some(drag) if drag.window_id == window_id => {
+                drag.action = action;
+                drag.update_offer();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The mime types the clipboard or a drag is offered in.
+#[derive(Debug)]
+pub struct DataOfferData {
+    mime_types: Mutex<Vec<String>>,
+
+    /// The action agreed on for a drag, since version 3.
+    action: Mutex<DndAction>,
+}
+
+impl Default for DataOfferData {
+    fn default() -> Self {
+        Self {
+            mime_types: Default::default(),
+            action: Mutex::new(DndAction::empty()),
+        }
+    }
+}
+
+/// The data of the clipboard set by the application, for each mime type.
+#[derive(Debug)]
+pub struct DataSourceData {
+    data: Arc<[(String, Vec<u8>)]>,
+}
+
+impl Dispatch<WlDataDeviceManager, GlobalData, WinitState> for DataDeviceManager {
+    fn event(
+        _state: &mut WinitState,
+        _proxy: &WlDataDeviceManager,
+        _event: <WlDataDeviceManager as Proxy>::Event,
+        _data: &GlobalData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+    }
+}
+
+impl Dispatch<WlDataDevice, GlobalData, WinitState> for DataDeviceManager {
+    event_created_child!(WinitState, WlDataDevice, [
+        wl_data_device::EVT_DATA_OFFER_OPCODE => (WlDataOffer, DataOfferData::default())
+    ]);
+
+    fn event(
+        state: &mut WinitState,
+        _device: &WlDataDevice,
+        event: <WlDataDevice as Proxy>::Event,
+        _data: &GlobalData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        match event {
+            // The offer is announced with `DataOffer` right before it becomes the selection.
+            wl_data_device::Event::Selection { id } => {
+                let mut clipboard = state.clipboard.lock().unwrap();
+                if let Some(offer) = mem::replace(&mut clipboard.offer, id) {
+                    offer.destroy();
+                }
+            }
+            wl_data_device::Event::Enter {
+                serial,
+                surface,
+                x,
+                y,
+                id: Some(offer),
+            } => {
+                // Drags over the decorations are over the window.
+                let surface = match surface.data::<SurfaceData>() {
+                    Some(data) => data.parent_surface().unwrap_or(&surface).clone(),
+                    None => surface,
+                };
+                let window_id = wayland::make_wid(&surface);
+
+                let position = match drag_position(state, window_id, x, y) {
+                    Some(position) => position,
+                    None => return offer.destroy(),
+                };
+                let mime_types = offer
+                    .data::<DataOfferData>()
+                    .map(|data| data.mime_types.lock().unwrap().clone())
+                    .unwrap_or_default();
+
+                let drag = Drag::new(offer, serial, window_id);
+
+                let mut clipboard = state.clipboard.lock().unwrap();
+                if let Some(drag) = clipboard.drag.replace(drag) {
+                    drag.offer.destroy();
+                }
+                drop(clipboard);
+
+                state.events_sink.push_window_event(
+                    WindowEvent::DragEntered {
+                        mime_types,
+                        position,
+                    },
+                    window_id,
+                );
+            }
+            wl_data_device::Event::Motion { x, y, .. } => {
+                let window_id = match state.clipboard.lock().unwrap().drag.as_ref() {
+                    Some(drag) => drag.window_id,
+                    None => return,
+                };
+
+                if let Some(position) = drag_position(state, window_id, x, y) {
+                    state
+                        .events_sink
+                        .push_window_event(WindowEvent::DragMoved { position }, window_id);
+                }
+            }
+            wl_data_device::Event::Leave => {
+                let drag = state.clipboard.lock().unwrap().drag.take();
+                if let Some(drag) = drag {
+                    drag.offer.destroy();
+                    state
+                        .events_sink
+                        .push_window_event(WindowEvent::DragLeft, drag.window_id);
+                }
+            }
+            wl_data_device::Event::Drop => {
+                let drag = state.clipboard.lock().unwrap().drag.take();
+                if let Some(drag) = drag {
+                    receive_drop(state, drag);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// The position of a drag in the window, or `None` if the window is gone.
+fn drag_position(
+    state: &mut WinitState,
+    window_id: WindowId,
+    x: f64,
+    y: f64,
+) -> Option<PhysicalPosition<f64>> {
+    let scale_factor = state
+        .windows
+        .get_mut()
+        .get(&window_id)?
+        .lock()
+        .unwrap()
+        .scale_factor();
+    Some(LogicalPosition::new(x, y).to_physical(scale_factor))
+}
+
+/// Read the data of a dropped drag in the accepted mime type, and deliver it to the window.
+fn receive_drop(state: &mut WinitState, drag: Drag) {
+    let mime_type = match drag.accepted_mime_type() {
+        Some(mime_type) => mime_type,
+        None => {
+            drag.offer.destroy();
+            state
+                .events_sink
+                .push_window_event(WindowEvent::DragLeft, drag.window_id);
+            return;
+        }
+    };
+
+    let pipe = rustix::pipe::pipe_with(PipeFlags::CLOEXEC | PipeFlags::NONBLOCK)
+        .map_err(|err| warn!("Failed to create a pipe for the drop: {err}"));
+    let read = match pipe {
+        Ok((read, write)) => {
+            // The write end is duplicated when sending the request, so it can be closed here.
+            drag.offer.receive(mime_type.clone(), write.as_raw_fd());
+            read
+        }
+        Err(()) => {
+            drag.offer.destroy();
+            state.events_sink.push_window_event(
+                WindowEvent::DragDropped {
+                    mime_type,
+                    data: None,
+                    action: drag.negotiated_action(),
+                },
+                drag.window_id,
+            );
+            return;
+        }
+    };
+
+    // The event loop flushes the request before polling, so read the data from it as well
+    // to finish the drop without blocking.
+    let action = drag.negotiated_action();
+    let offer = drag.offer;
+    let window_id = drag.window_id;
+    let mut data = Vec::new();
+    let source = Generic::new(File::from(read), Interest::READ, Mode::Level);
+    let inserted = state
+        .loop_handle
+        .insert_source(source, move |_, file, state| {
+            let mut buf = [0; 4096];
+            let data = loop {
+                match file.read(&mut buf) {
+                    // The source closed its end, which completes the data.
+                    Ok(0) => break Some(mem::take(&mut data)),
+                    Ok(len) => data.extend_from_slice(&buf[..len]),
+                    Err(err) if err.kind() == io::ErrorKind::Interrupted => (),
+                    Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                        return Ok(PostAction::Continue)
+                    }
+                    Err(err) => {
+                        warn!("Failed to read the drop: {err}");
+                        break None;
+                    }
+                }
+            };
+
+            if action.is_some() && data.is_some() {
+                offer.finish();
+            }
+            offer.destroy();
+
+            state.events_sink.push_window_event(
+                WindowEvent::DragDropped {
+                    mime_type: mime_type.clone(),
+                    data,
+                    action,
+                },
+                window_id,
+            );
+
+            Ok(PostAction::Remove)
+        });
+
+    if let Err(err) = inserted {
+        warn!("Failed to read the drop: {err}");
+    }
+}
+
+impl Dispatch<WlDataOffer, DataOfferData, WinitState> for DataDeviceManager {
+    fn event(
+        _state: &mut WinitState,
+        _offer: &WlDataOffer,
+        event: <WlDataOffer as Proxy>::Event,
+        data: &DataOfferData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        match event {
+            wl_data_offer::Event::Offer { mime_type } => {
+                data.mime_types.lock().unwrap().push(mime_type);
+            }
+            wl_data_offer::Event::Action {
+                dnd_action: WEnum::Value(action),
+            } => *data.action.lock().unwrap() = action,
+            _ => (),
+        }
+    }
+}
+
+impl Dispatch<WlDataSource, DataSourceData, WinitState> for DataDeviceManager {
+    fn event(
+        state: &mut WinitState,
+        source: &WlDataSource,
+        event: <WlDataSource as Proxy>::Event,
+        data: &DataSourceData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        match event {
+            wl_data_source::Event::Send { mime_type, fd } => {
+                // Don't block the event loop on a slow reader.
+                let data = data.data.clone();
+                thread::spawn(move || {
+                    let bytes = match data.iter().find(|(offered, _)| *offered == mime_type) {
+                        Some((_, bytes)) => bytes,
+                        None => return,
+                    };
+
+                    if let Err(err) = File::from(fd).write_all(bytes) {
+                        warn!("Failed to write the clipboard: {err}");
+                    }
+                });
+            }
+            wl_data_source::Event::Cancelled => {
+                let mut clipboard = state.clipboard.lock().unwrap();
+                if clipboard.source.as_ref() == Some(source) {
+                    clipboard.source = None;
+                }
+                source.destroy();
+            }
+            _ => (),
+        }
+    }
+}
+
+delegate_dispatch!(WinitState: [WlDataDeviceManager: GlobalData] => DataDeviceManager);
+delegate_dispatch!(WinitState: [WlDataDevice: GlobalData] => DataDeviceManager);
+delegate_dispatch!(WinitState: [WlDataOffer: DataOfferData] => DataDeviceManager);
+delegate_dispatch!(WinitState: [WlDataSource: DataSourceData] => DataDeviceManager);
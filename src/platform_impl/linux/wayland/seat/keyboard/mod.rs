@@ -15,7 +15,7 @@ use sctk::reexports::client::protocol::wl_seat::WlSeat;
 use sctk::reexports::client::{Connection, Dispatch, Proxy, QueueHandle, WEnum};
 
 use crate::event::{ElementState, WindowEvent};
-use crate::keyboard::ModifiersState;
+use crate::keyboard::{LockKeyState, ModifiersState};
 
 use crate::platform_impl::common::xkb_state::KbdState;
 use crate::platform_impl::wayland::event_loop::sink::EventSink;
@@ -37,6 +37,15 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
             None => return,
         };
 
+        if let WlKeyboardEvent::Enter { serial, .. } | WlKeyboardEvent::Key { serial, .. } = &event
+        {
+            seat_state.selection_input_received(
+                &state.primary_selection,
+                &state.clipboard,
+                *serial,
+            );
+        }
+
         match event {
             WlKeyboardEvent::Keymap { format, fd, size } => match format {
                 WEnum::Value(format) => match format {
@@ -152,6 +161,20 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
                     .loop_handle
                     .insert_source(timer, move |_, _, state| {
                         let data = wl_keyboard.data::<KeyboardData>().unwrap();
+
+                        // NOTE: the window could disable the key repeat while it's going.
+                        let key_repeat = match *data.window_id.lock().unwrap() {
+                            Some(window_id) => state
+                                .windows
+                                .get_mut()
+                                .get(&window_id)
+                                .map_or(true, |window| window.lock().unwrap().key_repeat()),
+                            None => true,
+                        };
+                        if !key_repeat {
+                            return TimeoutAction::Drop;
+                        }
+
                         let seat_state = state.seats.get_mut(&data.seat.id()).unwrap();
 
                         // NOTE: The removed on event source is batched, but key change to
@@ -214,6 +237,12 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
                 xkb_state.update_modifiers(mods_depressed, mods_latched, mods_locked, 0, 0, group);
                 seat_state.modifiers = xkb_state.mods_state().into();
 
+                let lock_keys: LockKeyState = xkb_state.mods_state().into();
+                let lock_keys_changed = {
+                    let mut current = state.lock_keys.lock().unwrap();
+                    std::mem::replace(&mut *current, lock_keys) != lock_keys
+                };
+
                 // HACK: part of the workaround from `WlKeyboardEvent::Enter`.
                 let window_id = match *data.window_id.lock().unwrap() {
                     Some(window_id) => window_id,
@@ -227,6 +256,12 @@ impl Dispatch<WlKeyboard, KeyboardData, WinitState> for WinitState {
                     WindowEvent::ModifiersChanged(seat_state.modifiers.into()),
                     window_id,
                 );
+
+                if lock_keys_changed {
+                    state
+                        .events_sink
+                        .push_window_event(WindowEvent::LockKeysChanged(lock_keys), window_id);
+                }
             }
             WlKeyboardEvent::RepeatInfo { rate, delay } => {
                 let keyboard_state = seat_state.keyboard_state.as_mut().unwrap();
@@ -357,7 +392,9 @@ fn key_input(
 
     let keyboard_state = seat_state.keyboard_state.as_mut().unwrap();
 
-    let device_id = crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(DeviceId));
+    let device_id = crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
+        DeviceId::from_seat(&data.seat),
+    ));
     let event = keyboard_state
         .xkb_state
         .process_key_event(keycode, state, repeat);
@@ -1,28 +1,38 @@
 //! Seat handling.
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use fnv::FnvHashMap;
 
+use sctk::reexports::client::protocol::wl_data_device::WlDataDevice;
 use sctk::reexports::client::protocol::wl_seat::WlSeat;
 use sctk::reexports::client::protocol::wl_touch::WlTouch;
 use sctk::reexports::client::{Connection, Proxy, QueueHandle};
+use sctk::reexports::protocols::wp::primary_selection::zv1::client::zwp_primary_selection_device_v1::ZwpPrimarySelectionDeviceV1;
 use sctk::reexports::protocols::wp::relative_pointer::zv1::client::zwp_relative_pointer_v1::ZwpRelativePointerV1;
 use sctk::reexports::protocols::wp::text_input::zv3::client::zwp_text_input_v3::ZwpTextInputV3;
 
 use sctk::seat::pointer::{ThemeSpec, ThemedPointer};
 use sctk::seat::{Capability as SeatCapability, SeatHandler, SeatState};
 
+use crate::event::{DeviceEvent, DeviceKind};
 use crate::keyboard::ModifiersState;
 use crate::platform_impl::wayland::state::WinitState;
+use crate::platform_impl::wayland::DeviceId;
 
+mod data_device;
 mod keyboard;
 mod pointer;
+mod primary_selection;
 mod text_input;
 mod touch;
 
+pub use data_device::{Clipboard, DataDeviceManager};
+pub use pointer::cursor_cache::CursorCache;
+pub use pointer::cursor_shape::{cursor_icon_to_shape, CursorShapeManager};
 pub use pointer::relative_pointer::RelativePointerState;
 pub use pointer::{PointerConstraintsState, WinitPointerData, WinitPointerDataExt};
+pub use primary_selection::{PrimarySelection, PrimarySelectionManager};
 pub use text_input::{TextInputState, ZwpTextInputV3Ext};
 
 use keyboard::{KeyboardData, KeyboardState};
@@ -46,6 +56,12 @@ pub struct WinitSeatState {
     /// The relative pointer bound on the seat.
     relative_pointer: Option<ZwpRelativePointerV1>,
 
+    /// The primary selection device bound on the seat.
+    primary_selection_device: Option<ZwpPrimarySelectionDeviceV1>,
+
+    /// The data device bound on the seat, for the clipboard.
+    data_device: Option<WlDataDevice>,
+
     /// The keyboard bound on the seat.
     keyboard_state: Option<KeyboardState>,
 
@@ -63,12 +79,33 @@ impl WinitSeatState {
             touch: None,
             relative_pointer: None,
             text_input: None,
+            primary_selection_device: None,
+            data_device: None,
             touch_map: Default::default(),
             keyboard_state: None,
             modifiers: ModifiersState::empty(),
             modifiers_pending: false,
         }
     }
+
+    /// Remember the serial of an input on the seat, the selections can only be set with one.
+    fn selection_input_received(
+        &self,
+        primary_selection: &Mutex<PrimarySelection>,
+        clipboard: &Mutex<Clipboard>,
+        serial: u32,
+    ) {
+        if let Some(device) = self.primary_selection_device.as_ref() {
+            primary_selection
+                .lock()
+                .unwrap()
+                .input_received(device, serial);
+        }
+
+        if let Some(device) = self.data_device.as_ref() {
+            clipboard.lock().unwrap().input_received(device, serial);
+        }
+    }
 }
 
 impl SeatHandler for WinitState {
@@ -85,14 +122,16 @@ impl SeatHandler for WinitState {
     ) {
         let seat_state = self.seats.get_mut(&seat.id()).unwrap();
 
-        match capability {
+        let added = match capability {
             SeatCapability::Touch if seat_state.touch.is_none() => {
                 seat_state.touch = self.seat_state.get_touch(queue_handle, &seat).ok();
+                seat_state.touch.is_some().then_some(DeviceKind::Touch)
             }
             SeatCapability::Keyboard if seat_state.keyboard_state.is_none() => {
                 let keyboard = seat.get_keyboard(queue_handle, KeyboardData::new(seat.clone()));
                 seat_state.keyboard_state =
                     Some(KeyboardState::new(keyboard, self.loop_handle.clone()));
+                Some(DeviceKind::Keyboard)
             }
             SeatCapability::Pointer if seat_state.pointer.is_none() => {
                 let surface = self.compositor_state.create_surface(queue_handle);
@@ -116,6 +155,18 @@ impl SeatHandler for WinitState {
                     )
                 });
 
+                if let Some(cursor_shape_manager) = self.cursor_shape_manager.as_ref() {
+                    let device = cursor_shape_manager.get_pointer(
+                        themed_pointer.pointer(),
+                        queue_handle,
+                        sctk::globals::GlobalData,
+                    );
+                    themed_pointer
+                        .pointer()
+                        .winit_data()
+                        .set_cursor_shape_device(device);
+                }
+
                 let themed_pointer = Arc::new(themed_pointer);
 
                 // Register cursor surface.
@@ -123,8 +174,14 @@ impl SeatHandler for WinitState {
                     .insert(surface_id, themed_pointer.clone());
 
                 seat_state.pointer = Some(themed_pointer);
+                Some(DeviceKind::Pointer)
             }
-            _ => (),
+            _ => None,
+        };
+
+        if let Some(kind) = added {
+            self.events_sink
+                .push_device_event(DeviceEvent::Added { kind }, DeviceId::from_seat(&seat));
         }
 
         if let Some(text_input_state) = seat_state
@@ -139,6 +196,26 @@ impl SeatHandler for WinitState {
                 TextInputData::default(),
             )));
         }
+
+        if let Some(manager) = seat_state
+            .primary_selection_device
+            .is_none()
+            .then_some(self.primary_selection_manager.as_ref())
+            .flatten()
+        {
+            seat_state.primary_selection_device =
+                Some(manager.get_device(&seat, queue_handle, sctk::globals::GlobalData));
+        }
+
+        if let Some(manager) = seat_state
+            .data_device
+            .is_none()
+            .then_some(self.data_device_manager.as_ref())
+            .flatten()
+        {
+            seat_state.data_device =
+                Some(manager.get_data_device(&seat, queue_handle, sctk::globals::GlobalData));
+        }
     }
 
     fn remove_capability(
@@ -150,20 +227,19 @@ impl SeatHandler for WinitState {
     ) {
         let seat_state = self.seats.get_mut(&seat.id()).unwrap();
 
-        match capability {
-            SeatCapability::Touch => {
-                if let Some(touch) = seat_state.touch.take() {
-                    if touch.version() >= 3 {
-                        touch.release();
-                    }
+        let removed = match capability {
+            SeatCapability::Touch => seat_state.touch.take().map(|touch| {
+                if touch.version() >= 3 {
+                    touch.release();
                 }
-            }
+                DeviceKind::Touch
+            }),
             SeatCapability::Pointer => {
                 if let Some(relative_pointer) = seat_state.relative_pointer.take() {
                     relative_pointer.destroy();
                 }
 
-                if let Some(pointer) = seat_state.pointer.take() {
+                seat_state.pointer.take().map(|pointer| {
                     let pointer_data = pointer.pointer().winit_data();
 
                     // Remove the cursor from the mapping.
@@ -177,12 +253,20 @@ impl SeatHandler for WinitState {
                     if pointer.pointer().version() >= 3 {
                         pointer.pointer().release();
                     }
-                }
-            }
-            SeatCapability::Keyboard => {
-                seat_state.keyboard_state = None;
+
+                    DeviceKind::Pointer
+                })
             }
-            _ => (),
+            SeatCapability::Keyboard => seat_state
+                .keyboard_state
+                .take()
+                .map(|_| DeviceKind::Keyboard),
+            _ => None,
+        };
+
+        if let Some(kind) = removed {
+            self.events_sink
+                .push_device_event(DeviceEvent::Removed { kind }, DeviceId::from_seat(&seat));
         }
 
         if let Some(text_input) = seat_state.text_input.take() {
@@ -205,7 +289,25 @@ impl SeatHandler for WinitState {
         _queue_handle: &QueueHandle<Self>,
         seat: WlSeat,
     ) {
-        let _ = self.seats.remove(&seat.id());
+        let seat_state = match self.seats.remove(&seat.id()) {
+            Some(seat_state) => seat_state,
+            None => return,
+        };
+
+        if let Some(device) = seat_state.primary_selection_device {
+            self.primary_selection
+                .lock()
+                .unwrap()
+                .device_removed(&device);
+            device.destroy();
+        }
+
+        if let Some(device) = seat_state.data_device {
+            self.clipboard.lock().unwrap().device_removed(&device);
+            if device.version() >= 2 {
+                device.release();
+            }
+        }
     }
 }
 
@@ -0,0 +1,130 @@
+//! Cache of the cursor images loaded from the cursor theme.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::env;
+
+use sctk::reexports::client::protocol::wl_pointer::WlPointer;
+use sctk::reexports::client::protocol::wl_shm::WlShm;
+use sctk::reexports::client::protocol::wl_surface::WlSurface;
+use sctk::reexports::client::{Connection, Proxy};
+
+use wayland_cursor::{CursorImageBuffer, CursorTheme};
+
+/// The cursor images of the system cursor theme, shared by all pointers.
+///
+/// The theme is loaded once per scale, and every cursor once per scale as well. Cursors the
+/// theme doesn't provide are remembered too, as looking them up means searching the theme
+/// directories on disk.
+#[derive(Debug)]
+pub struct CursorCache {
+    /// The name of the theme, from `XCURSOR_THEME`.
+    name: String,
+
+    /// The size of the cursors at scale 1, from `XCURSOR_SIZE`.
+    size: u32,
+
+    /// The themes loaded for a given scale.
+    themes: HashMap<u32, CursorTheme>,
+
+    /// The names of the cursors missing from the theme, for each scale.
+    missing: HashMap<u32, HashSet<String>>,
+}
+
+impl CursorCache {
+    pub fn new() -> Self {
+        let name = env::var("XCURSOR_THEME").unwrap_or_else(|_| "default".into());
+        let size = env::var("XCURSOR_SIZE")
+            .ok()
+            .and_then(|size| size.parse().ok())
+            .unwrap_or(24);
+
+        Self {
+            name,
+            size,
+            themes: HashMap::new(),
+            missing: HashMap::new(),
+        }
+    }
+
+    /// Get the first image of the cursor with the given name, loading it if needed.
+    pub fn get(
+        &mut self,
+        connection: &Connection,
+        shm: &WlShm,
+        name: &str,
+        scale: u32,
+    ) -> Option<&CursorImageBuffer> {
+        if self
+            .missing
+            .get(&scale)
+            .map_or(false, |missing| missing.contains(name))
+        {
+            return None;
+        }
+
+        let theme = match self.themes.entry(scale) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let theme = CursorTheme::load_from_name(
+                    connection,
+                    shm.clone(),
+                    &self.name,
+                    self.size * scale,
+                )
+                .ok()?;
+                entry.insert(theme)
+            }
+        };
+
+        match theme.get_cursor(name) {
+            Some(cursor) => Some(&cursor[0]),
+            None => {
+                self.missing
+                    .entry(scale)
+                    .or_default()
+                    .insert(name.to_owned());
+                None
+            }
+        }
+    }
+
+    /// Show the cursor with the given name for the pointer, returning `false` if it's missing.
+    #[allow(clippy::too_many_arguments)]
+    pub fn set_cursor(
+        &mut self,
+        connection: &Connection,
+        shm: &WlShm,
+        pointer: &WlPointer,
+        serial: u32,
+        surface: &WlSurface,
+        name: &str,
+        scale: i32,
+    ) -> bool {
+        let image = match self.get(connection, shm, name, scale as u32) {
+            Some(image) => image,
+            None => return false,
+        };
+
+        let (width, height) = image.dimensions();
+        let (hotspot_x, hotspot_y) = image.hotspot();
+
+        surface.set_buffer_scale(scale);
+        surface.attach(Some(image), 0, 0);
+        if surface.version() >= 4 {
+            surface.damage_buffer(0, 0, width as i32, height as i32);
+        } else {
+            surface.damage(0, 0, width as i32 / scale, height as i32 / scale);
+        }
+        surface.commit();
+
+        pointer.set_cursor(
+            serial,
+            Some(surface),
+            hotspot_x as i32 / scale,
+            hotspot_y as i32 / scale,
+        );
+
+        true
+    }
+}
@@ -0,0 +1,113 @@
+//! Cursor shape protocol.
+
+use std::ops::Deref;
+
+use sctk::reexports::client::globals::{BindError, GlobalList};
+use sctk::reexports::client::{delegate_dispatch, Dispatch};
+use sctk::reexports::client::{Connection, QueueHandle};
+use sctk::reexports::protocols::wp::cursor_shape::v1::client::{
+    wp_cursor_shape_device_v1::{Shape, WpCursorShapeDeviceV1},
+    wp_cursor_shape_manager_v1::WpCursorShapeManagerV1,
+};
+
+use sctk::globals::GlobalData;
+
+use crate::platform_impl::wayland::state::WinitState;
+use crate::window::CursorIcon;
+
+/// Wrapper around the cursor shape manager.
+#[derive(Debug)]
+pub struct CursorShapeManager {
+    manager: WpCursorShapeManagerV1,
+}
+
+impl CursorShapeManager {
+    /// Create new cursor shape manager.
+    pub fn new(
+        globals: &GlobalList,
+        queue_handle: &QueueHandle<WinitState>,
+    ) -> Result<Self, BindError> {
+        let manager = globals.bind(queue_handle, 1..=1, GlobalData)?;
+        Ok(Self { manager })
+    }
+}
+
+impl Deref for CursorShapeManager {
+    type Target = WpCursorShapeManagerV1;
+
+    fn deref(&self) -> &Self::Target {
+        &self.manager
+    }
+}
+
+/// Map the cursor icon to the shape the compositor should draw.
+///
+/// Returns `None` for icons the protocol doesn't know about.
+pub fn cursor_icon_to_shape(cursor_icon: CursorIcon) -> Option<Shape> {
+    let shape = match cursor_icon {
+        CursorIcon::Default => Shape::Default,
+        CursorIcon::ContextMenu => Shape::ContextMenu,
+        CursorIcon::Help => Shape::Help,
+        CursorIcon::Pointer => Shape::Pointer,
+        CursorIcon::Progress => Shape::Progress,
+        CursorIcon::Wait => Shape::Wait,
+        CursorIcon::Cell => Shape::Cell,
+        CursorIcon::Crosshair => Shape::Crosshair,
+        CursorIcon::Text => Shape::Text,
+        CursorIcon::VerticalText => Shape::VerticalText,
+        CursorIcon::Alias => Shape::Alias,
+        CursorIcon::Copy => Shape::Copy,
+        CursorIcon::Move => Shape::Move,
+        CursorIcon::NoDrop => Shape::NoDrop,
+        CursorIcon::NotAllowed => Shape::NotAllowed,
+        CursorIcon::Grab => Shape::Grab,
+        CursorIcon::Grabbing => Shape::Grabbing,
+        CursorIcon::EResize => Shape::EResize,
+        CursorIcon::NResize => Shape::NResize,
+        CursorIcon::NeResize => Shape::NeResize,
+        CursorIcon::NwResize => Shape::NwResize,
+        CursorIcon::SResize => Shape::SResize,
+        CursorIcon::SeResize => Shape::SeResize,
+        CursorIcon::SwResize => Shape::SwResize,
+        CursorIcon::WResize => Shape::WResize,
+        CursorIcon::EwResize => Shape::EwResize,
+        CursorIcon::NsResize => Shape::NsResize,
+        CursorIcon::NeswResize => Shape::NeswResize,
+        CursorIcon::NwseResize => Shape::NwseResize,
+        CursorIcon::ColResize => Shape::ColResize,
+        CursorIcon::RowResize => Shape::RowResize,
+        CursorIcon::AllScroll => Shape::AllScroll,
+        CursorIcon::ZoomIn => Shape::ZoomIn,
+        CursorIcon::ZoomOut => Shape::ZoomOut,
+        _ => return None,
+    };
+
+    Some(shape)
+}
+
+impl Dispatch<WpCursorShapeManagerV1, GlobalData, WinitState> for CursorShapeManager {
+    fn event(
+        _state: &mut WinitState,
+        _proxy: &WpCursorShapeManagerV1,
+        _event: <WpCursorShapeManagerV1 as wayland_client::Proxy>::Event,
+        _data: &GlobalData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+    }
+}
+
+impl Dispatch<WpCursorShapeDeviceV1, GlobalData, WinitState> for CursorShapeManager {
+    fn event(
+        _state: &mut WinitState,
+        _proxy: &WpCursorShapeDeviceV1,
+        _event: <WpCursorShapeDeviceV1 as wayland_client::Proxy>::Event,
+        _data: &GlobalData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+    }
+}
+
+delegate_dispatch!(WinitState: [WpCursorShapeManagerV1: GlobalData] => CursorShapeManager);
+delegate_dispatch!(WinitState: [WpCursorShapeDeviceV1: GlobalData] => CursorShapeManager);
@@ -8,8 +8,9 @@ use sctk::reexports::client::protocol::wl_pointer::WlPointer;
 use sctk::reexports::client::protocol::wl_seat::WlSeat;
 use sctk::reexports::client::protocol::wl_surface::WlSurface;
 use sctk::reexports::client::{Connection, Proxy, QueueHandle, Dispatch};
-use sctk::reexports::protocols::wp::pointer_constraints::zv1::client::zwp_confined_pointer_v1::ZwpConfinedPointerV1;
-use sctk::reexports::protocols::wp::pointer_constraints::zv1::client::zwp_locked_pointer_v1::ZwpLockedPointerV1;
+use sctk::reexports::protocols::wp::cursor_shape::v1::client::wp_cursor_shape_device_v1::WpCursorShapeDeviceV1;
+use sctk::reexports::protocols::wp::pointer_constraints::zv1::client::zwp_confined_pointer_v1::{self, ZwpConfinedPointerV1};
+use sctk::reexports::protocols::wp::pointer_constraints::zv1::client::zwp_locked_pointer_v1::{self, ZwpLockedPointerV1};
 use sctk::reexports::protocols::wp::pointer_constraints::zv1::client::zwp_pointer_constraints_v1::{Lifetime, ZwpPointerConstraintsV1};
 use sctk::reexports::client::globals::{BindError, GlobalList};
 
@@ -28,6 +29,10 @@ use crate::event::{
 use crate::platform_impl::wayland::state::WinitState;
 use crate::platform_impl::wayland::{self, DeviceId, WindowId};
 
+use cursor_shape::cursor_icon_to_shape;
+
+pub mod cursor_cache;
+pub mod cursor_shape;
 pub mod relative_pointer;
 
 impl PointerHandler for WinitState {
@@ -41,7 +46,9 @@ impl PointerHandler for WinitState {
         let seat = pointer.winit_data().seat();
         let seat_state = self.seats.get(&seat.id()).unwrap();
 
-        let device_id = crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(DeviceId));
+        let device_id = crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
+            DeviceId::from_seat(seat),
+        ));
 
         for event in events {
             let surface = &event.surface;
@@ -69,25 +76,27 @@ impl PointerHandler for WinitState {
                 PointerEventKind::Enter { .. } | PointerEventKind::Motion { .. }
                     if parent_surface != surface =>
                 {
+                    let cursor_scale_factor = window.cursor_scale_factor();
                     if let Some(icon) =
                         window.frame_point_moved(seat, surface, event.position.0, event.position.1)
                     {
                         if let Some(pointer) = seat_state.pointer.as_ref() {
-                            let surface = pointer
-                                .pointer()
-                                .data::<WinitPointerData>()
-                                .unwrap()
-                                .cursor_surface();
-                            let scale_factor =
-                                surface.data::<SurfaceData>().unwrap().scale_factor();
-
-                            let _ = pointer.set_cursor(
-                                connection,
-                                icon,
-                                self.shm.wl_shm(),
-                                surface,
-                                scale_factor,
-                            );
+                            let data = pointer.pointer().data::<WinitPointerData>().unwrap();
+
+                            let shape = icon.parse().ok().and_then(cursor_icon_to_shape);
+                            if let Some((device, shape)) = data.cursor_shape_device().zip(shape) {
+                                device.set_shape(data.latest_enter_serial(), shape);
+                            } else {
+                                self.cursor_cache.lock().unwrap().set_cursor(
+                                    connection,
+                                    self.shm.wl_shm(),
+                                    pointer.pointer(),
+                                    data.latest_enter_serial(),
+                                    data.cursor_surface(),
+                                    icon,
+                                    cursor_scale_factor,
+                                );
+                            }
                         }
                     }
                 }
@@ -179,7 +188,29 @@ impl PointerHandler for WinitState {
                         .unwrap()
                         .latest_button_serial = serial;
 
+                    seat_state.selection_input_received(
+                        &self.primary_selection,
+                        &self.clipboard,
+                        serial,
+                    );
+
+                    let raw_button = button;
                     let button = wayland_button_to_winit(button);
+
+                    // The compositor takes over the pointer on the title bar and resize handles,
+                    // so the release of a consumed press must not reach the user either.
+                    if let PointerEventKind::Press { time, .. } = *kind {
+                        let position = LogicalPosition::new(event.position.0, event.position.1);
+                        let consumed = window.hittest_press(seat, serial, time, button, position);
+                        pointer
+                            .winit_data()
+                            .set_hittest_button(raw_button, consumed);
+                        if consumed {
+                            continue;
+                        }
+                    } else if pointer.winit_data().take_hittest_button(raw_button) {
+                        continue;
+                    }
                     let state = if matches!(kind, PointerEventKind::Press { .. }) {
                         ElementState::Pressed
                     } else {
@@ -291,11 +322,21 @@ impl WinitPointerData {
                 None,
                 Lifetime::Persistent,
                 queue_handle,
-                GlobalData,
+                PointerConstraintData::new(surface),
             ));
         }
     }
 
+    /// Set the cursor shape device used to request standard cursors from the compositor.
+    pub fn set_cursor_shape_device(&self, device: WpCursorShapeDeviceV1) {
+        self.inner.lock().unwrap().cursor_shape_device = Some(device);
+    }
+
+    /// The cursor shape device, if the compositor supports the cursor shape protocol.
+    pub fn cursor_shape_device(&self) -> Option<WpCursorShapeDeviceV1> {
+        self.inner.lock().unwrap().cursor_shape_device.clone()
+    }
+
     pub fn unlock_pointer(&self) {
         let mut inner = self.inner.lock().unwrap();
         if let Some(locked_pointer) = inner.locked_pointer.take() {
@@ -316,7 +357,7 @@ impl WinitPointerData {
             None,
             Lifetime::Persistent,
             queue_handle,
-            GlobalData,
+            PointerConstraintData::new(surface),
         ));
     }
 
@@ -347,6 +388,23 @@ impl WinitPointerData {
         self.inner.lock().unwrap().latest_button_serial
     }
 
+    /// Record whether the press of `button` was consumed by a hit test region.
+    pub fn set_hittest_button(&self, button: u32, consumed: bool) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.hittest_buttons.retain(|&pressed| pressed != button);
+        if consumed {
+            inner.hittest_buttons.push(button);
+        }
+    }
+
+    /// Whether the press of `button` was consumed by a hit test region, forgetting it.
+    pub fn take_hittest_button(&self, button: u32) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        let len = inner.hittest_buttons.len();
+        inner.hittest_buttons.retain(|&pressed| pressed != button);
+        inner.hittest_buttons.len() != len
+    }
+
     /// Last enter serial.
     pub fn latest_enter_serial(&self) -> u32 {
         self.sctk_data.latest_enter_serial().unwrap_or_default()
@@ -380,6 +438,9 @@ pub struct WinitPointerDataInner {
     /// The associated confined pointer.
     confined_pointer: Option<ZwpConfinedPointerV1>,
 
+    /// The associated cursor shape device.
+    cursor_shape_device: Option<WpCursorShapeDeviceV1>,
+
     /// Serial of the last button event.
     latest_button_serial: u32,
 
@@ -388,6 +449,9 @@ pub struct WinitPointerDataInner {
 
     /// Current axis phase.
     phase: TouchPhase,
+
+    /// Buttons whose press was consumed by a hit test region, so their release is dropped too.
+    hittest_buttons: Vec<u32>,
 }
 
 impl Drop for WinitPointerDataInner {
@@ -399,6 +463,10 @@ impl Drop for WinitPointerDataInner {
         if let Some(confined_pointer) = self.confined_pointer.take() {
             confined_pointer.destroy();
         }
+
+        if let Some(cursor_shape_device) = self.cursor_shape_device.take() {
+            cursor_shape_device.destroy();
+        }
     }
 }
 
@@ -408,8 +476,10 @@ impl Default for WinitPointerDataInner {
             surface: None,
             locked_pointer: None,
             confined_pointer: None,
+            cursor_shape_device: None,
             latest_button_serial: 0,
             phase: TouchPhase::Ended,
+            hittest_buttons: Vec::new(),
         }
     }
 }
@@ -424,6 +494,8 @@ fn wayland_button_to_winit(button: u32) -> MouseButton {
     const BTN_EXTRA: u32 = 0x114;
     const BTN_FORWARD: u32 = 0x115;
     const BTN_BACK: u32 = 0x116;
+    const BTN_TASK: u32 = 0x117;
+    const BTN_JOYSTICK: u32 = 0x120;
 
     match button {
         BTN_LEFT => MouseButton::Left,
@@ -431,6 +503,10 @@ fn wayland_button_to_winit(button: u32) -> MouseButton {
         BTN_MIDDLE => MouseButton::Middle,
         BTN_BACK | BTN_SIDE => MouseButton::Back,
         BTN_FORWARD | BTN_EXTRA => MouseButton::Forward,
+        // Number the extra buttons after `Forward`, so `BTN_TASK` is `Other(5)`.
+        button if (BTN_TASK..BTN_JOYSTICK).contains(&button) => {
+            MouseButton::Other((button - BTN_TASK + 5) as u16)
+        }
         button => MouseButton::Other(button as u16),
     }
 }
@@ -481,31 +557,63 @@ impl Dispatch<ZwpPointerConstraintsV1, GlobalData, WinitState> for PointerConstr
     }
 }
 
-impl Dispatch<ZwpLockedPointerV1, GlobalData, WinitState> for PointerConstraintsState {
+/// The window a pointer constraint was created for.
+#[derive(Debug)]
+pub struct PointerConstraintData {
+    window_id: WindowId,
+}
+
+impl PointerConstraintData {
+    fn new(surface: &WlSurface) -> Self {
+        Self {
+            window_id: wayland::make_wid(surface),
+        }
+    }
+}
+
+impl Dispatch<ZwpLockedPointerV1, PointerConstraintData, WinitState> for PointerConstraintsState {
     fn event(
-        _state: &mut WinitState,
+        state: &mut WinitState,
         _proxy: &ZwpLockedPointerV1,
-        _event: <ZwpLockedPointerV1 as wayland_client::Proxy>::Event,
-        _data: &GlobalData,
+        event: <ZwpLockedPointerV1 as wayland_client::Proxy>::Event,
+        data: &PointerConstraintData,
         _conn: &Connection,
         _qhandle: &QueueHandle<WinitState>,
     ) {
+        let grabbed = match event {
+            zwp_locked_pointer_v1::Event::Locked => true,
+            zwp_locked_pointer_v1::Event::Unlocked => false,
+            _ => return,
+        };
+
+        state
+            .events_sink
+            .push_window_event(WindowEvent::PointerGrabChanged { grabbed }, data.window_id);
     }
 }
 
-impl Dispatch<ZwpConfinedPointerV1, GlobalData, WinitState> for PointerConstraintsState {
+impl Dispatch<ZwpConfinedPointerV1, PointerConstraintData, WinitState> for PointerConstraintsState {
     fn event(
-        _state: &mut WinitState,
+        state: &mut WinitState,
         _proxy: &ZwpConfinedPointerV1,
-        _event: <ZwpConfinedPointerV1 as wayland_client::Proxy>::Event,
-        _data: &GlobalData,
+        event: <ZwpConfinedPointerV1 as wayland_client::Proxy>::Event,
+        data: &PointerConstraintData,
         _conn: &Connection,
         _qhandle: &QueueHandle<WinitState>,
     ) {
+        let grabbed = match event {
+            zwp_confined_pointer_v1::Event::Confined => true,
+            zwp_confined_pointer_v1::Event::Unconfined => false,
+            _ => return,
+        };
+
+        state
+            .events_sink
+            .push_window_event(WindowEvent::PointerGrabChanged { grabbed }, data.window_id);
     }
 }
 
 delegate_dispatch!(WinitState: [ WlPointer: WinitPointerData] => SeatState);
 delegate_dispatch!(WinitState: [ZwpPointerConstraintsV1: GlobalData] => PointerConstraintsState);
-delegate_dispatch!(WinitState: [ZwpLockedPointerV1: GlobalData] => PointerConstraintsState);
-delegate_dispatch!(WinitState: [ZwpConfinedPointerV1: GlobalData] => PointerConstraintsState);
+delegate_dispatch!(WinitState: [ZwpLockedPointerV1: PointerConstraintData] => PointerConstraintsState);
+delegate_dispatch!(WinitState: [ZwpConfinedPointerV1: PointerConstraintData] => PointerConstraintsState);
@@ -54,7 +54,7 @@ impl Dispatch<ZwpRelativePointerManagerV1, GlobalData, WinitState> for RelativeP
 impl Dispatch<ZwpRelativePointerV1, GlobalData, WinitState> for RelativePointerState {
     fn event(
         state: &mut WinitState,
-        _proxy: &ZwpRelativePointerV1,
+        proxy: &ZwpRelativePointerV1,
         event: <ZwpRelativePointerV1 as wayland_client::Proxy>::Event,
         _data: &GlobalData,
         _conn: &Connection,
@@ -66,11 +66,19 @@ impl Dispatch<ZwpRelativePointerV1, GlobalData, WinitState> for RelativePointerS
             ..
         } = event
         {
+            let seat_id = state.seats.iter().find_map(|(seat_id, seat_state)| {
+                (seat_state.relative_pointer.as_ref() == Some(proxy)).then_some(seat_id)
+            });
+            let device_id = match seat_id {
+                Some(seat_id) => super::DeviceId(seat_id.protocol_id()),
+                None => return,
+            };
+
             state.events_sink.push_device_event(
                 DeviceEvent::MouseMotion {
                     delta: (dx_unaccel, dy_unaccel),
                 },
-                super::DeviceId,
+                device_id,
             );
         }
     }
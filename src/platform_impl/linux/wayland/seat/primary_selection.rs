@@ -0,0 +1,275 @@
+//! Primary selection protocol, which backs the middle-click paste.
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::mem;
+use std::ops::Deref;
+use std::os::unix::io::AsRawFd;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use log::warn;
+
+use sctk::globals::GlobalData;
+use sctk::reexports::client::globals::{BindError, GlobalList};
+use sctk::reexports::client::{delegate_dispatch, event_created_child, Dispatch};
+use sctk::reexports::client::{Connection, Proxy, QueueHandle};
+use sctk::reexports::protocols::wp::primary_selection::zv1::client::{
+    zwp_primary_selection_device_manager_v1::ZwpPrimarySelectionDeviceManagerV1,
+    zwp_primary_selection_device_v1::{self, ZwpPrimarySelectionDeviceV1},
+    zwp_primary_selection_offer_v1::{self, ZwpPrimarySelectionOfferV1},
+    zwp_primary_selection_source_v1::{self, ZwpPrimarySelectionSourceV1},
+};
+
+use crate::platform_impl::wayland::state::WinitState;
+
+/// The mime types used for text, from the most to the least preferred.
+const TEXT_MIME_TYPES: [&str; 5] = [
+    "text/plain;charset=utf-8",
+    "UTF8_STRING",
+    "text/plain",
+    "TEXT",
+    "STRING",
+];
+
+/// Wrapper around the primary selection device manager.
+#[derive(Debug)]
+pub struct PrimarySelectionManager {
+    manager: ZwpPrimarySelectionDeviceManagerV1,
+}
+
+impl PrimarySelectionManager {
+    /// Create new primary selection device manager.
+    pub fn new(
+        globals: &GlobalList,
+        queue_handle: &QueueHandle<WinitState>,
+    ) -> Result<Self, BindError> {
+        let manager = globals.bind(queue_handle, 1..=1, GlobalData)?;
+        Ok(Self { manager })
+    }
+}
+
+impl Deref for PrimarySelectionManager {
+    type Target = ZwpPrimarySelectionDeviceManagerV1;
+
+    fn deref(&self) -> &Self::Target {
+        &self.manager
+    }
+}
+
+/// The primary selection of all the seats, shared between the event loop and the windows.
+#[derive(Debug, Default)]
+pub struct PrimarySelection {
+    /// The device of the seat with the latest input, and the serial of that input.
+    latest_input: Option<(ZwpPrimarySelectionDeviceV1, u32)>,
+
+    /// The offer for the current selection.
+    offer: Option<ZwpPrimarySelectionOfferV1>,
+
+    /// The source of the selection set by the application.
+    source: Option<ZwpPrimarySelectionSourceV1>,
+}
+
+impl PrimarySelection {
+    /// Remember the serial of an input on the seat, the selection can only be set with one.
+    pub fn input_received(&mut self, device: &ZwpPrimarySelectionDeviceV1, serial: u32) {
+        self.latest_input = Some((device.clone(), serial));
+    }
+
+    /// Forget the device of a seat that is gone.
+    pub fn device_removed(&mut self, device: &ZwpPrimarySelectionDeviceV1) {
+        if matches!(&self.latest_input, Some((latest, _)) if latest == device) {
+            self.latest_input = None;
+        }
+    }
+
+    /// Offer `text` as the selection, returning `false` if there was no input to set it with.
+    pub fn set_text(
+        &mut self,
+        manager: &ZwpPrimarySelectionDeviceManagerV1,
+        queue_handle: &QueueHandle<WinitState>,
+        text: &str,
+    ) -> bool {
+        let (device, serial) = match self.latest_input.as_ref() {
+            Some(latest_input) => latest_input,
+            None => return false,
+        };
+
+        let source = manager.create_source(
+            queue_handle,
+            PrimarySelectionSourceData {
+                text: Arc::from(text),
+            },
+        );
+        for mime_type in TEXT_MIME_TYPES {
+            source.offer(mime_type.into());
+        }
+        device.set_selection(Some(&source), *serial);
+
+        if let Some(source) = self.source.replace(source) {
+            source.destroy();
+        }
+
+        true
+    }
+
+    /// Read the text of the selection on another thread and pass it to `callback`.
+    ///
+    /// The `callback` gets `None` right away when there's no selection with text.
+    pub fn read_text<F>(&self, connection: &Connection, callback: F)
+    where
+        F: FnOnce(Option<String>) + Send + 'static,
+    {
+        let pipe = self.offer.as_ref().and_then(|offer| {
+            let mime_type = offer
+                .data::<PrimarySelectionOfferData>()?
+                .text_mime_type()?;
+            let (read, write) = rustix::pipe::pipe_with(rustix::pipe::PipeFlags::CLOEXEC)
+                .map_err(|err| warn!("Failed to create a pipe for the primary selection: {err}"))
+                .ok()?;
+
+            // The write end is duplicated when sending the request, so it can be closed here.
+            offer.receive(mime_type, write.as_raw_fd());
+            Some(read)
+        });
+
+        let read = match pipe {
+            Some(read) => read,
+            None => return callback(None),
+        };
+
+        // The source only starts writing once it got the request.
+        let _ = connection.flush();
+
+        thread::spawn(move || {
+            let mut text = Vec::new();
+            let text = match File::from(read).read_to_end(&mut text) {
+                Ok(_) => Some(String::from_utf8_lossy(&text).into_owned()),
+                Err(err) => {
+                    warn!("Failed to read the primary selection: {err}");
+                    None
+                }
+            };
+
+            callback(text);
+        });
+    }
+}
+
+/// The mime types the selection is offered in.
+#[derive(Debug, Default)]
+pub struct PrimarySelectionOfferData {
+    mime_types: Mutex<Vec<String>>,
+}
+
+impl PrimarySelectionOfferData {
+    /// The most preferred mime type for text the selection is offered in.
+    fn text_mime_type(&self) -> Option<String> {
+        let mime_types = self.mime_types.lock().unwrap();
+        TEXT_MIME_TYPES
+            .iter()
+            .find(|mime_type| mime_types.iter().any(|offered| offered == *mime_type))
+            .map(|mime_type| mime_type.to_string())
+    }
+}
+
+/// The text of the selection set by the application.
+#[derive(Debug)]
+pub struct PrimarySelectionSourceData {
+    text: Arc<str>,
+}
+
+impl Dispatch<ZwpPrimarySelectionDeviceManagerV1, GlobalData, WinitState>
+    for PrimarySelectionManager
+{
+    fn event(
+        _state: &mut WinitState,
+        _proxy: &ZwpPrimarySelectionDeviceManagerV1,
+        _event: <ZwpPrimarySelectionDeviceManagerV1 as Proxy>::Event,
+        _data: &GlobalData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpPrimarySelectionDeviceV1, GlobalData, WinitState> for PrimarySelectionManager {
+    event_created_child!(WinitState, ZwpPrimarySelectionDeviceV1, [
+        zwp_primary_selection_device_v1::EVT_DATA_OFFER_OPCODE => (
+            ZwpPrimarySelectionOfferV1,
+            PrimarySelectionOfferData::default()
+        )
+    ]);
+
+    fn event(
+        state: &mut WinitState,
+        _device: &ZwpPrimarySelectionDeviceV1,
+        event: <ZwpPrimarySelectionDeviceV1 as Proxy>::Event,
+        _data: &GlobalData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        // The offer is announced with `DataOffer` right before it becomes the selection.
+        if let zwp_primary_selection_device_v1::Event::Selection { id } = event {
+            let mut primary_selection = state.primary_selection.lock().unwrap();
+            if let Some(offer) = mem::replace(&mut primary_selection.offer, id) {
+                offer.destroy();
+            }
+        }
+    }
+}
+
+impl Dispatch<ZwpPrimarySelectionOfferV1, PrimarySelectionOfferData, WinitState>
+    for PrimarySelectionManager
+{
+    fn event(
+        _state: &mut WinitState,
+        _offer: &ZwpPrimarySelectionOfferV1,
+        event: <ZwpPrimarySelectionOfferV1 as Proxy>::Event,
+        data: &PrimarySelectionOfferData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        if let zwp_primary_selection_offer_v1::Event::Offer { mime_type } = event {
+            data.mime_types.lock().unwrap().push(mime_type);
+        }
+    }
+}
+
+impl Dispatch<ZwpPrimarySelectionSourceV1, PrimarySelectionSourceData, WinitState>
+    for PrimarySelectionManager
+{
+    fn event(
+        state: &mut WinitState,
+        source: &ZwpPrimarySelectionSourceV1,
+        event: <ZwpPrimarySelectionSourceV1 as Proxy>::Event,
+        data: &PrimarySelectionSourceData,
+        _conn: &Connection,
+        _qhandle: &QueueHandle<WinitState>,
+    ) {
+        match event {
+            zwp_primary_selection_source_v1::Event::Send { fd, .. } => {
+                // Don't block the event loop on a slow reader.
+                let text = data.text.clone();
+                thread::spawn(move || {
+                    if let Err(err) = File::from(fd).write_all(text.as_bytes()) {
+                        warn!("Failed to write the primary selection: {err}");
+                    }
+                });
+            }
+            zwp_primary_selection_source_v1::Event::Cancelled => {
+                let mut primary_selection = state.primary_selection.lock().unwrap();
+                if primary_selection.source.as_ref() == Some(source) {
+                    primary_selection.source = None;
+                }
+                source.destroy();
+            }
+            _ => (),
+        }
+    }
+}
+
+delegate_dispatch!(WinitState: [ZwpPrimarySelectionDeviceManagerV1: GlobalData] => PrimarySelectionManager);
+delegate_dispatch!(WinitState: [ZwpPrimarySelectionDeviceV1: GlobalData] => PrimarySelectionManager);
+delegate_dispatch!(WinitState: [ZwpPrimarySelectionOfferV1: PrimarySelectionOfferData] => PrimarySelectionManager);
+delegate_dispatch!(WinitState: [ZwpPrimarySelectionSourceV1: PrimarySelectionSourceData] => PrimarySelectionManager);
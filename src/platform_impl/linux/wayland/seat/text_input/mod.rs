@@ -76,6 +76,9 @@ impl Dispatch<ZwpTextInputV3, TextInputData, WinitState> for TextInputState {
                 if window.ime_allowed() {
                     text_input.enable();
                     text_input.set_content_type_by_purpose(window.ime_purpose());
+                    if let Some((text, cursor, anchor)) = window.ime_surrounding_text() {
+                        text_input.set_surrounding_text_clamped(text, cursor, anchor);
+                    }
                     text_input.commit();
                     state
                         .events_sink
@@ -137,10 +140,21 @@ impl Dispatch<ZwpTextInputV3, TextInputData, WinitState> for TextInputState {
 
                 // Clear preedit at the start of `Done`.
                 state.events_sink.push_window_event(
-                    WindowEvent::Ime(Ime::Preedit(String::new(), None)),
+                    WindowEvent::Ime(Ime::Preedit(String::new(), None, Vec::new())),
                     window_id,
                 );
 
+                // Delete the surrounding text before inserting the commit.
+                if let Some((before_length, after_length)) = text_input_data.pending_delete.take() {
+                    state.events_sink.push_window_event(
+                        WindowEvent::Ime(Ime::DeleteSurrounding {
+                            before_length,
+                            after_length,
+                        }),
+                        window_id,
+                    );
+                }
+
                 // Send `Commit`.
                 if let Some(text) = text_input_data.pending_commit.take() {
                     state
@@ -155,21 +169,31 @@ impl Dispatch<ZwpTextInputV3, TextInputData, WinitState> for TextInputState {
                         .map(|b| (b, preedit.cursor_end.unwrap_or(b)));
 
                     state.events_sink.push_window_event(
-                        WindowEvent::Ime(Ime::Preedit(preedit.text, cursor_range)),
+                        WindowEvent::Ime(Ime::Preedit(preedit.text, cursor_range, Vec::new())),
                         window_id,
                     );
                 }
             }
-            TextInputEvent::DeleteSurroundingText { .. } => {
-                // Not handled.
+            TextInputEvent::DeleteSurroundingText {
+                before_length,
+                after_length,
+            } => {
+                text_input_data.pending_delete =
+                    Some((before_length as usize, after_length as usize));
             }
             _ => {}
         }
     }
 }
 
+/// The maximum length of the surrounding text, in bytes, that can be sent in a single request.
+const MAX_SURROUNDING_TEXT_LENGTH: usize = 4000;
+
 pub trait ZwpTextInputV3Ext {
     fn set_content_type_by_purpose(&self, purpose: ImePurpose);
+
+    /// Set the surrounding text, cutting it down around the cursor if it's too long.
+    fn set_surrounding_text_clamped(&self, text: &str, cursor: usize, anchor: usize);
 }
 
 impl ZwpTextInputV3Ext for ZwpTextInputV3 {
@@ -181,6 +205,35 @@ impl ZwpTextInputV3Ext for ZwpTextInputV3 {
         };
         self.set_content_type(hint, purpose);
     }
+
+    fn set_surrounding_text_clamped(&self, text: &str, cursor: usize, anchor: usize) {
+        let (text, cursor, anchor) = clamp_surrounding_text(text, cursor, anchor);
+        self.set_surrounding_text(text.to_owned(), cursor as i32, anchor as i32);
+    }
+}
+
+/// Cut the surrounding text down to `MAX_SURROUNDING_TEXT_LENGTH` around the cursor, returning
+/// the kept text with the cursor and anchor relative to it.
+fn clamp_surrounding_text(text: &str, cursor: usize, anchor: usize) -> (&str, usize, usize) {
+    let (mut start, mut end) = (0, text.len());
+    if text.len() > MAX_SURROUNDING_TEXT_LENGTH {
+        // Keep the part of the text centered around the cursor.
+        let half = MAX_SURROUNDING_TEXT_LENGTH / 2;
+        start = cursor
+            .saturating_sub(half)
+            .min(text.len() - MAX_SURROUNDING_TEXT_LENGTH);
+        end = start + MAX_SURROUNDING_TEXT_LENGTH;
+        while !text.is_char_boundary(start) {
+            start += 1;
+        }
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+    }
+
+    let cursor = cursor.clamp(start, end) - start;
+    let anchor = anchor.clamp(start, end) - start;
+    (&text[start..end], cursor, anchor)
 }
 
 /// The Data associated with the text input.
@@ -197,6 +250,9 @@ pub struct TextInputDataInner {
     /// The commit to submit on `done`.
     pending_commit: Option<String>,
 
+    /// The length of the text to delete before and after the cursor on `done`.
+    pending_delete: Option<(usize, usize)>,
+
     /// The preedit to submit on `done`.
     pending_preedit: Option<Preedit>,
 }
@@ -210,3 +266,47 @@ struct Preedit {
 
 delegate_dispatch!(WinitState: [ZwpTextInputManagerV3: GlobalData] => TextInputState);
 delegate_dispatch!(WinitState: [ZwpTextInputV3: TextInputData] => TextInputState);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_surrounding_text_keeps_short_text() {
+        assert_eq!(clamp_surrounding_text("hello", 2, 4), ("hello", 2, 4));
+    }
+
+    #[test]
+    fn clamp_surrounding_text_centers_long_text_around_the_cursor() {
+        let text = "a".repeat(3 * MAX_SURROUNDING_TEXT_LENGTH);
+        let cursor = text.len() / 2;
+
+        let (clamped, new_cursor, anchor) = clamp_surrounding_text(&text, cursor, 0);
+        assert_eq!(clamped.len(), MAX_SURROUNDING_TEXT_LENGTH);
+        assert_eq!(new_cursor, MAX_SURROUNDING_TEXT_LENGTH / 2);
+        // The anchor is cut off, so it's moved to the start of the kept text.
+        assert_eq!(anchor, 0);
+    }
+
+    #[test]
+    fn clamp_surrounding_text_keeps_the_end_when_the_cursor_is_there() {
+        let text = "a".repeat(2 * MAX_SURROUNDING_TEXT_LENGTH);
+
+        let (clamped, cursor, anchor) = clamp_surrounding_text(&text, text.len(), text.len());
+        assert_eq!(clamped.len(), MAX_SURROUNDING_TEXT_LENGTH);
+        assert_eq!(cursor, MAX_SURROUNDING_TEXT_LENGTH);
+        assert_eq!(anchor, MAX_SURROUNDING_TEXT_LENGTH);
+    }
+
+    #[test]
+    fn clamp_surrounding_text_cuts_at_char_boundaries() {
+        // Three bytes per character, so the cut can't be at an exact multiple of the maximum.
+        let text = "\u{20ac}".repeat(MAX_SURROUNDING_TEXT_LENGTH);
+        let cursor = text.len() / 2 + 1;
+
+        let (clamped, cursor, _) = clamp_surrounding_text(&text, cursor, cursor);
+        assert!(clamped.len() <= MAX_SURROUNDING_TEXT_LENGTH);
+        assert!(clamped.chars().all(|c| c == '\u{20ac}'));
+        assert!(cursor <= clamped.len());
+    }
+}
@@ -7,7 +7,7 @@ use sctk::reexports::client::{Connection, Proxy, QueueHandle};
 
 use sctk::seat::touch::{TouchData, TouchHandler};
 
-use crate::dpi::{LogicalPosition, PhysicalPosition};
+use crate::dpi::LogicalPosition;
 use crate::event::{ElementState, PointerId, WindowEvent};
 
 use crate::platform_impl::wayland::state::WinitState;
@@ -43,7 +43,7 @@ impl TouchHandler for WinitState {
         self.events_sink.push_window_event(
             WindowEvent::PointerButton {
                 device_id: crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
-                    DeviceId,
+                    DeviceId::from_seat(touch.seat()),
                 )),
                 source: PointerId::Touch { finger: id as u64 },
                 force: None,
@@ -81,7 +81,7 @@ impl TouchHandler for WinitState {
         self.events_sink.push_window_event(
             WindowEvent::PointerButton {
                 device_id: crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
-                    DeviceId,
+                    DeviceId::from_seat(touch.seat()),
                 )),
                 source: PointerId::Touch { finger: id as u64 },
                 state: ElementState::Released,
@@ -121,7 +121,7 @@ impl TouchHandler for WinitState {
         self.events_sink.push_window_event(
             WindowEvent::PointerMoved {
                 device_id: crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
-                    DeviceId,
+                    DeviceId::from_seat(touch.seat()),
                 )),
                 source: PointerId::Touch { finger: id as u64 },
                 location: touch_point.location.to_physical(scale_factor),
@@ -136,17 +136,14 @@ impl TouchHandler for WinitState {
 
         for (id, touch_point) in seat_state.touch_map.drain() {
             let window_id = wayland::make_wid(&touch_point.surface);
-            let scale_factor = match self.windows.get_mut().get(&window_id) {
-                Some(window) => window.lock().unwrap().scale_factor(),
-                None => return,
-            };
-
-            let location: PhysicalPosition<f64> = touch_point.location.to_physical(scale_factor);
+            if !self.windows.get_mut().contains_key(&window_id) {
+                return;
+            }
 
             self.events_sink.push_window_event(
                 WindowEvent::PointerCancelled {
                     device_id: crate::event::DeviceId(crate::platform_impl::DeviceId::Wayland(
-                        DeviceId,
+                        DeviceId::from_seat(touch.seat()),
                     )),
                     source: PointerId::Touch { finger: id as u64 },
                 },
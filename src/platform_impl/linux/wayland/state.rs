@@ -23,14 +23,18 @@ use sctk::shm::{Shm, ShmHandler};
 use sctk::subcompositor::SubcompositorState;
 
 use crate::dpi::LogicalSize;
+use crate::event::{MonitorEvent, WindowEvent};
+use crate::keyboard::LockKeyState;
 
 use super::event_loop::sink::EventSink;
 use super::output::MonitorHandle;
 use super::seat::{
-    PointerConstraintsState, RelativePointerState, TextInputState, WinitPointerData,
-    WinitPointerDataExt, WinitSeatState,
+    Clipboard, CursorCache, CursorShapeManager, DataDeviceManager, PointerConstraintsState,
+    PrimarySelection, PrimarySelectionManager, RelativePointerState, TextInputState,
+    WinitPointerData, WinitPointerDataExt, WinitSeatState,
 };
 use super::types::wp_fractional_scaling::FractionalScalingManager;
+use super::types::wp_single_pixel_buffer::SinglePixelBufferManager;
 use super::types::wp_viewporter::ViewporterState;
 use super::types::xdg_activation::XdgActivationState;
 use super::window::{WindowRequests, WindowState};
@@ -80,9 +84,24 @@ pub struct WinitState {
     /// The state of the text input on the client.
     pub text_input_state: Option<TextInputState>,
 
+    /// The primary selection device manager.
+    pub primary_selection_manager: Option<PrimarySelectionManager>,
+
+    /// The primary selection, shared with the windows.
+    pub primary_selection: Arc<Mutex<PrimarySelection>>,
+
+    /// The data device manager for the clipboard.
+    pub data_device_manager: Option<DataDeviceManager>,
+
+    /// The clipboard, shared with the windows.
+    pub clipboard: Arc<Mutex<Clipboard>>,
+
     /// Observed monitors.
     pub monitors: Arc<Mutex<Vec<MonitorHandle>>>,
 
+    /// The state of the lock keys, as last reported for the focused window.
+    pub lock_keys: Arc<Mutex<LockKeyState>>,
+
     /// Sink to accumulate window events from the compositor, which is latter dispatched in
     /// event loop run.
     pub events_sink: EventSink,
@@ -93,6 +112,12 @@ pub struct WinitState {
     /// Relative pointer.
     pub relative_pointer: Option<RelativePointerState>,
 
+    /// Cursor shape manager to let the compositor draw standard cursors.
+    pub cursor_shape_manager: Option<CursorShapeManager>,
+
+    /// The cursor images loaded when the compositor can't draw the cursor itself.
+    pub cursor_cache: Arc<Mutex<CursorCache>>,
+
     /// Pointer constraints to handle pointer locking and confining.
     pub pointer_constraints: Option<Arc<PointerConstraintsState>>,
 
@@ -102,6 +127,12 @@ pub struct WinitState {
     /// Fractional scaling manager.
     pub fractional_scaling_manager: Option<FractionalScalingManager>,
 
+    /// Single pixel buffer manager, only bound when the buffers can be scaled with a viewport.
+    pub single_pixel_buffer_manager: Option<SinglePixelBufferManager>,
+
+    /// The `app_id` for windows that don't set one themselves.
+    pub app_id: Option<String>,
+
     /// Loop handle to re-register event sources, such as keyboard repeat.
     pub loop_handle: LoopHandle<'static, Self>,
 }
@@ -152,20 +183,31 @@ impl WinitState {
             window_requests: Default::default(),
             window_compositor_updates: Vec::new(),
             window_events_sink: Default::default(),
+            single_pixel_buffer_manager: viewporter_state
+                .as_ref()
+                .and_then(|_| SinglePixelBufferManager::new(globals, queue_handle).ok()),
             viewporter_state,
             fractional_scaling_manager,
 
             seats,
             text_input_state: TextInputState::new(globals, queue_handle).ok(),
+            primary_selection_manager: PrimarySelectionManager::new(globals, queue_handle).ok(),
+            primary_selection: Default::default(),
+            data_device_manager: DataDeviceManager::new(globals, queue_handle).ok(),
+            clipboard: Default::default(),
 
             relative_pointer: RelativePointerState::new(globals, queue_handle).ok(),
+            cursor_shape_manager: CursorShapeManager::new(globals, queue_handle).ok(),
+            cursor_cache: Arc::new(Mutex::new(CursorCache::new())),
             pointer_constraints: PointerConstraintsState::new(globals, queue_handle)
                 .map(Arc::new)
                 .ok(),
             pointer_surfaces: Default::default(),
 
             monitors: Arc::new(Mutex::new(monitors)),
+            lock_keys: Default::default(),
             events_sink: EventSink::new(),
+            app_id: None,
             loop_handle,
         })
     }
@@ -266,14 +308,21 @@ impl WindowHandler for WinitState {
         // Populate the configure to the window.
         //
         // XXX the size on the window will be updated right before dispatching the size to the user.
-        let new_size = self
+        let mut window_state = self
             .windows
             .get_mut()
             .get_mut(&window_id)
             .expect("got configure for dead window.")
             .lock()
-            .unwrap()
-            .configure(configure, &self.shm, &self.subcompositor_state);
+            .unwrap();
+
+        if window_state.set_is_active(configure.is_activated()) {
+            self.events_sink
+                .push_window_event(WindowEvent::Activated(configure.is_activated()), window_id);
+        }
+
+        let new_size = window_state.configure(configure, &self.shm, &self.subcompositor_state);
+        drop(window_state);
 
         self.window_compositor_updates[pos].size = Some(new_size);
     }
@@ -285,10 +334,10 @@ impl OutputHandler for WinitState {
     }
 
     fn new_output(&mut self, _: &Connection, _: &QueueHandle<Self>, output: WlOutput) {
-        self.monitors
-            .lock()
-            .unwrap()
-            .push(MonitorHandle::new(output));
+        let monitor = MonitorHandle::new(output);
+        self.monitors.lock().unwrap().push(monitor.clone());
+        self.events_sink
+            .push_monitor_event(MonitorEvent::Connected, monitor);
     }
 
     fn update_output(&mut self, _: &Connection, _: &QueueHandle<Self>, updated: WlOutput) {
@@ -306,6 +355,8 @@ impl OutputHandler for WinitState {
         let removed = MonitorHandle::new(removed);
         if let Some(pos) = monitors.iter().position(|output| output == &removed) {
             monitors.remove(pos);
+            self.events_sink
+                .push_monitor_event(MonitorEvent::Disconnected, removed);
         }
     }
 }
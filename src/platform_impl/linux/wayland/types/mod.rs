@@ -1,5 +1,6 @@
 //! Wayland protocol implementation boilerplate.
 
 pub mod wp_fractional_scaling;
+pub mod wp_single_pixel_buffer;
 pub mod wp_viewporter;
 pub mod xdg_activation;
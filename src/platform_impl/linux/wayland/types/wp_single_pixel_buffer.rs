@@ -0,0 +1,83 @@
+//! Handling of the wp-single-pixel-buffer.
+
+use sctk::reexports::client::globals::{BindError, GlobalList};
+use sctk::reexports::client::protocol::wl_buffer::{self, WlBuffer};
+use sctk::reexports::client::Dispatch;
+use sctk::reexports::client::{delegate_dispatch, Connection, Proxy, QueueHandle};
+use sctk::reexports::protocols::wp::single_pixel_buffer::v1::client::wp_single_pixel_buffer_manager_v1::WpSinglePixelBufferManagerV1;
+
+use sctk::globals::GlobalData;
+
+use crate::platform_impl::wayland::state::WinitState;
+
+/// Single pixel buffer manager.
+#[derive(Debug)]
+pub struct SinglePixelBufferManager {
+    manager: WpSinglePixelBufferManagerV1,
+}
+
+impl SinglePixelBufferManager {
+    /// Create new single pixel buffer manager.
+    pub fn new(
+        globals: &GlobalList,
+        queue_handle: &QueueHandle<WinitState>,
+    ) -> Result<Self, BindError> {
+        let manager = globals.bind(queue_handle, 1..=1, GlobalData)?;
+        Ok(Self { manager })
+    }
+
+    pub fn manager(&self) -> &WpSinglePixelBufferManagerV1 {
+        &self.manager
+    }
+}
+
+/// Create a buffer of a single pixel with the given premultiplied RGBA color.
+///
+/// The buffer is destroyed once the compositor releases it.
+pub fn create_buffer(
+    manager: &WpSinglePixelBufferManagerV1,
+    [r, g, b, a]: [u8; 4],
+    queue_handle: &QueueHandle<WinitState>,
+) -> WlBuffer {
+    // The channels are given in the full `u32` range.
+    let expand = |channel: u8| channel as u32 * 0x0101_0101;
+    manager.create_u32_rgba_buffer(
+        expand(r),
+        expand(g),
+        expand(b),
+        expand(a),
+        queue_handle,
+        GlobalData,
+    )
+}
+
+impl Dispatch<WpSinglePixelBufferManagerV1, GlobalData, WinitState> for SinglePixelBufferManager {
+    fn event(
+        _: &mut WinitState,
+        _: &WpSinglePixelBufferManagerV1,
+        _: <WpSinglePixelBufferManagerV1 as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<WinitState>,
+    ) {
+        // No events.
+    }
+}
+
+impl Dispatch<WlBuffer, GlobalData, WinitState> for SinglePixelBufferManager {
+    fn event(
+        _: &mut WinitState,
+        buffer: &WlBuffer,
+        event: <WlBuffer as Proxy>::Event,
+        _: &GlobalData,
+        _: &Connection,
+        _: &QueueHandle<WinitState>,
+    ) {
+        if let wl_buffer::Event::Release = event {
+            buffer.destroy();
+        }
+    }
+}
+
+delegate_dispatch!(WinitState: [WpSinglePixelBufferManagerV1: GlobalData] => SinglePixelBufferManager);
+delegate_dispatch!(WinitState: [WlBuffer: GlobalData] => SinglePixelBufferManager);
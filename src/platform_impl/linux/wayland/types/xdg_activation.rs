@@ -1,4 +1,4 @@
-//! Handling of xdg activation, which is used for user attention requests.
+//! Handling of xdg activation, which is used for user attention and focus requests.
 
 use std::sync::atomic::AtomicBool;
 use std::sync::Weak;
@@ -79,6 +79,9 @@ impl Dispatch<XdgActivationTokenV1, XdgActivationTokenData, WinitState> for XdgA
                     attention_requested.store(false, std::sync::atomic::Ordering::Relaxed);
                 }
             }
+            XdgActivationTokenData::Focus(surface) => {
+                global.activate(token, surface);
+            }
             XdgActivationTokenData::Obtain((window_id, serial)) => {
                 state.events_sink.push_window_event(
                     crate::event::WindowEvent::ActivationTokenDone {
@@ -98,6 +101,8 @@ impl Dispatch<XdgActivationTokenV1, XdgActivationTokenData, WinitState> for XdgA
 pub enum XdgActivationTokenData {
     /// Request user attention for the given surface.
     Attention((WlSurface, Weak<AtomicBool>)),
+    /// Focus the given surface.
+    Focus(WlSurface),
     /// Get a token to be passed outside of the winit.
     Obtain((WindowId, AsyncRequestSerial)),
 }
@@ -8,32 +8,37 @@ use raw_window_handle::{
 };
 
 use sctk::reexports::calloop;
+use sctk::reexports::client::backend::WaylandError;
+use sctk::reexports::client::protocol::wl_data_device_manager::WlDataDeviceManager;
 use sctk::reexports::client::protocol::wl_display::WlDisplay;
 use sctk::reexports::client::protocol::wl_surface::WlSurface;
-use sctk::reexports::client::Proxy;
 use sctk::reexports::client::QueueHandle;
+use sctk::reexports::client::{Connection, Proxy};
 
-use sctk::compositor::{CompositorState, Region, SurfaceData};
+use sctk::compositor::SurfaceData;
+use sctk::reexports::protocols::wp::primary_selection::zv1::client::zwp_primary_selection_device_manager_v1::ZwpPrimarySelectionDeviceManagerV1;
 use sctk::reexports::protocols::xdg::activation::v1::client::xdg_activation_v1::XdgActivationV1;
 use sctk::shell::xdg::window::Window as SctkWindow;
 use sctk::shell::xdg::window::WindowDecorations;
 use sctk::shell::WaylandSurface;
 
-use crate::dpi::{LogicalSize, PhysicalPosition, PhysicalSize, Position, Size};
+use crate::dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize, Position, Size};
 use crate::error::{ExternalError, NotSupportedError, OsError as RootOsError};
 use crate::event::{Ime, WindowEvent};
 use crate::event_loop::AsyncRequestSerial;
+use crate::keyboard::LockKeyState;
 use crate::platform_impl::{
     Fullscreen, MonitorHandle as PlatformMonitorHandle, OsError,
     PlatformSpecificWindowBuilderAttributes as PlatformAttributes,
 };
 use crate::window::{
-    CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, UserAttentionType,
-    WindowAttributes, WindowButtons,
+    CursorGrabMode, CursorIcon, DragAction, HitTestRegion, ImePurpose, Rect, ResizeDirection,
+    Theme, UserAttentionType, WindowAttributes, WindowButtons,
 };
 
 use super::event_loop::sink::EventSink;
 use super::output::MonitorHandle;
+use super::seat::{Clipboard, PrimarySelection};
 use super::state::WinitState;
 use super::types::xdg_activation::XdgActivationTokenData;
 use super::{EventLoopWindowTarget, WindowId};
@@ -53,18 +58,30 @@ pub struct Window {
     /// The state of the window.
     window_state: Arc<Mutex<WindowState>>,
 
-    /// Compositor to handle WlRegion stuff.
-    compositor: Arc<CompositorState>,
-
     /// The wayland display used solely for raw window handle.
     display: WlDisplay,
 
+    /// The connection to flush the requests of the window on demand.
+    connection: Connection,
+
     /// Xdg activation to request user attention.
     xdg_activation: Option<XdgActivationV1>,
 
     /// The state of the requested attention from the `xdg_activation`.
     attention_requested: Arc<AtomicBool>,
 
+    /// The primary selection device manager to set the selection with.
+    primary_selection_manager: Option<ZwpPrimarySelectionDeviceManagerV1>,
+
+    /// The primary selection shared with the event loop.
+    primary_selection: Arc<Mutex<PrimarySelection>>,
+
+    /// The data device manager to set the clipboard with.
+    data_device_manager: Option<WlDataDeviceManager>,
+
+    /// The clipboard shared with the event loop.
+    clipboard: Arc<Mutex<Clipboard>>,
+
     /// Handle to the main queue to perform requests.
     queue_handle: QueueHandle<WinitState>,
 
@@ -74,6 +91,9 @@ pub struct Window {
     /// Observed monitors.
     monitors: Arc<Mutex<Vec<MonitorHandle>>>,
 
+    /// The state of the lock keys.
+    lock_keys: Arc<Mutex<LockKeyState>>,
+
     /// Source to wake-up the event-loop for window requests.
     event_loop_awakener: calloop::ping::Ping,
 
@@ -91,13 +111,23 @@ impl Window {
         let mut state = event_loop_window_target.state.borrow_mut();
 
         let monitors = state.monitors.clone();
+        let lock_keys = state.lock_keys.clone();
 
         let surface = state.compositor_state.create_surface(&queue_handle);
-        let compositor = state.compositor_state.clone();
         let xdg_activation = state
             .xdg_activation
             .as_ref()
             .map(|activation_state| activation_state.global().clone());
+        let primary_selection_manager = state
+            .primary_selection_manager
+            .as_ref()
+            .map(|manager| (**manager).clone());
+        let primary_selection = state.primary_selection.clone();
+        let data_device_manager = state
+            .data_device_manager
+            .as_ref()
+            .map(|manager| (**manager).clone());
+        let clipboard = state.clipboard.clone();
         let display = event_loop_window_target.connection.display();
 
         // XXX The initial scale factor must be 1, but it might cause sizing issues on HiDPI.
@@ -135,8 +165,12 @@ impl Window {
         window_state.set_decorate(attributes.decorations);
 
         // Set the app_id.
-        if let Some(name) = platform_attributes.name.map(|name| name.general) {
-            window.set_app_id(name);
+        if let Some(app_id) = platform_attributes
+            .name
+            .map(|name| name.general)
+            .or_else(|| state.app_id.clone())
+        {
+            window.set_app_id(app_id);
         }
 
         // Set the window title.
@@ -227,13 +261,18 @@ impl Window {
         Ok(Self {
             window,
             display,
+            connection: event_loop_window_target.connection.clone(),
             monitors,
+            lock_keys,
             window_id,
-            compositor,
             window_state,
             queue_handle,
             xdg_activation,
             attention_requested: Arc::new(AtomicBool::new(false)),
+            primary_selection_manager,
+            primary_selection,
+            data_device_manager,
+            clipboard,
             event_loop_awakener,
             window_requests,
             window_events_sink,
@@ -258,6 +297,36 @@ impl Window {
         // Not possible on Wayland.
     }
 
+    #[inline]
+    pub fn fill_color(&self, rgba: [u8; 4]) -> Result<(), ExternalError> {
+        self.window_state.lock().unwrap().fill_color(rgba)?;
+        // Wake up the event loop to flush the commit.
+        self.event_loop_awakener.ping();
+        Ok(())
+    }
+
+    #[inline]
+    pub fn set_viewport_source(
+        &self,
+        source: Option<(LogicalPosition<f64>, LogicalSize<f64>)>,
+    ) -> Result<(), ExternalError> {
+        self.window_state
+            .lock()
+            .unwrap()
+            .set_viewport_source(source)?;
+        self.request_redraw();
+        Ok(())
+    }
+
+    #[inline]
+    pub fn set_buffer_scale(&self, buffer_scale: Option<u32>) {
+        self.window_state
+            .lock()
+            .unwrap()
+            .set_buffer_scale(buffer_scale);
+        self.request_redraw();
+    }
+
     #[inline]
     pub fn is_visible(&self) -> Option<bool> {
         None
@@ -298,6 +367,28 @@ impl Window {
         self.window_state.lock().unwrap().request_frame_callback();
     }
 
+    #[inline]
+    pub fn pre_present_notify_with_damage(
+        &self,
+        damage: &[(PhysicalPosition<i32>, PhysicalSize<u32>)],
+    ) {
+        let mut window_state = self.window_state.lock().unwrap();
+        window_state.add_damage(damage);
+        window_state.request_frame_callback();
+    }
+
+    #[inline]
+    pub fn flush(&self) -> Result<(), ExternalError> {
+        match self.connection.flush() {
+            // The event loop sends the rest once the socket has room again.
+            Err(WaylandError::Io(err)) if err.kind() == std::io::ErrorKind::WouldBlock => Ok(()),
+            Err(_) => Err(ExternalError::Os(os_error!(OsError::WaylandMisc(
+                "failed to flush the connection."
+            )))),
+            Ok(()) => Ok(()),
+        }
+    }
+
     #[inline]
     pub fn outer_size(&self) -> PhysicalSize<u32> {
         let window_state = self.window_state.lock().unwrap();
@@ -356,11 +447,26 @@ impl Window {
             .set_transparent(transparent);
     }
 
+    #[inline]
+    pub fn set_opaque_region(&self, region: Option<&[Rect]>) {
+        self.window_state.lock().unwrap().set_opaque_region(region);
+    }
+
     #[inline]
     pub fn has_focus(&self) -> bool {
         self.window_state.lock().unwrap().has_focus()
     }
 
+    #[inline]
+    pub fn lock_key_state(&self) -> LockKeyState {
+        *self.lock_keys.lock().unwrap()
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.window_state.lock().unwrap().is_active()
+    }
+
     #[inline]
     pub fn is_minimized(&self) -> Option<bool> {
         // XXX clients don't know whether they are minimized or not.
@@ -375,6 +481,14 @@ impl Window {
             .drag_resize_window(direction)
     }
 
+    #[inline]
+    pub fn set_hittest_regions(&self, regions: &[HitTestRegion]) {
+        self.window_state
+            .lock()
+            .unwrap()
+            .set_hittest_regions(regions);
+    }
+
     #[inline]
     pub fn set_resizable(&self, resizable: bool) {
         self.window_state.lock().unwrap().set_resizable(resizable);
@@ -433,6 +547,13 @@ impl Window {
             .unwrap_or_default()
     }
 
+    #[inline]
+    pub fn restored_inner_size(&self) -> Option<PhysicalSize<u32>> {
+        let window_state = self.window_state.lock().unwrap();
+        let scale_factor = window_state.scale_factor();
+        Some(window_state.restored_size().to_physical(scale_factor))
+    }
+
     #[inline]
     pub fn set_maximized(&self, maximized: bool) {
         if maximized {
@@ -485,6 +606,11 @@ impl Window {
         self.window_state.lock().unwrap().set_cursor(cursor);
     }
 
+    #[inline]
+    pub fn preload_cursors(&self, cursors: &[CursorIcon]) {
+        self.window_state.lock().unwrap().preload_cursors(cursors);
+    }
+
     #[inline]
     pub fn set_cursor_visible(&self, visible: bool) {
         self.window_state
@@ -519,6 +645,26 @@ impl Window {
         xdg_activation_token.commit();
     }
 
+    #[inline]
+    pub fn focus_window(&self) {
+        let xdg_activation = match self.xdg_activation.as_ref() {
+            Some(xdg_activation) => xdg_activation,
+            None => {
+                warn!("`focus_window` isn't supported");
+                return;
+            }
+        };
+
+        // Request a token for our own surface and activate the window with it. Depending on the
+        // focus stealing prevention of the compositor, this may only mark the window as urgent.
+        let surface = self.surface().clone();
+        let data = XdgActivationTokenData::Focus(surface.clone());
+        let xdg_activation_token = xdg_activation.get_activation_token(&self.queue_handle, data);
+        xdg_activation_token.set_surface(&surface);
+        xdg_activation_token.commit();
+    }
+
+    #[inline]
     pub fn request_activation_token(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
         let xdg_activation = match self.xdg_activation.as_ref() {
             Some(xdg_activation) => xdg_activation,
@@ -535,6 +681,134 @@ impl Window {
         Ok(serial)
     }
 
+    #[inline]
+    pub fn set_primary_selection_text(&self, text: &str) {
+        let primary_selection_manager = match self.primary_selection_manager.as_ref() {
+            Some(primary_selection_manager) => primary_selection_manager,
+            None => {
+                warn!("`set_primary_selection_text` isn't supported");
+                return;
+            }
+        };
+
+        let mut primary_selection = self.primary_selection.lock().unwrap();
+        if primary_selection.set_text(primary_selection_manager, &self.queue_handle, text) {
+            // Flush the request.
+            self.event_loop_awakener.ping();
+        } else {
+            warn!("The primary selection can't be set before any input was received");
+        }
+    }
+
+    #[inline]
+    pub fn request_primary_selection_text(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        if self.primary_selection_manager.is_none() {
+            return Err(NotSupportedError::new());
+        }
+
+        let serial = AsyncRequestSerial::get();
+
+        let window_id = self.window_id;
+        let window_events_sink = self.window_events_sink.clone();
+        let event_loop_awakener = self.event_loop_awakener.clone();
+        self.primary_selection
+            .lock()
+            .unwrap()
+            .read_text(&self.connection, move |text| {
+                window_events_sink.lock().unwrap().push_window_event(
+                    WindowEvent::PrimarySelectionText { serial, text },
+                    window_id,
+                );
+                event_loop_awakener.ping();
+            });
+
+        Ok(serial)
+    }
+
+    #[inline]
+    pub fn set_clipboard_data(&self, data: Vec<(String, Vec<u8>)>) {
+        let data_device_manager = match self.data_device_manager.as_ref() {
+            Some(data_device_manager) => data_device_manager,
+            None => {
+                warn!("`set_clipboard_data` isn't supported");
+                return;
+            }
+        };
+
+        let mut clipboard = self.clipboard.lock().unwrap();
+        if clipboard.set_data(data_device_manager, &self.queue_handle, data) {
+            // Flush the request.
+            self.event_loop_awakener.ping();
+        } else {
+            warn!("The clipboard can't be set before any input was received");
+        }
+    }
+
+    #[inline]
+    pub fn request_clipboard_data(
+        &self,
+        mime_type: &str,
+    ) -> Result<AsyncRequestSerial, NotSupportedError> {
+        if self.data_device_manager.is_none() {
+            return Err(NotSupportedError::new());
+        }
+
+        let serial = AsyncRequestSerial::get();
+
+        let window_id = self.window_id;
+        let window_events_sink = self.window_events_sink.clone();
+        let event_loop_awakener = self.event_loop_awakener.clone();
+        self.clipboard
+            .lock()
+            .unwrap()
+            .read_data(&self.connection, mime_type, move |data| {
+                window_events_sink
+                    .lock()
+                    .unwrap()
+                    .push_window_event(WindowEvent::ClipboardData { serial, data }, window_id);
+                event_loop_awakener.ping();
+            });
+
+        Ok(serial)
+    }
+
+    #[inline]
+    pub fn request_clipboard_mime_types(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        if self.data_device_manager.is_none() {
+            return Err(NotSupportedError::new());
+        }
+
+        let serial = AsyncRequestSerial::get();
+
+        // The mime types are announced with the offer, so they're known right away.
+        let mime_types = self.clipboard.lock().unwrap().mime_types();
+        self.window_events_sink.lock().unwrap().push_window_event(
+            WindowEvent::ClipboardMimeTypes { serial, mime_types },
+            self.window_id,
+        );
+        self.event_loop_awakener.ping();
+
+        Ok(serial)
+    }
+
+    #[inline]
+    pub fn set_drag_accept(&self, mime_type: Option<&str>) {
+        let mut clipboard = self.clipboard.lock().unwrap();
+        if clipboard.set_drag_accept(self.window_id, mime_type) {
+            // Flush the request.
+            self.event_loop_awakener.ping();
+        }
+    }
+
+    #[inline]
+    pub fn set_drag_action(&self, action: DragAction) {
+        let mut clipboard = self.clipboard.lock().unwrap();
+        if clipboard.set_drag_action(self.window_id, action) {
+            // Flush the request.
+            self.event_loop_awakener.ping();
+        }
+    }
+
     #[inline]
     pub fn set_cursor_grab(&self, mode: CursorGrabMode) -> Result<(), ExternalError> {
         self.window_state.lock().unwrap().set_cursor_grab(mode)
@@ -559,21 +833,13 @@ impl Window {
 
     #[inline]
     pub fn set_cursor_hittest(&self, hittest: bool) -> Result<(), ExternalError> {
-        let surface = self.window.wl_surface();
+        let region: Option<&[Rect]> = if hittest { None } else { Some(&[]) };
+        self.set_input_region(region)
+    }
 
-        if hittest {
-            surface.set_input_region(None);
-            Ok(())
-        } else {
-            let region = Region::new(&*self.compositor).map_err(|_| {
-                ExternalError::Os(os_error!(OsError::WaylandMisc(
-                    "failed to set input region."
-                )))
-            })?;
-            region.add(0, 0, 0, 0);
-            surface.set_input_region(Some(region.wl_region()));
-            Ok(())
-        }
+    #[inline]
+    pub fn set_input_region(&self, region: Option<&[Rect]>) -> Result<(), ExternalError> {
+        self.window_state.lock().unwrap().set_input_region(region)
     }
 
     #[inline]
@@ -587,6 +853,11 @@ impl Window {
         }
     }
 
+    #[inline]
+    pub fn set_key_repeat(&self, key_repeat: bool) {
+        self.window_state.lock().unwrap().set_key_repeat(key_repeat);
+    }
+
     #[inline]
     pub fn set_ime_allowed(&self, allowed: bool) {
         let mut window_state = self.window_state.lock().unwrap();
@@ -606,6 +877,14 @@ impl Window {
         self.window_state.lock().unwrap().set_ime_purpose(purpose);
     }
 
+    #[inline]
+    pub fn set_ime_surrounding_text(&self, text: &str, cursor: usize, anchor: usize) {
+        self.window_state
+            .lock()
+            .unwrap()
+            .set_ime_surrounding_text(text, cursor, anchor);
+    }
+
     #[inline]
     pub fn display(&self) -> &WlDisplay {
         &self.display
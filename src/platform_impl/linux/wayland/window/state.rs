@@ -2,7 +2,8 @@
 
 use std::mem::ManuallyDrop;
 use std::num::NonZeroU32;
-use std::sync::{Arc, Weak};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
 
 use log::warn;
 
@@ -12,6 +13,7 @@ use sctk::reexports::client::protocol::wl_surface::WlSurface;
 use sctk::reexports::client::{Connection, Proxy, QueueHandle};
 use sctk::reexports::protocols::wp::fractional_scale::v1::client::wp_fractional_scale_v1::WpFractionalScaleV1;
 use sctk::reexports::protocols::wp::text_input::zv3::client::zwp_text_input_v3::ZwpTextInputV3;
+use sctk::reexports::protocols::wp::single_pixel_buffer::v1::client::wp_single_pixel_buffer_manager_v1::WpSinglePixelBufferManagerV1;
 use sctk::reexports::protocols::wp::viewporter::client::wp_viewport::WpViewport;
 use sctk::reexports::protocols::xdg::shell::client::xdg_toplevel::ResizeEdge;
 
@@ -24,15 +26,22 @@ use sctk::shell::WaylandSurface;
 use sctk::shm::Shm;
 use sctk::subcompositor::SubcompositorState;
 
-use crate::dpi::{LogicalPosition, LogicalSize};
+use crate::dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize};
 use crate::error::{ExternalError, NotSupportedError};
+use crate::event::MouseButton;
 use crate::platform_impl::WindowId;
-use crate::window::{CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme};
+use crate::window::{
+    CursorGrabMode, CursorIcon, HitTestKind, HitTestRegion, ImePurpose, Rect, ResizeDirection,
+    Theme,
+};
 
+use crate::platform_impl::wayland::output::MonitorHandle;
 use crate::platform_impl::wayland::seat::{
-    PointerConstraintsState, WinitPointerData, WinitPointerDataExt, ZwpTextInputV3Ext,
+    cursor_icon_to_shape, CursorCache, PointerConstraintsState, WinitPointerData,
+    WinitPointerDataExt, ZwpTextInputV3Ext,
 };
 use crate::platform_impl::wayland::state::{WindowCompositorUpdate, WinitState};
+use crate::platform_impl::wayland::types::wp_single_pixel_buffer;
 
 #[cfg(feature = "sctk-adwaita")]
 pub type WinitFrame = sctk_adwaita::AdwaitaFrame<WinitState>;
@@ -42,6 +51,9 @@ pub type WinitFrame = sctk::shell::xdg::frame::fallback_frame::FallbackFrame<Win
 // Minimum window inner size.
 const MIN_WINDOW_SIZE: LogicalSize<u32> = LogicalSize::new(2, 1);
 
+/// The longest time between two clicks on the title bar to count as a double click, in ms.
+const DOUBLE_CLICK_TIME: u32 = 400;
+
 /// The state of the window which is being updated from the [`WinitState`].
 pub struct WindowState {
     /// The connection to Wayland server.
@@ -56,6 +68,12 @@ pub struct WindowState {
     /// The `Shm` to set cursor.
     pub shm: WlShm,
 
+    /// The cursor images shared by all windows.
+    cursor_cache: Arc<Mutex<CursorCache>>,
+
+    /// Whether the compositor supports drawing the cursor shapes itself.
+    has_cursor_shapes: bool,
+
     /// The last received configure.
     pub last_configure: Option<WindowConfigure>,
 
@@ -86,12 +104,21 @@ pub struct WindowState {
     /// Whether the window has focus.
     has_focus: bool,
 
+    /// Whether the window is activated by the compositor.
+    is_active: bool,
+
     /// The scale factor of the window.
     scale_factor: f64,
 
     /// Whether the window is transparent.
     transparent: bool,
 
+    /// The opaque region set by the user, overriding the one derived from `transparent`.
+    opaque_region: Option<Vec<Rect>>,
+
+    /// The input region set by the user, `None` when the whole window catches the events.
+    input_region: Option<Vec<Rect>>,
+
     /// The state of the compositor to create WlRegions.
     compositor: Arc<CompositorState>,
 
@@ -104,6 +131,12 @@ pub struct WindowState {
     /// The current IME purpose.
     ime_purpose: ImePurpose,
 
+    /// Whether the key repeat events are delivered to the window.
+    key_repeat: bool,
+
+    /// The text around the cursor, with the cursor and anchor positions.
+    ime_surrounding_text: Option<(String, usize, usize)>,
+
     /// The text inputs observed on the window.
     text_inputs: Vec<ZwpTextInputV3>,
 
@@ -128,13 +161,34 @@ pub struct WindowState {
     /// The state of the frame callback.
     frame_callback_state: FrameCallbackState,
 
+    /// The time the last frame callback arrived.
+    frame_callback_time: Option<Instant>,
+
     viewport: Option<WpViewport>,
     fractional_scale: Option<WpFractionalScaleV1>,
 
+    /// The buffer scale set by the user, overriding the one derived from the scale factor.
+    buffer_scale: Option<u32>,
+
+    /// The part of the buffer scaled to the window's surface set by the user.
+    viewport_source: Option<(LogicalPosition<f64>, LogicalSize<f64>)>,
+
+    /// Manager to create buffers used to fill the window with a color.
+    single_pixel_buffer_manager: Option<WpSinglePixelBufferManagerV1>,
+
+    /// The color to fill the window with once it's configured.
+    pending_fill_color: Option<[u8; 4]>,
+
     /// Whether the client side decorations have pending move operations.
     ///
     /// The value is the serial of the event triggered moved.
     has_pending_move: Option<u32>,
+
+    /// The regions acting as the title bar or resize handles, set by the user.
+    hittest_regions: Vec<HitTestRegion>,
+
+    /// The time of the last click on a title bar region, to detect double clicks.
+    last_title_bar_click: Option<u32>,
 }
 
 impl WindowState {
@@ -153,6 +207,10 @@ impl WindowState {
             .viewporter_state
             .as_ref()
             .map(|state| state.get_viewport(window.wl_surface(), queue_handle));
+        let single_pixel_buffer_manager = winit_state
+            .single_pixel_buffer_manager
+            .as_ref()
+            .map(|manager| manager.manager().clone());
         let fractional_scale = winit_state
             .fractional_scaling_manager
             .as_ref()
@@ -169,19 +227,29 @@ impl WindowState {
             fractional_scale,
             frame: None,
             frame_callback_state: FrameCallbackState::None,
+            frame_callback_time: None,
             has_focus: false,
             has_pending_move: None,
+            hittest_regions: Vec::new(),
             ime_allowed: false,
             ime_purpose: ImePurpose::Normal,
+            ime_surrounding_text: None,
+            is_active: false,
+            key_repeat: true,
             last_configure: None,
+            last_title_bar_click: None,
             max_inner_size: None,
             min_inner_size: MIN_WINDOW_SIZE,
+            opaque_region: None,
+            input_region: None,
             pointer_constraints,
             pointers: Default::default(),
             queue_handle: queue_handle.clone(),
             resizable: true,
             scale_factor: 1.,
             shm: winit_state.shm.wl_shm().clone(),
+            cursor_cache: winit_state.cursor_cache.clone(),
+            has_cursor_shapes: winit_state.cursor_shape_manager.is_some(),
             size,
             stateless_size: size,
             text_inputs: Vec::new(),
@@ -189,6 +257,10 @@ impl WindowState {
             title: String::default(),
             transparent: false,
             viewport,
+            buffer_scale: None,
+            viewport_source: None,
+            single_pixel_buffer_manager,
+            pending_fill_color: None,
             window: ManuallyDrop::new(window),
         }
     }
@@ -215,6 +287,31 @@ impl WindowState {
     /// The frame callback was received, but not yet sent to the user.
     pub fn frame_callback_received(&mut self) {
         self.frame_callback_state = FrameCallbackState::Received;
+        self.frame_callback_time = Some(Instant::now());
+    }
+
+    /// Estimate when the next frame must be ready to make the next refresh of the monitor.
+    ///
+    /// The compositor sends the frame callback when it's a good time to start drawing, so count
+    /// one refresh period from when it arrived.
+    pub fn frame_deadline(&self) -> Option<Instant> {
+        if self.frame_callback_state != FrameCallbackState::Received {
+            return None;
+        }
+
+        let output = self
+            .window
+            .wl_surface()
+            .data::<SurfaceData>()?
+            .outputs()
+            .next()?;
+        let refresh_rate = MonitorHandle::new(output).refresh_rate_millihertz()?;
+        if refresh_rate == 0 {
+            return None;
+        }
+
+        let period = Duration::from_nanos(1_000_000_000_000 / refresh_rate as u64);
+        Some(self.frame_callback_time? + period)
     }
 
     /// Reset the frame callbacks state.
@@ -234,6 +331,36 @@ impl WindowState {
         }
     }
 
+    /// Mark the regions of the next buffer that have changed.
+    ///
+    /// The damage is accumulated by the compositor until the next commit of the surface.
+    pub fn add_damage(&self, damage: &[(PhysicalPosition<i32>, PhysicalSize<u32>)]) {
+        let surface = self.window.wl_surface();
+        // `damage_buffer` is only available since version 4, fall back to the surface local
+        // coordinates otherwise.
+        let use_buffer_damage = surface.version() >= 4;
+        // With fractional scaling the buffer is scaled down by the viewport rather than by the
+        // buffer scale, which is then 1.
+        let scale = if self.buffer_scale.is_none() && self.fractional_scale.is_some() {
+            self.scale_factor
+        } else {
+            self.effective_buffer_scale() as f64
+        };
+        for &(position, size) in damage {
+            if use_buffer_damage {
+                surface.damage_buffer(
+                    position.x,
+                    position.y,
+                    size.width as i32,
+                    size.height as i32,
+                );
+            } else {
+                let (x, y, width, height) = surface_damage(position, size, scale);
+                surface.damage(x, y, width, height);
+            }
+        }
+    }
+
     pub fn configure(
         &mut self,
         configure: WindowConfigure,
@@ -300,9 +427,19 @@ impl WindowState {
         // XXX Update the new size right away.
         self.resize(new_size);
 
+        if let Some(rgba) = self.pending_fill_color.take() {
+            self.commit_fill_color(rgba);
+        }
+
         new_size
     }
 
+    /// The inner size the window has when it's not maximized, fullscreen or tiled.
+    #[inline]
+    pub fn restored_size(&self) -> LogicalSize<u32> {
+        self.stateless_size
+    }
+
     #[inline]
     fn is_stateless(configure: &WindowConfigure) -> bool {
         !(configure.is_maximized() || configure.is_fullscreen() || configure.is_tiled())
@@ -335,6 +472,63 @@ impl WindowState {
         Ok(())
     }
 
+    /// Set the regions acting as the title bar or resize handles.
+    pub fn set_hittest_regions(&mut self, regions: &[HitTestRegion]) {
+        self.hittest_regions = regions.to_vec();
+    }
+
+    /// Handle a press on the main surface at `position`, in surface coordinates, returning
+    /// `true` if it hit one of the regions set by the user.
+    pub fn hittest_press(
+        &mut self,
+        seat: &WlSeat,
+        serial: u32,
+        time: u32,
+        button: MouseButton,
+        position: LogicalPosition<f64>,
+    ) -> bool {
+        let position = position.to_physical(self.scale_factor);
+        let kind = match HitTestRegion::hit(&self.hittest_regions, position, self.scale_factor) {
+            Some(kind) => kind,
+            None => return false,
+        };
+
+        let xdg_toplevel = self.window.xdg_toplevel();
+        match (kind, button) {
+            (HitTestKind::TitleBar, MouseButton::Left) => {
+                let double_click = self
+                    .last_title_bar_click
+                    .map_or(false, |last| time.wrapping_sub(last) <= DOUBLE_CLICK_TIME);
+                if double_click {
+                    self.last_title_bar_click = None;
+                    let maximized = self
+                        .last_configure
+                        .as_ref()
+                        .map_or(false, |configure| configure.is_maximized());
+                    if maximized {
+                        self.window.unset_maximized();
+                    } else {
+                        self.window.set_maximized();
+                    }
+                } else {
+                    self.last_title_bar_click = Some(time);
+                    xdg_toplevel._move(seat, serial);
+                }
+            }
+            (HitTestKind::TitleBar, MouseButton::Right) => {
+                let position = position.to_logical::<i32>(self.scale_factor);
+                self.window
+                    .show_window_menu(seat, serial, (position.x, position.y));
+            }
+            (HitTestKind::Resize(direction), MouseButton::Left) => {
+                xdg_toplevel.resize(seat, serial, direction.into());
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
     /// Tells whether the window should be closed.
     pub fn frame_click(
         &mut self,
@@ -424,12 +618,30 @@ impl WindowState {
         self.has_focus
     }
 
+    /// Whether the window is activated.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
     /// Whether the IME is allowed.
     #[inline]
     pub fn ime_allowed(&self) -> bool {
         self.ime_allowed
     }
 
+    /// Whether the key repeat events are delivered to the window.
+    #[inline]
+    pub fn key_repeat(&self) -> bool {
+        self.key_repeat
+    }
+
+    /// Set whether the key repeat events are delivered to the window.
+    #[inline]
+    pub fn set_key_repeat(&mut self, key_repeat: bool) {
+        self.key_repeat = key_repeat;
+    }
+
     /// Get the size of the window.
     #[inline]
     pub fn inner_size(&self) -> LogicalSize<u32> {
@@ -515,16 +727,42 @@ impl WindowState {
     pub fn reload_transparency_hint(&self) {
         let surface = self.window.wl_surface();
 
-        if self.transparent {
+        if self.opaque_region.is_none() && self.transparent {
             surface.set_opaque_region(None);
         } else if let Ok(region) = Region::new(&*self.compositor) {
-            region.add(0, 0, i32::MAX, i32::MAX);
+            match self.opaque_region.as_ref() {
+                Some(rects) => add_rects(&region, rects, self.scale_factor),
+                None => region.add(0, 0, i32::MAX, i32::MAX),
+            }
             surface.set_opaque_region(Some(region.wl_region()));
         } else {
             warn!("Failed to mark window opaque.");
         }
     }
 
+    /// Reissue the input region to the compositor.
+    fn reload_input_region(&self) -> Result<(), ExternalError> {
+        let surface = self.window.wl_surface();
+
+        let rects = match self.input_region.as_ref() {
+            Some(rects) => rects,
+            None => {
+                surface.set_input_region(None);
+                return Ok(());
+            }
+        };
+
+        let region = Region::new(&*self.compositor).map_err(|_| {
+            ExternalError::Os(os_error!(crate::platform_impl::OsError::WaylandMisc(
+                "failed to set input region."
+            )))
+        })?;
+        add_rects(&region, rects, self.scale_factor);
+        surface.set_input_region(Some(region.wl_region()));
+
+        Ok(())
+    }
+
     /// Resize the window to the new inner size.
     pub fn resize(&mut self, inner_size: LogicalSize<u32>) {
         self.size = inner_size;
@@ -576,6 +814,12 @@ impl WindowState {
         self.scale_factor
     }
 
+    /// Get the integer scale the cursor images should be loaded at.
+    #[inline]
+    pub fn cursor_scale_factor(&self) -> i32 {
+        self.scale_factor.ceil() as i32
+    }
+
     /// Set the cursor icon.
     ///
     /// Providing `None` will hide the cursor.
@@ -586,25 +830,52 @@ impl WindowState {
             return;
         }
 
+        // Compositors don't reliably send output enter events for the cursor surface, so its own
+        // scale factor can stay at 1. Use the window's one so the cursor is sized right on HiDPI.
+        let scale_factor = self.cursor_scale_factor();
         self.apply_on_poiner(|pointer, data| {
-            let surface = data.cursor_surface();
-            let scale_factor = surface.data::<SurfaceData>().unwrap().scale_factor();
-
-            if pointer
-                .set_cursor(
-                    &self.connection,
-                    cursor_icon.name(),
-                    &self.shm,
-                    surface,
-                    scale_factor,
-                )
-                .is_err()
+            // Let the compositor draw the cursor when it supports the cursor shape protocol.
+            if let Some((device, shape)) = data
+                .cursor_shape_device()
+                .zip(cursor_icon_to_shape(cursor_icon))
             {
+                device.set_shape(data.latest_enter_serial(), shape);
+                return;
+            }
+
+            if !self.cursor_cache.lock().unwrap().set_cursor(
+                &self.connection,
+                &self.shm,
+                pointer.pointer(),
+                data.latest_enter_serial(),
+                data.cursor_surface(),
+                cursor_icon.name(),
+                scale_factor,
+            ) {
                 warn!("Failed to set cursor to {:?}", cursor_icon);
             }
         })
     }
 
+    /// Load the images of the given cursors ahead of their first use.
+    pub fn preload_cursors(&self, cursor_icons: &[CursorIcon]) {
+        let scale_factor = self.cursor_scale_factor() as u32;
+        let mut cursor_cache = self.cursor_cache.lock().unwrap();
+        for &cursor_icon in cursor_icons {
+            // The compositor draws these icons itself, so they never need an image.
+            if self.has_cursor_shapes && cursor_icon_to_shape(cursor_icon).is_some() {
+                continue;
+            }
+
+            cursor_cache.get(
+                &self.connection,
+                &self.shm,
+                cursor_icon.name(),
+                scale_factor,
+            );
+        }
+    }
+
     /// Set maximum inner window size.
     pub fn set_min_inner_size(&mut self, size: Option<LogicalSize<u32>>) {
         // Ensure that the window has the right minimum size.
@@ -782,6 +1053,16 @@ impl WindowState {
         self.has_focus = has_focus;
     }
 
+    /// Mark that the window is activated.
+    ///
+    /// Returns `true` if the state has changed.
+    #[inline]
+    pub fn set_is_active(&mut self, is_active: bool) -> bool {
+        let changed = self.is_active != is_active;
+        self.is_active = is_active;
+        changed
+    }
+
     /// Returns `true` if the requested state was applied.
     pub fn set_ime_allowed(&mut self, allowed: bool) -> bool {
         self.ime_allowed = allowed;
@@ -792,6 +1073,9 @@ impl WindowState {
             if allowed {
                 text_input.enable();
                 text_input.set_content_type_by_purpose(self.ime_purpose);
+                if let Some((text, cursor, anchor)) = self.ime_surrounding_text.as_ref() {
+                    text_input.set_surrounding_text_clamped(text, *cursor, *anchor);
+                }
             } else {
                 text_input.disable();
             }
@@ -829,14 +1113,131 @@ impl WindowState {
         self.ime_purpose
     }
 
+    /// Set the text around the cursor.
+    pub fn set_ime_surrounding_text(&mut self, text: &str, cursor: usize, anchor: usize) {
+        for text_input in &self.text_inputs {
+            text_input.set_surrounding_text_clamped(text, cursor, anchor);
+            text_input.commit();
+        }
+
+        self.ime_surrounding_text = Some((text.to_owned(), cursor, anchor));
+    }
+
+    /// Get the text around the cursor.
+    pub fn ime_surrounding_text(&self) -> Option<(&str, usize, usize)> {
+        self.ime_surrounding_text
+            .as_ref()
+            .map(|(text, cursor, anchor)| (text.as_str(), *cursor, *anchor))
+    }
+
+    /// Set the buffer scale of the surface, `None` lets winit derive it from the scale factor.
+    pub fn set_buffer_scale(&mut self, buffer_scale: Option<u32>) {
+        self.buffer_scale = buffer_scale.map(|scale| scale.max(1));
+        let _ = self.window.set_buffer_scale(self.effective_buffer_scale());
+    }
+
+    /// The buffer scale currently set on the surface.
+    fn effective_buffer_scale(&self) -> u32 {
+        match self.buffer_scale {
+            Some(buffer_scale) => buffer_scale,
+            // With fractional scaling the buffer is scaled through the viewport instead.
+            None if self.fractional_scale.is_some() => 1,
+            None => self.scale_factor as u32,
+        }
+    }
+
+    /// Fill the window with a color, until the next buffer is committed.
+    pub fn fill_color(&mut self, rgba: [u8; 4]) -> Result<(), ExternalError> {
+        if self.single_pixel_buffer_manager.is_none() || self.viewport.is_none() {
+            return Err(ExternalError::NotSupported(NotSupportedError::new()));
+        }
+
+        // Attaching a buffer before the initial configure is a protocol error.
+        if self.is_configured() {
+            self.commit_fill_color(rgba);
+        } else {
+            self.pending_fill_color = Some(rgba);
+        }
+
+        Ok(())
+    }
+
+    fn commit_fill_color(&self, rgba: [u8; 4]) {
+        let manager = match self.single_pixel_buffer_manager.as_ref() {
+            Some(manager) => manager,
+            None => return,
+        };
+
+        // Checked by `fill_color`.
+        let viewport = self.viewport.as_ref().unwrap();
+        let buffer = wp_single_pixel_buffer::create_buffer(manager, rgba, &self.queue_handle);
+        let surface = self.window.wl_surface();
+
+        // The 1x1 buffer is stretched to the window size by the viewport, so its size must be a
+        // multiple of the buffer scale and the whole buffer must be used as the source.
+        let _ = self.window.set_buffer_scale(1);
+        if self.viewport_source.is_some() {
+            viewport.set_source(-1., -1., -1., -1.);
+        }
+
+        surface.attach(Some(&buffer), 0, 0);
+        surface.damage(0, 0, i32::MAX, i32::MAX);
+        surface.commit();
+
+        // Restore the state for the next buffer committed by the user.
+        let _ = self.window.set_buffer_scale(self.effective_buffer_scale());
+        if let Some((position, size)) = self.viewport_source {
+            viewport.set_source(position.x, position.y, size.width, size.height);
+        }
+    }
+
+    /// Set the part of the buffer that is scaled to the window's surface.
+    pub fn set_viewport_source(
+        &mut self,
+        source: Option<(LogicalPosition<f64>, LogicalSize<f64>)>,
+    ) -> Result<(), ExternalError> {
+        let viewport = match self.viewport.as_ref() {
+            Some(viewport) => viewport,
+            None => return Err(ExternalError::NotSupported(NotSupportedError::new())),
+        };
+
+        match source {
+            // An invalid source rectangle is a protocol error, which would kill the connection.
+            Some((position, size)) if !is_valid_viewport_source(position, size) => {
+                return Err(ExternalError::Ignored);
+            }
+            Some((position, size)) => {
+                viewport.set_source(position.x, position.y, size.width, size.height)
+            }
+            // Unset the source, so the whole buffer is used.
+            None => viewport.set_source(-1., -1., -1., -1.),
+        }
+        self.viewport_source = source;
+
+        Ok(())
+    }
+
     /// Set the scale factor for the given window.
     #[inline]
     pub fn set_scale_factor(&mut self, scale_factor: f64) {
         self.scale_factor = scale_factor;
 
         // XXX when fractional scaling is not used update the buffer scale.
-        if self.fractional_scale.is_none() {
-            let _ = self.window.set_buffer_scale(self.scale_factor as _);
+        if self.fractional_scale.is_none() && self.buffer_scale.is_none() {
+            let _ = self.window.set_buffer_scale(self.effective_buffer_scale());
+        }
+
+        // Reload the cursor images at the new scale.
+        if self.cursor_visible {
+            self.set_cursor(self.cursor_icon);
+        }
+
+        // The opaque and input regions set by the user may be in physical pixels.
+        if self.opaque_region.is_some() {
+            self.reload_transparency_hint();
+        }
+        if self.input_region.is_some() && self.reload_input_region().is_err() {
+            warn!("Failed to update the input region.");
         }
     }
 
@@ -870,6 +1271,20 @@ impl WindowState {
         self.reload_transparency_hint();
     }
 
+    /// Set the opaque region of the window, `None` deriving it from the transparency again.
+    #[inline]
+    pub fn set_opaque_region(&mut self, region: Option<&[Rect]>) {
+        self.opaque_region = region.map(<[_]>::to_vec);
+        self.reload_transparency_hint();
+    }
+
+    /// Set the input region of the window, `None` making the whole window catch the events.
+    #[inline]
+    pub fn set_input_region(&mut self, region: Option<&[Rect]>) -> Result<(), ExternalError> {
+        self.input_region = region.map(<[_]>::to_vec);
+        self.reload_input_region()
+    }
+
     /// Register text input on the top-level.
     #[inline]
     pub fn text_input_entered(&mut self, text_input: &ZwpTextInputV3) {
@@ -950,6 +1365,41 @@ impl From<ResizeDirection> for ResizeEdge {
     }
 }
 
+/// Whether the compositor accepts the source rectangle, which must be finite, start inside of the
+/// buffer and have an area.
+fn is_valid_viewport_source(position: LogicalPosition<f64>, size: LogicalSize<f64>) -> bool {
+    [position.x, position.y, size.width, size.height]
+        .iter()
+        .all(|value| value.is_finite())
+        && position.x >= 0.
+        && position.y >= 0.
+        && size.width > 0.
+        && size.height > 0.
+}
+
+/// Add the rectangles set by the user to the region, in surface local coordinates.
+fn add_rects(region: &Region, rects: &[Rect], scale_factor: f64) {
+    for rect in rects {
+        let position: LogicalPosition<i32> = rect.position.to_logical(scale_factor);
+        let size: LogicalSize<i32> = rect.size.to_logical(scale_factor);
+        region.add(position.x, position.y, size.width, size.height);
+    }
+}
+
+/// Converts a damaged rectangle of the buffer into surface local coordinates, covering all the
+/// partially damaged surface pixels.
+fn surface_damage(
+    position: PhysicalPosition<i32>,
+    size: PhysicalSize<u32>,
+    scale: f64,
+) -> (i32, i32, i32, i32) {
+    let x = (position.x as f64 / scale).floor();
+    let y = (position.y as f64 / scale).floor();
+    let width = ((position.x as f64 + size.width as f64) / scale).ceil() - x;
+    let height = ((position.y as f64 + size.height as f64) / scale).ceil() - y;
+    (x as i32, y as i32, width as i32, height as i32)
+}
+
 // XXX rust doesn't allow from `Option`.
 #[cfg(feature = "sctk-adwaita")]
 fn into_sctk_adwaita_config(theme: Option<Theme>) -> sctk_adwaita::FrameConfig {
@@ -959,3 +1409,39 @@ fn into_sctk_adwaita_config(theme: Option<Theme>) -> sctk_adwaita::FrameConfig {
         None => sctk_adwaita::FrameConfig::auto(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn surface_damage_divides_by_the_scale() {
+        let damage = surface_damage(PhysicalPosition::new(20, 40), PhysicalSize::new(60, 80), 2.);
+        assert_eq!(damage, (10, 20, 30, 40));
+    }
+
+    #[test]
+    fn surface_damage_covers_partially_damaged_pixels() {
+        let damage = surface_damage(PhysicalPosition::new(3, 3), PhysicalSize::new(2, 2), 2.);
+        assert_eq!(damage, (1, 1, 2, 2));
+
+        let damage = surface_damage(PhysicalPosition::new(1, 0), PhysicalSize::new(3, 3), 1.5);
+        assert_eq!(damage, (0, 0, 3, 2));
+    }
+
+    #[test]
+    fn viewport_source_must_be_finite_and_inside_the_buffer() {
+        let valid = |x, y, width, height| {
+            is_valid_viewport_source(LogicalPosition::new(x, y), LogicalSize::new(width, height))
+        };
+        assert!(valid(0., 0., 10., 10.));
+        assert!(valid(2.5, 4., 0.5, 1.));
+        assert!(!valid(0., 0., 0., 10.));
+        assert!(!valid(0., 0., 10., -1.));
+        assert!(!valid(-1., 0., 10., 10.));
+        assert!(!valid(0., -0.5, 10., 10.));
+        assert!(!valid(f64::NAN, 0., 10., 10.));
+        assert!(!valid(0., 0., f64::NAN, 10.));
+        assert!(!valid(0., 0., 10., f64::INFINITY));
+    }
+}
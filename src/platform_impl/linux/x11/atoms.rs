@@ -34,6 +34,7 @@ macro_rules! atom_manager {
 atom_manager! {
     // General Use Atoms
     CARD32,
+    Edid: b"EDID",
     UTF8_STRING,
     WM_CHANGE_STATE,
     WM_CLIENT_MACHINE,
@@ -78,6 +79,11 @@ atom_manager! {
     _NET_WM_WINDOW_TYPE_DND,
     _NET_WM_WINDOW_TYPE_NORMAL,
 
+    // XKB indicator names
+    CapsLock: b"Caps Lock",
+    NumLock: b"Num Lock",
+    ScrollLock: b"Scroll Lock",
+
     // Drag-N-Drop Atoms
     XdndAware,
     XdndEnter,
@@ -92,14 +98,23 @@ atom_manager! {
     TextUriList: b"text/uri-list",
     None: b"None",
 
+    // Selection atoms
+    Clipboard: b"CLIPBOARD",
+    Incr: b"INCR",
+    Targets: b"TARGETS",
+    TextPlainUtf8: b"text/plain;charset=utf-8",
+
     // Miscellaneous Atoms
     _GTK_THEME_VARIANT,
+    _KDE_NET_WM_BLUR_BEHIND_REGION,
     _MOTIF_WM_HINTS,
     _NET_ACTIVE_WINDOW,
     _NET_CLIENT_LIST,
+    _NET_CURRENT_DESKTOP,
     _NET_FRAME_EXTENTS,
     _NET_SUPPORTED,
-    _NET_SUPPORTING_WM_CHECK
+    _NET_SUPPORTING_WM_CHECK,
+    _NET_WORKAREA
 }
 
 impl Index<AtomName> for Atoms {
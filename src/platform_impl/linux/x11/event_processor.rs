@@ -18,10 +18,17 @@ use super::{
 
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize},
-    event::{DeviceEvent, ElementState, Event, Ime, RawKeyEvent, TouchPhase, WindowEvent},
+    event::{
+        DeviceEvent, DeviceKind, ElementState, Event, Ime, MonitorEvent, RawKeyEvent, TouchPhase,
+        WindowEvent,
+    },
     event_loop::EventLoopWindowTarget as RootELW,
-    keyboard::ModifiersState,
-    platform_impl::platform::common::{keymap, xkb_state::KbdState},
+    keyboard::{LockKeyState, ModifiersState},
+    monitor::MonitorHandle as RootMonitorHandle,
+    platform_impl::platform::{
+        common::{keymap, xkb_state::KbdState},
+        MonitorHandle as PlatformMonitorHandle,
+    },
 };
 use crate::{
     event::InnerSizeWriter,
@@ -41,6 +48,8 @@ pub(super) struct EventProcessor<T: 'static> {
     pub(super) xkbext: XExtension,
     pub(super) target: Rc<RootELW<T>>,
     pub(super) kb_state: KbdState,
+    // The lock key state last reported to the focused window.
+    pub(super) lock_keys: LockKeyState,
     // Number of touch events currently in progress
     pub(super) num_touch: u32,
     // This is the last pressed key that is repeatable (if it hasn't been
@@ -324,9 +333,44 @@ impl<T: 'static> EventProcessor<T> {
                     }
 
                     self.dnd.result = result;
+                } else if let Some(event) = wt.xconn.read_selection(
+                    window,
+                    xsel.selection as xproto::Atom,
+                    xsel.target as xproto::Atom,
+                    xsel.property as xproto::Atom,
+                ) {
+                    // This is where we receive the primary selection and the clipboard.
+                    callback(Event::WindowEvent { window_id, event });
                 }
             }
 
+            ffi::SelectionRequest => {
+                let xsel: &ffi::XSelectionRequestEvent = xev.as_ref();
+
+                let request = xproto::SelectionRequestEvent {
+                    response_type: xproto::SELECTION_REQUEST_EVENT,
+                    sequence: 0,
+                    time: xsel.time as xproto::Timestamp,
+                    owner: xsel.owner as xproto::Window,
+                    requestor: xsel.requestor as xproto::Window,
+                    selection: xsel.selection as xproto::Atom,
+                    target: xsel.target as xproto::Atom,
+                    property: xsel.property as xproto::Atom,
+                };
+                if let Err(err) = wt.xconn.answer_selection_request(&request) {
+                    log::warn!("Failed to answer the request for a selection: {err}");
+                }
+            }
+
+            ffi::SelectionClear => {
+                let xsel: &ffi::XSelectionClearEvent = xev.as_ref();
+
+                wt.xconn.clear_selection(
+                    xsel.window as xproto::Window,
+                    xsel.selection as xproto::Atom,
+                );
+            }
+
             ffi::ConfigureNotify => {
                 let xev: &ffi::XConfigureEvent = xev.as_ref();
                 let xwindow = xev.window as xproto::Window;
@@ -532,6 +576,21 @@ impl<T: 'static> EventProcessor<T> {
                     event: WindowEvent::Focused(focus),
                 });
             }
+            ffi::UnmapNotify => {
+                let xev: &ffi::XUnmapEvent = xev.as_ref();
+                let window = xev.window as xproto::Window;
+
+                // The window manager unmaps windows when they get minimized or moved to another
+                // workspace. Since no `VisibilityNotify` is sent in that case, report the window
+                // as occluded here; mapping it again is followed by a `VisibilityNotify`, which
+                // reports the new state.
+                if self.with_window(window, |_| ()).is_some() {
+                    callback(Event::WindowEvent {
+                        window_id: mkwid(window),
+                        event: WindowEvent::Occluded(true),
+                    });
+                }
+            }
             ffi::DestroyNotify => {
                 let xev: &ffi::XDestroyWindowEvent = xev.as_ref();
 
@@ -576,7 +635,10 @@ impl<T: 'static> EventProcessor<T> {
                     let window = xev.window as xproto::Window;
                     let window_id = mkwid(window);
 
-                    callback(Event::RedrawRequested(window_id));
+                    callback(Event::RedrawRequested {
+                        window_id,
+                        deadline: None,
+                    });
                 }
             }
 
@@ -633,6 +695,13 @@ impl<T: 'static> EventProcessor<T> {
                 };
 
                 if keycode != 0 && !self.is_composing {
+                    let key_repeat = self
+                        .with_window(window, |window| window.shared_state_lock().key_repeat)
+                        .unwrap_or(true);
+                    if repeat && !key_repeat {
+                        return;
+                    }
+
                     let event = self.kb_state.process_key_event(keycode, state, repeat);
                     callback(Event::WindowEvent {
                         window_id,
@@ -647,7 +716,7 @@ impl<T: 'static> EventProcessor<T> {
                     if !written.is_empty() {
                         let event = Event::WindowEvent {
                             window_id,
-                            event: WindowEvent::Ime(Ime::Preedit(String::new(), None)),
+                            event: WindowEvent::Ime(Ime::Preedit(String::new(), None, Vec::new())),
                         };
                         callback(event);
 
@@ -751,7 +820,9 @@ impl<T: 'static> EventProcessor<T> {
                                 }
                             }
 
-                            8 => callback(Event::WindowEvent {
+                            // The drivers number the evdev buttons from `BTN_SIDE` at 8, so 10 and
+                            // 11 are `BTN_FORWARD` and `BTN_BACK`, mapped like on Wayland.
+                            8 | 11 => callback(Event::WindowEvent {
                                 window_id,
                                 event: MouseInput {
                                     device_id,
@@ -759,7 +830,7 @@ impl<T: 'static> EventProcessor<T> {
                                     button: Back,
                                 },
                             }),
-                            9 => callback(Event::WindowEvent {
+                            9 | 10 => callback(Event::WindowEvent {
                                 window_id,
                                 event: MouseInput {
                                     device_id,
@@ -773,7 +844,10 @@ impl<T: 'static> EventProcessor<T> {
                                 event: MouseInput {
                                     device_id,
                                     state,
-                                    button: Other(x as u16),
+                                    // Buttons 4 to 7 are the scroll wheel and 8 to 11 back and
+                                    // forward, so the first extra button, `BTN_TASK`, is
+                                    // `Other(5)`.
+                                    button: Other(x.saturating_sub(7) as u16),
                                 },
                             }),
                         }
@@ -939,6 +1013,10 @@ impl<T: 'static> EventProcessor<T> {
                         // Set the timestamp.
                         wt.xconn.set_timestamp(xev.time as xproto::Timestamp);
 
+                        if is_transient_focus_change(xev.mode, xev.detail) {
+                            return;
+                        }
+
                         wt.ime
                             .borrow_mut()
                             .focus(xev.event)
@@ -952,15 +1030,26 @@ impl<T: 'static> EventProcessor<T> {
                             let window_id = mkwid(window);
                             let position = PhysicalPosition::new(xev.event_x, xev.event_y);
 
-                            if let Some(window) = self.with_window(window, Arc::clone) {
-                                window.shared_state_lock().has_focus = true;
-                            }
+                            let grab_lost = match self.with_window(window, Arc::clone) {
+                                Some(window) => {
+                                    window.shared_state_lock().has_focus = true;
+                                    !window.restore_cursor_grab()
+                                }
+                                None => false,
+                            };
 
                             callback(Event::WindowEvent {
                                 window_id,
                                 event: Focused(true),
                             });
 
+                            if grab_lost {
+                                callback(Event::WindowEvent {
+                                    window_id,
+                                    event: WindowEvent::PointerGrabChanged { grabbed: false },
+                                });
+                            }
+
                             let modifiers: crate::keyboard::ModifiersState =
                                 self.kb_state.mods_state().into();
                             if !modifiers.is_empty() {
@@ -1004,7 +1093,9 @@ impl<T: 'static> EventProcessor<T> {
                         // Set the timestamp.
                         wt.xconn.set_timestamp(xev.time as xproto::Timestamp);
 
-                        if !self.window_exists(window) {
+                        if !self.window_exists(window)
+                            || is_transient_focus_change(xev.mode, xev.detail)
+                        {
                             return;
                         }
 
@@ -1204,17 +1295,25 @@ impl<T: 'static> EventProcessor<T> {
                         for info in
                             unsafe { slice::from_raw_parts(xev.info, xev.num_info as usize) }
                         {
+                            let kind = match info._use {
+                                ffi::XIMasterPointer | ffi::XISlavePointer => DeviceKind::Pointer,
+                                ffi::XIMasterKeyboard | ffi::XISlaveKeyboard => {
+                                    DeviceKind::Keyboard
+                                }
+                                _ => DeviceKind::Other,
+                            };
+
                             if 0 != info.flags & (ffi::XISlaveAdded | ffi::XIMasterAdded) {
                                 self.init_device(info.deviceid);
                                 callback(Event::DeviceEvent {
                                     device_id: mkdid(info.deviceid),
-                                    event: DeviceEvent::Added,
+                                    event: DeviceEvent::Added { kind },
                                 });
                             } else if 0 != info.flags & (ffi::XISlaveRemoved | ffi::XIMasterRemoved)
                             {
                                 callback(Event::DeviceEvent {
                                     device_id: mkdid(info.deviceid),
-                                    event: DeviceEvent::Removed,
+                                    event: DeviceEvent::Removed { kind },
                                 });
                                 let mut devices = self.devices.borrow_mut();
                                 devices.remove(&DeviceId(info.deviceid));
@@ -1279,14 +1378,56 @@ impl<T: 'static> EventProcessor<T> {
                                 }
                             }
                         }
+                        ffi::XkbIndicatorStateNotify => {
+                            let xev = unsafe {
+                                &*(xev as *const _ as *const ffi::XkbIndicatorNotifyEvent)
+                            };
+
+                            // Set the timestamp.
+                            wt.xconn.set_timestamp(xev.time as xproto::Timestamp);
+
+                            // The event only carries indicator indices, so look the lock keys
+                            // up by name.
+                            if let Ok(lock_keys) = wt.xconn.query_lock_keys() {
+                                if std::mem::replace(&mut self.lock_keys, lock_keys) != lock_keys {
+                                    if let Some(window) = self.active_window {
+                                        callback(Event::WindowEvent {
+                                            window_id: mkwid(window),
+                                            event: WindowEvent::LockKeysChanged(lock_keys),
+                                        });
+                                    }
+                                }
+                            }
+                        }
                         _ => {}
                     }
                 }
                 if event_type == self.randr_event_offset {
-                    // In the future, it would be quite easy to emit monitor hotplug events.
                     let prev_list = monitor::invalidate_cached_monitor_list();
                     if let Some(prev_list) = prev_list {
                         let new_list = wt.xconn.available_monitors();
+
+                        let (disconnected, connected) =
+                            crate::monitor::monitor_changes(&prev_list, &new_list, |a, b| {
+                                a.name == b.name
+                            });
+                        let changes = disconnected
+                            .into_iter()
+                            .map(|monitor| (monitor, MonitorEvent::Disconnected))
+                            .chain(
+                                connected
+                                    .into_iter()
+                                    .map(|monitor| (monitor, MonitorEvent::Connected)),
+                            );
+                        for (monitor, event) in changes {
+                            callback(Event::MonitorEvent {
+                                monitor: RootMonitorHandle {
+                                    inner: PlatformMonitorHandle::X(monitor.clone()),
+                                },
+                                event,
+                            });
+                        }
+
                         for new_monitor in new_list {
                             // Previous list may be empty, in case of disconnecting and
                             // reconnecting the only one monitor. We still need to emit events in
@@ -1378,14 +1519,18 @@ impl<T: 'static> EventProcessor<T> {
                 self.is_composing = true;
                 callback(Event::WindowEvent {
                     window_id: mkwid(window),
-                    event: WindowEvent::Ime(Ime::Preedit("".to_owned(), None)),
+                    event: WindowEvent::Ime(Ime::Preedit("".to_owned(), None, Vec::new())),
                 });
             }
-            ImeEvent::Update(text, position) => {
+            ImeEvent::Update(text, position, spans) => {
                 if self.is_composing {
                     callback(Event::WindowEvent {
                         window_id: mkwid(window),
-                        event: WindowEvent::Ime(Ime::Preedit(text, Some((position, position)))),
+                        event: WindowEvent::Ime(Ime::Preedit(
+                            text,
+                            Some((position, position)),
+                            spans,
+                        )),
                     });
                 }
             }
@@ -1394,7 +1539,7 @@ impl<T: 'static> EventProcessor<T> {
                 // Issue empty preedit on `Done`.
                 callback(Event::WindowEvent {
                     window_id: mkwid(window),
-                    event: WindowEvent::Ime(Ime::Preedit(String::new(), None)),
+                    event: WindowEvent::Ime(Ime::Preedit(String::new(), None, Vec::new())),
                 });
             }
             ImeEvent::Disabled => {
@@ -1458,3 +1603,11 @@ fn is_first_touch(first: &mut Option<u64>, num: &mut u32, id: u64, phase: TouchP
 
     *first == Some(id)
 }
+
+/// Whether a focus event doesn't change which window has the keyboard focus.
+///
+/// Keyboard grabs, e.g. by the window manager's window switcher, temporarily move the focus and
+/// give it back once released, while focus moving to or from a child window stays inside of it.
+fn is_transient_focus_change(mode: c_int, detail: c_int) -> bool {
+    matches!(mode, ffi::XINotifyGrab | ffi::XINotifyUngrab) || detail == ffi::XINotifyInferior
+}
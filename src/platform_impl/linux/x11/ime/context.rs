@@ -1,10 +1,12 @@
 use std::ffi::CStr;
 use std::os::raw::c_short;
+use std::slice;
 use std::sync::Arc;
 use std::{mem, ptr};
 
 use x11_dl::xlib::{XIMCallback, XIMPreeditCaretCallbackStruct, XIMPreeditDrawCallbackStruct};
 
+use crate::event::{PreeditSpan, PreeditStyle};
 use crate::platform_impl::platform::x11::ime::input_method::{Style, XIMStyle};
 use crate::platform_impl::platform::x11::ime::{ImeEvent, ImeEventSender};
 
@@ -20,6 +22,11 @@ pub enum ImeContextCreationError {
     Null,
 }
 
+// XIM feedback flags describing how the preedit text should be drawn.
+const XIM_REVERSE: ffi::XIMFeedback = 1;
+const XIM_UNDERLINE: ffi::XIMFeedback = 1 << 1;
+const XIM_HIGHLIGHT: ffi::XIMFeedback = 1 << 2;
+
 /// The callback used by XIM preedit functions.
 type XIMProcNonnull = unsafe extern "C" fn(ffi::XIM, ffi::XPointer, ffi::XPointer);
 
@@ -41,6 +48,7 @@ extern "C" fn preedit_start_callback(
     let client_data = unsafe { &mut *(client_data as *mut ImeContextClientData) };
 
     client_data.text.clear();
+    client_data.styles.clear();
     client_data.cursor_pos = 0;
     client_data
         .event_sender
@@ -59,6 +67,7 @@ extern "C" fn preedit_done_callback(
 
     // Drop text buffer and reset cursor position on done.
     client_data.text = Vec::new();
+    client_data.styles = Vec::new();
     client_data.cursor_pos = 0;
 
     client_data
@@ -73,6 +82,39 @@ fn calc_byte_position(text: &[char], pos: usize) -> usize {
         .fold(0, |byte_pos, text| byte_pos + text.len_utf8())
 }
 
+fn feedback_to_style(feedback: ffi::XIMFeedback) -> Option<PreeditStyle> {
+    if feedback & (XIM_REVERSE | XIM_HIGHLIGHT) != 0 {
+        Some(PreeditStyle::Highlight)
+    } else if feedback & XIM_UNDERLINE != 0 {
+        Some(PreeditStyle::Underline)
+    } else {
+        None
+    }
+}
+
+/// Merge the per character styles into byte-wise indexed spans.
+fn calc_preedit_spans(text: &[char], styles: &[Option<PreeditStyle>]) -> Vec<PreeditSpan> {
+    let mut spans: Vec<PreeditSpan> = Vec::new();
+    let mut byte_pos = 0;
+    for (chr, style) in text.iter().zip(styles) {
+        let char_end = byte_pos + chr.len_utf8();
+        if let Some(style) = *style {
+            match spans.last_mut() {
+                Some(span) if span.style == style && span.range.end == byte_pos => {
+                    span.range.end = char_end;
+                }
+                _ => spans.push(PreeditSpan {
+                    range: byte_pos..char_end,
+                    style,
+                }),
+            }
+        }
+        byte_pos = char_end;
+    }
+
+    spans
+}
+
 /// Preedit text information to be drawn inline by the client.
 extern "C" fn preedit_draw_callback(
     _xim: ffi::XIM,
@@ -96,8 +138,8 @@ extern "C" fn preedit_draw_callback(
     }
 
     // NULL indicate text deletion
-    let mut new_chars = if call_data.text.is_null() {
-        Vec::new()
+    let (mut new_chars, mut new_styles) = if call_data.text.is_null() {
+        (Vec::new(), Vec::new())
     } else {
         let xim_text = unsafe { &mut *(call_data.text) };
         if xim_text.encoding_is_wchar > 0 {
@@ -112,21 +154,41 @@ extern "C" fn preedit_draw_callback(
 
         let new_text = unsafe { CStr::from_ptr(new_text) };
 
-        String::from(new_text.to_str().expect("Invalid UTF-8 String from IME"))
-            .chars()
-            .collect()
+        let new_chars: Vec<char> =
+            String::from(new_text.to_str().expect("Invalid UTF-8 String from IME"))
+                .chars()
+                .collect();
+
+        // The feedback holds one entry for each of the characters, if any.
+        let feedback = if xim_text.feedback.is_null() {
+            &[][..]
+        } else {
+            unsafe { slice::from_raw_parts(xim_text.feedback, xim_text.length as usize) }
+        };
+        let mut new_styles: Vec<Option<PreeditStyle>> = feedback
+            .iter()
+            .map(|&feedback| feedback_to_style(feedback))
+            .collect();
+        new_styles.resize(new_chars.len(), None);
+
+        (new_chars, new_styles)
     };
     let mut old_text_tail = client_data.text.split_off(chg_range.end);
     client_data.text.truncate(chg_range.start);
     client_data.text.append(&mut new_chars);
     client_data.text.append(&mut old_text_tail);
+    let mut old_styles_tail = client_data.styles.split_off(chg_range.end);
+    client_data.styles.truncate(chg_range.start);
+    client_data.styles.append(&mut new_styles);
+    client_data.styles.append(&mut old_styles_tail);
     let cursor_byte_pos = calc_byte_position(&client_data.text, client_data.cursor_pos);
+    let spans = calc_preedit_spans(&client_data.text, &client_data.styles);
 
     client_data
         .event_sender
         .send((
             client_data.window,
-            ImeEvent::Update(client_data.text.iter().collect(), cursor_byte_pos),
+            ImeEvent::Update(client_data.text.iter().collect(), cursor_byte_pos, spans),
         ))
         .expect("failed to send preedit update event");
 }
@@ -143,12 +205,13 @@ extern "C" fn preedit_caret_callback(
     if call_data.direction == ffi::XIMCaretDirection::XIMAbsolutePosition {
         client_data.cursor_pos = call_data.position as usize;
         let cursor_byte_pos = calc_byte_position(&client_data.text, client_data.cursor_pos);
+        let spans = calc_preedit_spans(&client_data.text, &client_data.styles);
 
         client_data
             .event_sender
             .send((
                 client_data.window,
-                ImeEvent::Update(client_data.text.iter().collect(), cursor_byte_pos),
+                ImeEvent::Update(client_data.text.iter().collect(), cursor_byte_pos, spans),
             ))
             .expect("failed to send preedit update event");
     }
@@ -184,6 +247,8 @@ struct ImeContextClientData {
     window: ffi::Window,
     event_sender: ImeEventSender,
     text: Vec<char>,
+    /// The style of each character in `text`.
+    styles: Vec<Option<PreeditStyle>>,
     cursor_pos: usize,
 }
 
@@ -213,6 +278,7 @@ impl ImeContext {
             window,
             event_sender,
             text: Vec::new(),
+            styles: Vec::new(),
             cursor_pos: 0,
         }));
 
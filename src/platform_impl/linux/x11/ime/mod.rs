@@ -11,6 +11,7 @@ use std::sync::{
 };
 
 use super::{ffi, util, XConnection, XError};
+use crate::event::PreeditSpan;
 
 pub use self::context::ImeContextCreationError;
 use self::{
@@ -25,7 +26,7 @@ use self::{
 pub enum ImeEvent {
     Enabled,
     Start,
-    Update(String, usize),
+    Update(String, usize, Vec<PreeditSpan>),
     End,
     Disabled,
 }
@@ -7,6 +7,7 @@ mod event_processor;
 pub mod ffi;
 mod ime;
 mod monitor;
+mod selection;
 pub mod util;
 mod window;
 mod xdisplay;
@@ -66,11 +67,14 @@ use super::{common::xkb_state::KbdState, OsError};
 use crate::{
     error::{OsError as RootOsError, RunLoopError},
     event::{Event, StartCause},
-    event_loop::{ControlFlow, DeviceEvents, EventLoopClosed, EventLoopWindowTarget as RootELW},
+    event_loop::{
+        CompositorCapabilities, CompositorInfo, ControlFlow, DeviceEvents, EventLoopClosed,
+        EventLoopWindowTarget as RootELW,
+    },
     platform::pump_events::PumpStatus,
     platform_impl::{
         platform::{min_timeout, sticky_exit_callback, WindowId},
-        PlatformSpecificWindowBuilderAttributes,
+        ApplicationName, PlatformSpecificWindowBuilderAttributes,
     },
     window::WindowAttributes,
 };
@@ -146,6 +150,7 @@ pub struct EventLoopWindowTarget<T> {
     redraw_sender: WakeSender<WindowId>,
     activation_sender: WakeSender<ActivationToken>,
     device_events: Cell<DeviceEvents>,
+    application_name: RefCell<Option<ApplicationName>>,
     _marker: ::std::marker::PhantomData<T>,
 }
 
@@ -232,6 +237,9 @@ impl<T: 'static> EventLoop<T> {
             .select_xrandr_input(root as ffi::Window)
             .expect("Failed to query XRandR extension");
 
+        // Fill the monitor cache, so the first RandR event can tell which monitors changed.
+        xconn.available_monitors();
+
         let xi2ext = unsafe {
             let mut ext = XExtension::default();
 
@@ -335,11 +343,13 @@ impl<T: 'static> EventLoop<T> {
 
         let kb_state =
             KbdState::from_x11_xkb(xconn.xcb_connection().get_raw_xcb_connection()).unwrap();
+        let lock_keys = xconn.query_lock_keys().unwrap_or_default();
 
         let window_target = EventLoopWindowTarget {
             ime,
             root,
             windows: Default::default(),
+            application_name: Default::default(),
             _marker: ::std::marker::PhantomData,
             ime_sender,
             xconn,
@@ -374,6 +384,7 @@ impl<T: 'static> EventLoop<T> {
             xi2ext,
             xkbext,
             kb_state,
+            lock_keys,
             num_touch: 0,
             held_key_press: None,
             first_touch: None,
@@ -396,7 +407,9 @@ impl<T: 'static> EventLoop<T> {
             .xconn
             .select_xkb_events(
                 0x100, // Use the "core keyboard device"
-                ffi::XkbNewKeyboardNotifyMask | ffi::XkbStateNotifyMask,
+                ffi::XkbNewKeyboardNotifyMask
+                    | ffi::XkbStateNotifyMask
+                    | ffi::XkbIndicatorStateNotifyMask,
             )
             .unwrap();
 
@@ -550,6 +563,10 @@ impl<T: 'static> EventLoop<T> {
             return;
         }
 
+        // NB: `StartCause::Init` is handled as a special case and doesn't need
+        // to be considered here
+        let cause = start_cause(self.control_flow, start, Instant::now());
+
         // False positive / spurious wake ups could lead to us spamming
         // redundant iterations of the event loop with no new events to
         // dispatch.
@@ -557,39 +574,13 @@ impl<T: 'static> EventLoop<T> {
         // If there's no readable event source then we just double check if we
         // have any pending `_receiver` events and if not we return without
         // running a loop iteration.
-        // If we don't have any pending `_receiver`
-        if !self.has_pending() && !self.state.x11_readiness.readable {
+        if is_spurious_wake_up(
+            self.has_pending() || self.state.x11_readiness.readable,
+            cause,
+        ) {
             return;
         }
 
-        // NB: `StartCause::Init` is handled as a special case and doesn't need
-        // to be considered here
-        let cause = match self.control_flow {
-            ControlFlow::Poll => StartCause::Poll,
-            ControlFlow::Wait => StartCause::WaitCancelled {
-                start,
-                requested_resume: None,
-            },
-            ControlFlow::WaitUntil(deadline) => {
-                if Instant::now() < deadline {
-                    StartCause::WaitCancelled {
-                        start,
-                        requested_resume: Some(deadline),
-                    }
-                } else {
-                    StartCause::ResumeTimeReached {
-                        start,
-                        requested_resume: deadline,
-                    }
-                }
-            }
-            // This function shouldn't have to handle any requests to exit
-            // the application (there should be no need to poll for events
-            // if the application has requested to exit) so we consider
-            // it a bug in the backend if we ever see `ExitWithCode` here.
-            ControlFlow::ExitWithCode(_code) => unreachable!(),
-        };
-
         self.single_iteration(&mut callback, cause);
     }
 
@@ -671,7 +662,10 @@ impl<T: 'static> EventLoop<T> {
             for window_id in windows {
                 let window_id = crate::window::WindowId(window_id);
                 sticky_exit_callback(
-                    Event::RedrawRequested(window_id),
+                    Event::RedrawRequested {
+                        window_id,
+                        deadline: None,
+                    },
                     &self.target,
                     &mut control_flow,
                     callback,
@@ -708,7 +702,11 @@ impl<T: 'static> EventLoop<T> {
                     target,
                     control_flow,
                     &mut |event, window_target, control_flow| {
-                        if let Event::RedrawRequested(crate::window::WindowId(wid)) = event {
+                        if let Event::RedrawRequested {
+                            window_id: crate::window::WindowId(wid),
+                            ..
+                        } = event
+                        {
                             wt.redraw_sender.send(wid).unwrap();
                         } else {
                             callback(event, window_target, control_flow);
@@ -739,6 +737,35 @@ impl<T> EventLoopWindowTarget<T> {
         self.device_events.set(allowed);
     }
 
+    pub fn set_application_name(&self, name: ApplicationName) {
+        // Laid out like `WindowBuilderExtX11::with_name` does.
+        for window in self.windows.borrow().values().filter_map(Weak::upgrade) {
+            window.set_class(&name.general, &name.instance);
+        }
+
+        *self.application_name.borrow_mut() = Some(name);
+    }
+
+    pub fn compositor_info(&self) -> CompositorInfo {
+        let atoms = self.xconn.atoms();
+        let name = util::wm_name();
+
+        let capabilities = CompositorCapabilities {
+            supports_ssd: name.is_some(),
+            supports_blur: util::hint_is_supported(atoms[_KDE_NET_WM_BLUR_BEHIND_REGION]),
+            supports_activation: util::hint_is_supported(atoms[_NET_ACTIVE_WINDOW]),
+            // Pointer grabs are part of the core protocol.
+            supports_pointer_constraints: true,
+            supports_fractional_scale: false,
+        };
+
+        CompositorInfo {
+            name,
+            capabilities,
+            globals: Vec::new(),
+        }
+    }
+
     /// Update the device event based on window focus.
     pub fn update_listen_device_events(&self, focus: bool) {
         let device_events = self.device_events.get() == DeviceEvents::Always
@@ -1023,6 +1050,47 @@ struct XExtension {
     first_error_id: c_int,
 }
 
+/// The cause of an iteration started at `now`, for a wait which started at `start`.
+fn start_cause(control_flow: ControlFlow, start: Instant, now: Instant) -> StartCause {
+    match control_flow {
+        ControlFlow::Poll => StartCause::Poll,
+        ControlFlow::Wait => StartCause::WaitCancelled {
+            start,
+            requested_resume: None,
+        },
+        ControlFlow::WaitUntil(deadline) => {
+            if now < deadline {
+                StartCause::WaitCancelled {
+                    start,
+                    requested_resume: Some(deadline),
+                }
+            } else {
+                StartCause::ResumeTimeReached {
+                    start,
+                    requested_resume: deadline,
+                }
+            }
+        }
+        // This function shouldn't have to handle any requests to exit
+        // the application (there should be no need to poll for events
+        // if the application has requested to exit) so we consider
+        // it a bug in the backend if we ever see `ExitWithCode` here.
+        ControlFlow::ExitWithCode(_code) => unreachable!(),
+    }
+}
+
+/// Whether a wake up with the given cause should be skipped, because nothing happened.
+///
+/// Wake ups requested through the control flow are never spurious, otherwise a reached
+/// `WaitUntil` deadline would never be reported and we'd keep polling with a zero timeout.
+fn is_spurious_wake_up(has_events: bool, cause: StartCause) -> bool {
+    !has_events
+        && !matches!(
+            cause,
+            StartCause::ResumeTimeReached { .. } | StartCause::Poll
+        )
+}
+
 fn mkwid(w: xproto::Window) -> crate::window::WindowId {
     crate::window::WindowId(crate::platform_impl::platform::WindowId(w as _))
 }
@@ -1127,3 +1195,77 @@ impl Device {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wait_until_reports_the_deadline_once_reached() {
+        let start = Instant::now();
+        let deadline = start + Duration::from_millis(10);
+
+        assert_eq!(
+            start_cause(ControlFlow::WaitUntil(deadline), start, deadline),
+            StartCause::ResumeTimeReached {
+                start,
+                requested_resume: deadline,
+            }
+        );
+        assert_eq!(
+            start_cause(
+                ControlFlow::WaitUntil(deadline),
+                start,
+                deadline + Duration::from_millis(1)
+            ),
+            StartCause::ResumeTimeReached {
+                start,
+                requested_resume: deadline,
+            }
+        );
+    }
+
+    #[test]
+    fn wait_until_is_cancelled_before_the_deadline() {
+        let start = Instant::now();
+        let deadline = start + Duration::from_millis(10);
+
+        assert_eq!(
+            start_cause(
+                ControlFlow::WaitUntil(deadline),
+                start,
+                start + Duration::from_millis(5)
+            ),
+            StartCause::WaitCancelled {
+                start,
+                requested_resume: Some(deadline),
+            }
+        );
+    }
+
+    #[test]
+    fn requested_wake_ups_are_never_spurious() {
+        let start = Instant::now();
+
+        assert!(!is_spurious_wake_up(false, StartCause::Poll));
+        assert!(!is_spurious_wake_up(
+            false,
+            StartCause::ResumeTimeReached {
+                start,
+                requested_resume: start,
+            }
+        ));
+    }
+
+    #[test]
+    fn cancelled_waits_without_events_are_spurious() {
+        let start = Instant::now();
+        let cause = StartCause::WaitCancelled {
+            start,
+            requested_resume: None,
+        };
+
+        assert!(is_spurious_wake_up(false, cause));
+        assert!(!is_spurious_wake_up(true, cause));
+    }
+}
@@ -5,6 +5,7 @@ use std::sync::Mutex;
 use once_cell::sync::Lazy;
 
 use super::{
+    atoms::*,
     ffi::{
         self, RRCrtc, RRCrtcChangeNotifyMask, RRMode, RROutputPropertyNotifyMask,
         RRScreenChangeNotifyMask, True, Window, XRRCrtcInfo, XRRModeInfo, XRRScreenResources,
@@ -15,6 +16,7 @@ use crate::{
     dpi::{PhysicalPosition, PhysicalSize},
     platform_impl::{MonitorHandle as PlatformMonitorHandle, VideoMode as PlatformVideoMode},
 };
+use x11rb::protocol::xproto;
 
 // Used for testing. This should always be committed as false.
 const DISABLE_MONITOR_LIST_CACHING: bool = false;
@@ -63,10 +65,18 @@ pub struct MonitorHandle {
     pub(crate) id: RRCrtc,
     /// The name of the monitor
     pub(crate) name: String,
+    /// The manufacturer ID from the EDID
+    make: Option<String>,
+    /// The model name from the EDID
+    model: Option<String>,
     /// The size of the monitor
     dimensions: (u32, u32),
+    /// The physical size of the monitor in millimeters
+    size_mm: (u32, u32),
     /// The position of the monitor in the X screen
     position: (i32, i32),
+    /// The part of the monitor not covered by panels
+    work_area: Option<((i32, i32), (u32, u32))>,
     /// If the monitor is the primary one
     primary: bool,
     /// The refresh rate used by monitor.
@@ -122,8 +132,11 @@ impl MonitorHandle {
         id: RRCrtc,
         crtc: *mut XRRCrtcInfo,
         primary: bool,
+        desktop_work_area: Option<((i32, i32), (u32, u32))>,
     ) -> Option<Self> {
-        let (name, scale_factor, video_modes) = unsafe { xconn.get_output_info(resources, crtc)? };
+        let (name, scale_factor, video_modes, size_mm) =
+            unsafe { xconn.get_output_info(resources, crtc)? };
+        let (make, model) = xconn.get_output_make_and_model(unsafe { *(*crtc).outputs.offset(0) });
         let dimensions = unsafe { ((*crtc).width, (*crtc).height) };
         let position = unsafe { ((*crtc).x, (*crtc).y) };
 
@@ -138,13 +151,30 @@ impl MonitorHandle {
 
         let rect = util::AaRect::new(position, dimensions);
 
+        // `_NET_WORKAREA` covers the whole X screen, so clip it to the monitor.
+        let work_area = desktop_work_area.and_then(|((x, y), (width, height))| {
+            let left = x.max(position.0);
+            let top = y.max(position.1);
+            let right = (x + width as i32).min(position.0 + dimensions.0 as i32);
+            let bottom = (y + height as i32).min(position.1 + dimensions.1 as i32);
+            if right > left && bottom > top {
+                Some(((left, top), ((right - left) as u32, (bottom - top) as u32)))
+            } else {
+                None
+            }
+        });
+
         Some(MonitorHandle {
             id,
             name,
+            make,
+            model,
+            size_mm,
             refresh_rate_millihertz,
             scale_factor,
             dimensions,
             position,
+            work_area,
             primary,
             rect,
             video_modes,
@@ -155,9 +185,13 @@ impl MonitorHandle {
         MonitorHandle {
             id: 0,
             name: "<dummy monitor>".into(),
+            make: None,
+            model: None,
             scale_factor: 1.0,
             dimensions: (1, 1),
+            size_mm: (0, 0),
             position: (0, 0),
+            work_area: None,
             refresh_rate_millihertz: None,
             primary: true,
             rect: util::AaRect::new((0, 0), (1, 1)),
@@ -174,6 +208,26 @@ impl MonitorHandle {
         Some(self.name.clone())
     }
 
+    pub fn friendly_name(&self) -> Option<String> {
+        self.model.clone()
+    }
+
+    pub fn make(&self) -> Option<String> {
+        self.make.clone()
+    }
+
+    pub fn model(&self) -> Option<String> {
+        self.model.clone()
+    }
+
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        // Projectors and some virtual outputs report a size of zero.
+        match self.size_mm {
+            (0, _) | (_, 0) => None,
+            size => Some(size),
+        }
+    }
+
     #[inline]
     pub fn native_identifier(&self) -> u32 {
         self.id as _
@@ -187,6 +241,11 @@ impl MonitorHandle {
         self.position.into()
     }
 
+    pub fn work_area(&self) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+        self.work_area
+            .map(|(position, size)| (position.into(), size.into()))
+    }
+
     pub fn refresh_rate_millihertz(&self) -> Option<u32> {
         self.refresh_rate_millihertz
     }
@@ -222,17 +281,30 @@ impl XConnection {
             None => return default.to_owned(),
         };
 
-        let mut largest_overlap = 0;
-        let mut matched_monitor = default;
-        for monitor in &monitors {
-            let overlapping_area = window_rect.get_overlapping_area(&monitor.rect);
-            if overlapping_area > largest_overlap {
-                largest_overlap = overlapping_area;
-                matched_monitor = monitor;
-            }
-        }
+        monitor_for_rect(&monitors, window_rect)
+            .unwrap_or(default)
+            .to_owned()
+    }
 
-        matched_monitor.to_owned()
+    /// Query the work area of the current desktop from the window manager.
+    fn query_work_area(&self, root: xproto::Window) -> Option<((i32, i32), (u32, u32))> {
+        let atoms = self.atoms();
+        let cardinal = xproto::Atom::from(xproto::AtomEnum::CARDINAL);
+
+        // The work area is given as `x, y, width, height` for every desktop.
+        let work_areas: Vec<util::Cardinal> = self
+            .get_property(root, atoms[_NET_WORKAREA], cardinal)
+            .ok()?;
+        let desktop = self
+            .get_property::<util::Cardinal>(root, atoms[_NET_CURRENT_DESKTOP], cardinal)
+            .ok()
+            .and_then(|desktop| desktop.first().copied())
+            .unwrap_or(0) as usize;
+
+        match work_areas.chunks_exact(4).nth(desktop) {
+            Some(&[x, y, width, height]) => Some(((x as i32, y as i32), (width, height))),
+            _ => None,
+        }
     }
 
     fn query_monitor_list(&self) -> Vec<MonitorHandle> {
@@ -257,6 +329,7 @@ impl XConnection {
             let mut has_primary = false;
 
             let primary = (self.xrandr.XRRGetOutputPrimary)(self.display, root as ffi::Window);
+            let desktop_work_area = self.query_work_area(root);
             let mut available = Vec::with_capacity((*resources).ncrtc as usize);
 
             for crtc_index in 0..(*resources).ncrtc {
@@ -266,9 +339,14 @@ impl XConnection {
                 if is_active {
                     let is_primary = *(*crtc).outputs.offset(0) == primary;
                     has_primary |= is_primary;
-                    if let Some(monitor_id) =
-                        MonitorHandle::new(self, resources, crtc_id, crtc, is_primary)
-                    {
+                    if let Some(monitor_id) = MonitorHandle::new(
+                        self,
+                        resources,
+                        crtc_id,
+                        crtc,
+                        is_primary,
+                        desktop_work_area,
+                    ) {
                         available.push(monitor_id)
                     }
                 }
@@ -339,3 +417,74 @@ impl XConnection {
         Ok(event_offset)
     }
 }
+
+/// Picks the monitor with the largest part of the window, preferring the primary monitor when
+/// the window is split evenly. Returns `None` when the window isn't on any monitor.
+fn monitor_for_rect(
+    monitors: &[MonitorHandle],
+    window_rect: util::AaRect,
+) -> Option<&MonitorHandle> {
+    let mut largest_overlap = 0;
+    let mut matched_monitor: Option<&MonitorHandle> = None;
+    for monitor in monitors {
+        let overlapping_area = window_rect.get_overlapping_area(&monitor.rect);
+        let is_tie = overlapping_area == largest_overlap
+            && overlapping_area > 0
+            && monitor.primary
+            && !matched_monitor.map_or(false, |matched| matched.primary);
+        if overlapping_area > largest_overlap || is_tie {
+            largest_overlap = overlapping_area;
+            matched_monitor = Some(monitor);
+        }
+    }
+
+    matched_monitor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(name: &str, position: (i32, i32), primary: bool) -> MonitorHandle {
+        MonitorHandle {
+            name: name.into(),
+            primary,
+            rect: util::AaRect::new(position, (1920, 1080)),
+            ..MonitorHandle::dummy()
+        }
+    }
+
+    fn name_for_rect(monitors: &[MonitorHandle], position: (i32, i32)) -> Option<&str> {
+        let window_rect = util::AaRect::new(position, (800, 600));
+        monitor_for_rect(monitors, window_rect).map(|monitor| monitor.name.as_str())
+    }
+
+    #[test]
+    fn monitor_for_rect_picks_the_largest_overlap() {
+        let monitors = [
+            monitor("DP-1", (0, 0), true),
+            monitor("DP-2", (1920, 0), false),
+        ];
+
+        assert_eq!(name_for_rect(&monitors, (100, 100)), Some("DP-1"));
+        // 300 pixels on the first monitor, 500 on the second.
+        assert_eq!(name_for_rect(&monitors, (1620, 100)), Some("DP-2"));
+    }
+
+    #[test]
+    fn monitor_for_rect_prefers_the_primary_monitor_on_a_tie() {
+        let monitors = [
+            monitor("DP-1", (0, 0), false),
+            monitor("DP-2", (1920, 0), true),
+        ];
+
+        assert_eq!(name_for_rect(&monitors, (1520, 100)), Some("DP-2"));
+    }
+
+    #[test]
+    fn monitor_for_rect_ignores_monitors_without_overlap() {
+        let monitors = [monitor("DP-1", (0, 0), true)];
+
+        assert_eq!(name_for_rect(&monitors, (4000, 4000)), None);
+    }
+}
@@ -0,0 +1,432 @@
+//! X11 selection handling, for the primary selection and the clipboard.
+//!
+//! A selection is owned by a window, and transferred between clients through window
+//! properties, see this URL:
+//! <https://tronche.com/gui/x/icccm/sec-2.html>
+
+use super::{atoms::*, X11Error, XConnection};
+
+use std::collections::VecDeque;
+
+use x11rb::connection::{Connection as _, RequestConnection as _};
+use x11rb::protocol::xproto::{self, ConnectionExt as _};
+
+use crate::event::WindowEvent;
+use crate::event_loop::AsyncRequestSerial;
+
+/// Targets describing the selection itself rather than its data.
+const META_TARGETS: [&[u8]; 4] = [b"TARGETS", b"MULTIPLE", b"TIMESTAMP", b"SAVE_TARGETS"];
+
+/// The data of a selection for each target.
+type SelectionData = Vec<(xproto::Atom, Vec<u8>)>;
+
+/// The selections owned by the windows of a connection, and the pending requests for them.
+#[derive(Debug, Default)]
+pub(crate) struct Selections {
+    primary: Selection,
+    clipboard: Selection,
+
+    /// The requests waiting for a `SelectionNotify`, oldest first.
+    requests: VecDeque<PendingRequest>,
+
+    /// The properties interned so far to receive converted selections in.
+    ///
+    /// Each pending request of a window uses its own property, so the owner answering one
+    /// request can't overwrite the data of another.
+    properties: Vec<xproto::Atom>,
+}
+
+/// A selection set by the application.
+#[derive(Debug, Default)]
+struct Selection {
+    /// The window owning the selection, and the data it holds for each target.
+    owned: Option<(xproto::Window, SelectionData)>,
+}
+
+/// A conversion a window asked the owner of a selection for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PendingRequest {
+    requestor: xproto::Window,
+    selection: xproto::Atom,
+    target: xproto::Atom,
+
+    /// The property of the requestor the owner stores the converted selection in.
+    property: xproto::Atom,
+
+    request: SelectionRequest,
+}
+
+/// What a window asked the owner of a selection for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SelectionRequest {
+    /// The text of the primary selection.
+    PrimaryText(AsyncRequestSerial),
+
+    /// The data of the clipboard in a given target.
+    ClipboardData(AsyncRequestSerial),
+
+    /// The targets the clipboard is available in.
+    ClipboardTargets(AsyncRequestSerial),
+}
+
+impl Selections {
+    fn get_mut(&mut self, selection: xproto::Atom, atoms: &Atoms) -> Option<&mut Selection> {
+        if selection == xproto::AtomEnum::PRIMARY.into() {
+            Some(&mut self.primary)
+        } else if selection == atoms[Clipboard] {
+            Some(&mut self.clipboard)
+        } else {
+            None
+        }
+    }
+
+    /// A property of `requestor` that no pending request uses.
+    fn free_property(&self, requestor: xproto::Window) -> Option<xproto::Atom> {
+        self.properties.iter().copied().find(|&property| {
+            !self
+                .requests
+                .iter()
+                .any(|pending| pending.requestor == requestor && pending.property == property)
+        })
+    }
+
+    /// Take the pending request a `SelectionNotify` answers.
+    ///
+    /// A refused conversion doesn't name the property, so the request is found by its target
+    /// instead.
+    fn take_request(
+        &mut self,
+        requestor: xproto::Window,
+        selection: xproto::Atom,
+        target: xproto::Atom,
+        property: xproto::Atom,
+    ) -> Option<PendingRequest> {
+        let index = self.requests.iter().position(|pending| {
+            pending.requestor == requestor
+                && pending.selection == selection
+                && if property == x11rb::NONE {
+                    pending.target == target
+                } else {
+                    pending.property == property
+                }
+        })?;
+        self.requests.remove(index)
+    }
+}
+
+impl XConnection {
+    /// Make `window` the owner of `selection` holding `data` for each target.
+    pub(crate) fn set_selection(
+        &self,
+        window: xproto::Window,
+        selection: xproto::Atom,
+        data: SelectionData,
+    ) -> Result<(), X11Error> {
+        self.xcb_connection()
+            .set_selection_owner(window, selection, self.timestamp())?
+            .check()?;
+
+        // The server ignores the request if the timestamp is older than the current owner's.
+        let owner = self
+            .xcb_connection()
+            .get_selection_owner(selection)?
+            .reply()?
+            .owner;
+        if owner == window {
+            let mut selections = self.selections.lock().unwrap();
+            if let Some(selection) = selections.get_mut(selection, self.atoms()) {
+                selection.owned = Some((window, data));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ask the owner of `selection` to convert it to `target` for `window`.
+    pub(crate) fn request_selection(
+        &self,
+        window: xproto::Window,
+        selection: xproto::Atom,
+        target: xproto::Atom,
+        request: SelectionRequest,
+    ) -> Result<(), X11Error> {
+        // Keep the lock until the request is queued, so its property isn't handed out twice.
+        let mut selections = self.selections.lock().unwrap();
+        let property = match selections.free_property(window) {
+            Some(property) => property,
+            None => {
+                let name = format!("_WINIT_SELECTION_{}", selections.properties.len());
+                let property = self
+                    .xcb_connection()
+                    .intern_atom(false, name.as_bytes())?
+                    .reply()?
+                    .atom;
+                selections.properties.push(property);
+                property
+            }
+        };
+
+        self.xcb_connection()
+            .convert_selection(window, selection, target, property, self.timestamp())?
+            .check()?;
+        selections.requests.push_back(PendingRequest {
+            requestor: window,
+            selection,
+            target,
+            property,
+            request,
+        });
+
+        Ok(())
+    }
+
+    /// Read the converted selection for `window`, after the owner answered with
+    /// `SelectionNotify`, and return the event delivering it.
+    ///
+    /// The data is `None` if the owner refused the conversion or transfers it incrementally.
+    pub(crate) fn read_selection(
+        &self,
+        window: xproto::Window,
+        selection: xproto::Atom,
+        target: xproto::Atom,
+        property: xproto::Atom,
+    ) -> Option<WindowEvent> {
+        let atoms = self.atoms();
+        let request = self
+            .selections
+            .lock()
+            .unwrap()
+            .take_request(window, selection, target, property)?
+            .request;
+
+        let reply = if property == x11rb::NONE {
+            None
+        } else {
+            self.xcb_connection()
+                .get_property(true, window, property, xproto::AtomEnum::ANY, 0, u32::MAX)
+                .ok()
+                .and_then(|cookie| cookie.reply().ok())
+                // Incremental transfers aren't supported.
+                .filter(|reply| reply.type_ != atoms[Incr])
+        };
+
+        let event = match request {
+            SelectionRequest::PrimaryText(serial) => WindowEvent::PrimarySelectionText {
+                serial,
+                text: reply.map(|reply| String::from_utf8_lossy(&reply.value).into_owned()),
+            },
+            SelectionRequest::ClipboardData(serial) => WindowEvent::ClipboardData {
+                serial,
+                data: reply.map(|reply| reply.value),
+            },
+            SelectionRequest::ClipboardTargets(serial) => WindowEvent::ClipboardMimeTypes {
+                serial,
+                mime_types: reply
+                    .and_then(|reply| reply.value32().map(|targets| targets.collect::<Vec<_>>()))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|target| self.atom_name(target))
+                    .filter(|name| !META_TARGETS.contains(&name.as_bytes()))
+                    .collect(),
+            },
+        };
+
+        Some(event)
+    }
+
+    /// Answer the request of another client for a selection we own.
+    pub(crate) fn answer_selection_request(
+        &self,
+        request: &xproto::SelectionRequestEvent,
+    ) -> Result<(), X11Error> {
+        // Obsolete clients don't name a property, the target must be used instead.
+        let property = if request.property == x11rb::NONE {
+            request.target
+        } else {
+            request.property
+        };
+
+        let converted = {
+            let mut selections = self.selections.lock().unwrap();
+            match selections
+                .get_mut(request.selection, self.atoms())
+                .and_then(|selection| selection.owned.as_ref())
+            {
+                Some((owner, data)) if *owner == request.owner => {
+                    self.convert_selection(request, property, data)?
+                }
+                _ => false,
+            }
+        };
+
+        let notify = xproto::SelectionNotifyEvent {
+            response_type: xproto::SELECTION_NOTIFY_EVENT,
+            sequence: 0,
+            time: request.time,
+            requestor: request.requestor,
+            selection: request.selection,
+            target: request.target,
+            property: if converted { property } else { x11rb::NONE },
+        };
+        self.xcb_connection()
+            .send_event(
+                false,
+                request.requestor,
+                xproto::EventMask::NO_EVENT,
+                notify,
+            )?
+            .ignore_error();
+        self.xcb_connection().flush()?;
+
+        Ok(())
+    }
+
+    /// Store the selection in the `property` of the requestor, returning `false` if the target
+    /// isn't available.
+    fn convert_selection(
+        &self,
+        request: &xproto::SelectionRequestEvent,
+        property: xproto::Atom,
+        data: &[(xproto::Atom, Vec<u8>)],
+    ) -> Result<bool, X11Error> {
+        let atoms = self.atoms();
+
+        if request.target == atoms[Targets] {
+            let targets: Vec<_> = std::iter::once(atoms[Targets])
+                .chain(data.iter().map(|&(target, _)| target))
+                .collect();
+            self.change_property(
+                request.requestor,
+                property,
+                xproto::AtomEnum::ATOM.into(),
+                xproto::PropMode::REPLACE,
+                &targets,
+            )?
+            .ignore_error();
+            return Ok(true);
+        }
+
+        let bytes = match data.iter().find(|&&(target, _)| target == request.target) {
+            Some((_, bytes)) => bytes,
+            None => return Ok(false),
+        };
+
+        // Larger data would have to be transferred incrementally, which isn't supported.
+        let max_len = self.xcb_connection().maximum_request_bytes() - 24;
+        if bytes.len() > max_len {
+            log::warn!("The selection is too large to be transferred");
+            return Ok(false);
+        }
+
+        self.change_property(
+            request.requestor,
+            property,
+            request.target,
+            xproto::PropMode::REPLACE,
+            bytes,
+        )?
+        .ignore_error();
+        Ok(true)
+    }
+
+    /// Forget the data of `selection` after `window` lost the ownership of it.
+    pub(crate) fn clear_selection(&self, window: xproto::Window, selection: xproto::Atom) {
+        let mut selections = self.selections.lock().unwrap();
+        if let Some(selection) = selections.get_mut(selection, self.atoms()) {
+            if matches!(&selection.owned, Some((owner, _)) if *owner == window) {
+                selection.owned = None;
+            }
+        }
+    }
+
+    /// Get the atom for the name of a target, such as a mime type.
+    pub(crate) fn target_atom(&self, name: &str) -> Result<xproto::Atom, X11Error> {
+        Ok(self
+            .xcb_connection()
+            .intern_atom(false, name.as_bytes())?
+            .reply()?
+            .atom)
+    }
+
+    /// Get the name of a target.
+    fn atom_name(&self, atom: xproto::Atom) -> Option<String> {
+        let reply = self
+            .xcb_connection()
+            .get_atom_name(atom)
+            .ok()?
+            .reply()
+            .ok()?;
+        String::from_utf8(reply.name).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: xproto::Window = 1;
+    const CLIPBOARD: xproto::Atom = 10;
+    const TARGETS: xproto::Atom = 20;
+    const TEXT: xproto::Atom = 21;
+
+    fn pending(
+        selections: &mut Selections,
+        target: xproto::Atom,
+        request: SelectionRequest,
+    ) -> xproto::Atom {
+        let property = selections.free_property(WINDOW).unwrap();
+        selections.requests.push_back(PendingRequest {
+            requestor: WINDOW,
+            selection: CLIPBOARD,
+            target,
+            property,
+            request,
+        });
+        property
+    }
+
+    #[test]
+    fn pending_requests_use_distinct_properties() {
+        let mut selections = Selections {
+            properties: vec![100, 101],
+            ..Default::default()
+        };
+        let targets = SelectionRequest::ClipboardTargets(AsyncRequestSerial::get());
+        let data = SelectionRequest::ClipboardData(AsyncRequestSerial::get());
+
+        let targets_property = pending(&mut selections, TARGETS, targets);
+        let data_property = pending(&mut selections, TEXT, data);
+        assert_ne!(targets_property, data_property);
+        assert_eq!(selections.free_property(WINDOW), None);
+        assert_eq!(selections.free_property(WINDOW + 1), Some(100));
+
+        // The answers are matched by property, whatever order they come in.
+        let answer = selections.take_request(WINDOW, CLIPBOARD, TEXT, data_property);
+        assert_eq!(answer.map(|pending| pending.request), Some(data));
+        assert_eq!(selections.free_property(WINDOW), Some(data_property));
+
+        let answer = selections.take_request(WINDOW, CLIPBOARD, TARGETS, targets_property);
+        assert_eq!(answer.map(|pending| pending.request), Some(targets));
+        assert!(selections.requests.is_empty());
+    }
+
+    #[test]
+    fn refused_requests_are_matched_by_target() {
+        let mut selections = Selections {
+            properties: vec![100, 101],
+            ..Default::default()
+        };
+        let targets = SelectionRequest::ClipboardTargets(AsyncRequestSerial::get());
+        let data = SelectionRequest::ClipboardData(AsyncRequestSerial::get());
+        pending(&mut selections, TARGETS, targets);
+        pending(&mut selections, TEXT, data);
+
+        let answer = selections.take_request(WINDOW, CLIPBOARD, TEXT, x11rb::NONE);
+        assert_eq!(answer.map(|pending| pending.request), Some(data));
+        assert_eq!(
+            selections.take_request(WINDOW, xproto::AtomEnum::PRIMARY.into(), TARGETS, 100),
+            None
+        );
+        assert_eq!(selections.requests.len(), 1);
+    }
+}
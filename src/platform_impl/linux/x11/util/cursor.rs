@@ -8,15 +8,24 @@ use super::*;
 
 impl XConnection {
     pub fn set_cursor_icon(&self, window: xproto::Window, cursor: Option<CursorIcon>) {
-        let cursor = *self
+        let cursor = self.cached_cursor(cursor);
+
+        self.update_cursor(window, cursor)
+            .expect("Failed to set cursor");
+    }
+
+    /// Load the cursor into the cache ahead of its first use.
+    pub fn preload_cursor(&self, cursor: Option<CursorIcon>) {
+        self.cached_cursor(cursor);
+    }
+
+    fn cached_cursor(&self, cursor: Option<CursorIcon>) -> ffi::Cursor {
+        *self
             .cursor_cache
             .lock()
             .unwrap()
             .entry(cursor)
-            .or_insert_with(|| self.get_cursor(cursor));
-
-        self.update_cursor(window, cursor)
-            .expect("Failed to set cursor");
+            .or_insert_with(|| self.get_cursor(cursor))
     }
 
     fn create_empty_cursor(&self) -> ffi::Cursor {
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use x11rb::properties::WmSizeHints;
+
 use super::*;
 
 #[derive(Debug)]
@@ -20,6 +22,47 @@ impl From<bool> for StateOperation {
     }
 }
 
+bitflags! {
+    /// The fields of `WM_NORMAL_HINTS` winit is allowed to set.
+    ///
+    /// All hints are sent by default. An empty set means no size hints are sent at all.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct SizeHints: u8 {
+        /// `PMinSize`, set from the minimum inner size.
+        const MIN_SIZE = 1 << 0;
+        /// `PMaxSize`, set from the maximum inner size.
+        const MAX_SIZE = 1 << 1;
+        /// `PBaseSize`, set from the base size.
+        const BASE_SIZE = 1 << 2;
+        /// `PResizeInc`, set from the resize increments.
+        const RESIZE_INCREMENTS = 1 << 3;
+    }
+}
+
+impl Default for SizeHints {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl SizeHints {
+    /// Clear the hints which aren't part of this set.
+    pub(crate) fn filter(self, normal_hints: &mut WmSizeHints) {
+        if !self.contains(SizeHints::MIN_SIZE) {
+            normal_hints.min_size = None;
+        }
+        if !self.contains(SizeHints::MAX_SIZE) {
+            normal_hints.max_size = None;
+        }
+        if !self.contains(SizeHints::BASE_SIZE) {
+            normal_hints.base_size = None;
+        }
+        if !self.contains(SizeHints::RESIZE_INCREMENTS) {
+            normal_hints.size_increment = None;
+        }
+    }
+}
+
 /// X window type. Maps directly to
 /// [`_NET_WM_WINDOW_TYPE`](https://specifications.freedesktop.org/wm-spec/wm-spec-1.5.html).
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
@@ -216,3 +259,54 @@ impl XConnection {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_hints() -> WmSizeHints {
+        WmSizeHints {
+            min_size: Some((100, 100)),
+            max_size: Some((800, 600)),
+            base_size: Some((10, 10)),
+            size_increment: Some((8, 16)),
+            ..WmSizeHints::default()
+        }
+    }
+
+    #[test]
+    fn filter_keeps_all_hints_by_default() {
+        let mut normal_hints = all_hints();
+        SizeHints::default().filter(&mut normal_hints);
+
+        assert_eq!(normal_hints.min_size, Some((100, 100)));
+        assert_eq!(normal_hints.max_size, Some((800, 600)));
+        assert_eq!(normal_hints.base_size, Some((10, 10)));
+        assert_eq!(normal_hints.size_increment, Some((8, 16)));
+    }
+
+    #[test]
+    fn filter_clears_excluded_hints() {
+        let mut normal_hints = all_hints();
+        (SizeHints::MIN_SIZE | SizeHints::RESIZE_INCREMENTS).filter(&mut normal_hints);
+
+        assert_eq!(normal_hints.min_size, Some((100, 100)));
+        assert_eq!(normal_hints.max_size, None);
+        assert_eq!(normal_hints.base_size, None);
+        assert_eq!(normal_hints.size_increment, Some((8, 16)));
+    }
+
+    #[test]
+    fn filter_with_no_hints_clears_everything() {
+        let mut normal_hints = all_hints();
+        normal_hints.win_gravity = Some(xproto::Gravity::STATIC);
+        SizeHints::empty().filter(&mut normal_hints);
+
+        assert_eq!(normal_hints.min_size, None);
+        assert_eq!(normal_hints.max_size, None);
+        assert_eq!(normal_hints.base_size, None);
+        assert_eq!(normal_hints.size_increment, None);
+        // Only the size hints are affected.
+        assert_eq!(normal_hints.win_gravity, Some(xproto::Gravity::STATIC));
+    }
+}
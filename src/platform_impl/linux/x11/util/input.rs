@@ -1,5 +1,10 @@
 use std::{slice, str};
-use x11rb::protocol::xinput::{self, ConnectionExt as _};
+use x11rb::protocol::{
+    xinput::{self, ConnectionExt as _},
+    xkb::{self, ConnectionExt as _},
+};
+
+use crate::keyboard::LockKeyState;
 
 use super::*;
 
@@ -67,6 +72,36 @@ impl XConnection {
         }
     }
 
+    /// Query the lock key indicators of the core keyboard.
+    pub fn query_lock_keys(&self) -> Result<LockKeyState, X11Error> {
+        let atoms = self.atoms();
+        let indicators = [
+            (LockKeyState::CAPS_LOCK, atoms[CapsLock]),
+            (LockKeyState::NUM_LOCK, atoms[NumLock]),
+            (LockKeyState::SCROLL_LOCK, atoms[ScrollLock]),
+        ];
+
+        // Send all the requests before waiting for the replies.
+        let mut cookies = Vec::with_capacity(indicators.len());
+        for (lock_key, indicator) in indicators {
+            let cookie = self.xcb_connection().xkb_get_named_indicator(
+                xkb::ID::USE_CORE_KBD.into(),
+                xkb::LedClass::DFLT_XI_CLASS,
+                xkb::ID::DFLT_XI_ID,
+                indicator,
+            )?;
+            cookies.push((lock_key, cookie));
+        }
+
+        let mut lock_keys = LockKeyState::empty();
+        for (lock_key, cookie) in cookies {
+            let reply = cookie.reply()?;
+            lock_keys.set(lock_key, reply.found && reply.on);
+        }
+
+        Ok(lock_keys)
+    }
+
     pub fn query_pointer(
         &self,
         window: xproto::Window,
@@ -1,11 +1,13 @@
-use std::{env, slice, str::FromStr};
+use std::{env, ptr, slice, str::FromStr};
 
 use super::{
-    ffi::{CurrentTime, RRCrtc, RRMode, Success, XRRCrtcInfo, XRRScreenResources},
+    ffi::{CurrentTime, RRCrtc, RRMode, RROutput, Success, XRRCrtcInfo, XRRScreenResources},
     *,
 };
 use crate::platform_impl::platform::x11::monitor;
-use crate::{dpi::validate_scale_factor, platform_impl::platform::x11::VideoMode};
+use crate::{
+    dpi::validate_scale_factor, monitor::decode_pnp_id, platform_impl::platform::x11::VideoMode,
+};
 
 /// Represents values of `WINIT_HIDPI_FACTOR`.
 pub enum EnvVarDPI {
@@ -53,11 +55,12 @@ impl XConnection {
         }
         None
     }
+    #[allow(clippy::type_complexity)]
     pub unsafe fn get_output_info(
         &self,
         resources: *mut XRRScreenResources,
         crtc: *mut XRRCrtcInfo,
-    ) -> Option<(String, f64, Vec<VideoMode>)> {
+    ) -> Option<(String, f64, Vec<VideoMode>, (u32, u32))> {
         let output_info =
             (self.xrandr.XRRGetOutputInfo)(self.display, resources, *(*crtc).outputs.offset(0));
         if output_info.is_null() {
@@ -147,8 +150,51 @@ impl XConnection {
             }
         };
 
+        let size_mm = ((*output_info).mm_width as _, (*output_info).mm_height as _);
+
         (self.xrandr.XRRFreeOutputInfo)(output_info);
-        Some((name, scale_factor, modes))
+        Some((name, scale_factor, modes, size_mm))
+    }
+
+    /// Reads the make and model of the monitor connected to `output` from its EDID.
+    pub fn get_output_make_and_model(&self, output: RROutput) -> (Option<String>, Option<String>) {
+        let edid_atom = self.atoms()[Edid];
+        let mut actual_type = 0;
+        let mut actual_format = 0;
+        let mut nitems = 0;
+        let mut bytes_after = 0;
+        let mut data = ptr::null_mut();
+        let status = unsafe {
+            (self.xrandr.XRRGetOutputProperty)(
+                self.display,
+                output,
+                edid_atom as ffi::Atom,
+                0,
+                // The length is given in 32-bit units, the base EDID block is 128 bytes long.
+                EDID_LENGTH as c_long / 4,
+                ffi::False,
+                ffi::False,
+                ffi::AnyPropertyType as ffi::Atom,
+                &mut actual_type,
+                &mut actual_format,
+                &mut nitems,
+                &mut bytes_after,
+                &mut data,
+            )
+        };
+
+        if status != Success as i32 || data.is_null() {
+            return (None, None);
+        }
+
+        let edid = if actual_format == 8 {
+            unsafe { slice::from_raw_parts(data, nitems as usize) }.to_vec()
+        } else {
+            Vec::new()
+        };
+        unsafe { (self.xlib.XFree)(data as _) };
+
+        parse_edid(&edid)
     }
 
     #[must_use]
@@ -211,3 +257,73 @@ impl XConnection {
         }
     }
 }
+
+const EDID_LENGTH: usize = 128;
+
+/// Extracts the manufacturer ID and the monitor name from an EDID base block.
+fn parse_edid(edid: &[u8]) -> (Option<String>, Option<String>) {
+    const HEADER: [u8; 8] = [0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00];
+    const MONITOR_NAME_TAG: u8 = 0xfc;
+
+    if edid.len() < EDID_LENGTH || edid[..8] != HEADER {
+        return (None, None);
+    }
+
+    let make = decode_pnp_id(u16::from_be_bytes([edid[8], edid[9]]));
+
+    // A name is stored in one of the four 18 bytes display descriptors.
+    let model = edid[54..126].chunks_exact(18).find_map(|descriptor| {
+        if descriptor[..3] != [0, 0, 0] || descriptor[3] != MONITOR_NAME_TAG {
+            return None;
+        }
+
+        let name = &descriptor[5..];
+        let end = name.iter().position(|&c| c == b'\n').unwrap_or(name.len());
+        let name = String::from_utf8_lossy(&name[..end]).trim_end().to_owned();
+        (!name.is_empty()).then_some(name)
+    });
+
+    (make, model)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The base block of the EDID of a Dell U2720Q.
+    const EDID: [u8; EDID_LENGTH] = [
+        0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x10, 0xac, 0xf6, 0xa0, 0x4c, 0x4c, 0x4a,
+        0x30, 0x1c, 0x1e, 0x01, 0x04, 0xb5, 0x3c, 0x22, 0x78, 0x3b, 0xef, 0xcc, 0xab, 0x51, 0x3a,
+        0xb5, 0x24, 0x0f, 0x50, 0x54, 0xa5, 0x4b, 0x00, 0x71, 0x4f, 0x81, 0x80, 0xa9, 0xc0, 0xa9,
+        0x40, 0xd1, 0xc0, 0xe1, 0x00, 0x01, 0x01, 0x01, 0x01, 0x4d, 0xd0, 0x00, 0xa0, 0xf0, 0x70,
+        0x3e, 0x80, 0x30, 0x20, 0x35, 0x00, 0x54, 0x4f, 0x21, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00,
+        0xff, 0x00, 0x36, 0x46, 0x31, 0x4c, 0x4b, 0x4c, 0x33, 0x0a, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x00, 0x00, 0x00, 0xfc, 0x00, 0x44, 0x45, 0x4c, 0x4c, 0x20, 0x55, 0x32, 0x37, 0x32, 0x30,
+        0x51, 0x0a, 0x20, 0x00, 0x00, 0x00, 0xfd, 0x00, 0x18, 0x4b, 0x1e, 0x8c, 0x3c, 0x00, 0x0a,
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x01, 0x14,
+    ];
+
+    #[test]
+    fn parse_edid_reads_make_and_model() {
+        let (make, model) = parse_edid(&EDID);
+        assert_eq!(make.as_deref(), Some("DEL"));
+        assert_eq!(model.as_deref(), Some("DELL U2720Q"));
+    }
+
+    #[test]
+    fn parse_edid_rejects_truncated_edid() {
+        assert_eq!(parse_edid(&EDID[..EDID_LENGTH / 2]), (None, None));
+        assert_eq!(parse_edid(&[]), (None, None));
+    }
+
+    #[test]
+    fn parse_edid_without_name_descriptor_has_no_model() {
+        let mut edid = EDID;
+        // Turn the monitor name descriptor into a dummy descriptor.
+        edid[93] = 0x10;
+
+        let (make, model) = parse_edid(&edid);
+        assert_eq!(make.as_deref(), Some("DEL"));
+        assert_eq!(model, None);
+    }
+}
@@ -32,6 +32,10 @@ pub fn wm_name_is_one_of(names: &[&str]) -> bool {
     }
 }
 
+pub fn wm_name() -> Option<String> {
+    WM_NAME.lock().unwrap().clone()
+}
+
 impl XConnection {
     pub fn update_cached_wm_info(&self, root: xproto::Window) {
         *SUPPORTED_HINTS.lock().unwrap() = self.get_supported_hints(root);
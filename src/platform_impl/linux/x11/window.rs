@@ -15,27 +15,31 @@ use x11rb::{
 };
 use x11rb::{
     properties::{WmHints, WmSizeHints, WmSizeHintsSpecification},
-    protocol::xinput,
+    protocol::{
+        shape::{self, ConnectionExt as _},
+        xinput,
+    },
 };
 
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize, Position, Size},
     error::{ExternalError, NotSupportedError, OsError as RootOsError},
     event_loop::AsyncRequestSerial,
+    keyboard::LockKeyState,
     platform_impl::{
         x11::{atoms::*, MonitorHandle as X11MonitorHandle, WakeSender, X11Error},
         Fullscreen, MonitorHandle as PlatformMonitorHandle, OsError,
         PlatformSpecificWindowBuilderAttributes, VideoMode as PlatformVideoMode,
     },
     window::{
-        CursorGrabMode, CursorIcon, Icon, ImePurpose, ResizeDirection, Theme, UserAttentionType,
-        WindowAttributes, WindowButtons, WindowLevel,
+        CursorGrabMode, CursorIcon, DragAction, HitTestRegion, Icon, ImePurpose, Rect,
+        ResizeDirection, Theme, UserAttentionType, WindowAttributes, WindowButtons, WindowLevel,
     },
 };
 
 use super::{
-    ffi, util, CookieResultExt, EventLoopWindowTarget, ImeRequest, ImeSender, VoidCookie, WindowId,
-    XConnection,
+    ffi, selection::SelectionRequest, util, CookieResultExt, EventLoopWindowTarget, ImeRequest,
+    ImeSender, VoidCookie, WindowId, XConnection,
 };
 
 #[derive(Debug)]
@@ -63,6 +67,9 @@ pub struct SharedState {
     pub base_size: Option<Size>,
     pub visibility: Visibility,
     pub has_focus: bool,
+    pub key_repeat: bool,
+    // Logical rects of the input shape, reapplied when the scale factor changes
+    pub input_region: Option<Vec<Rect>>,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -74,6 +81,15 @@ pub enum Visibility {
 }
 
 impl SharedState {
+    /// Remember the position to restore when leaving fullscreen.
+    ///
+    /// Only the windowed position is remembered, not the one of a previous fullscreen mode.
+    fn save_restore_position(&mut self, old_fullscreen: &Option<Fullscreen>, position: (i32, i32)) {
+        if old_fullscreen.is_none() {
+            self.restore_position = Some(position);
+        }
+    }
+
     fn new(last_monitor: X11MonitorHandle, window_attributes: &WindowAttributes) -> Mutex<Self> {
         let visibility = if window_attributes.visible {
             Visibility::YesWait
@@ -103,6 +119,8 @@ impl SharedState {
             resize_increments: None,
             base_size: None,
             has_focus: false,
+            key_repeat: true,
+            input_region: None,
         })
     }
 }
@@ -119,6 +137,9 @@ pub(crate) struct UnownedWindow {
     cursor_grabbed_mode: Mutex<CursorGrabMode>,
     #[allow(clippy::mutex_atomic)]
     cursor_visible: Mutex<bool>,
+    size_hints: Mutex<util::SizeHints>,
+    /// Startup notification which is completed once the window is first shown.
+    pending_activation_token: Mutex<Option<String>>,
     ime_sender: Mutex<ImeSender>,
     pub shared_state: Mutex<SharedState>,
     redraw_sender: WakeSender<WindowId>,
@@ -313,6 +334,8 @@ impl UnownedWindow {
             cursor: Default::default(),
             cursor_grabbed_mode: Mutex::new(CursorGrabMode::None),
             cursor_visible: Mutex::new(true),
+            size_hints: Mutex::new(pl_attribs.x11_size_hints),
+            pending_activation_token: Mutex::new(None),
             ime_sender: Mutex::new(event_loop.ime_sender.clone()),
             shared_state: SharedState::new(guessed_monitor, &window_attrs),
             redraw_sender: event_loop.redraw_sender.clone(),
@@ -346,7 +369,12 @@ impl UnownedWindow {
 
             // WM_CLASS must be set *before* mapping the window, as per ICCCM!
             {
-                let (class, instance) = if let Some(name) = pl_attribs.name {
+                let (class, instance) = if let Some((instance, class)) = pl_attribs.x11_class {
+                    (class, instance)
+                } else if let Some(name) = pl_attribs
+                    .name
+                    .or_else(|| event_loop.application_name.borrow().clone())
+                {
                     (name.instance, name.general)
                 } else {
                     let class = env::args_os()
@@ -364,15 +392,7 @@ impl UnownedWindow {
                     (instance, class)
                 };
 
-                let class = format!("{instance}\0{class}\0");
-                leap!(xconn.change_property(
-                    window.xwindow,
-                    xproto::Atom::from(xproto::AtomEnum::WM_CLASS),
-                    xproto::Atom::from(xproto::AtomEnum::STRING),
-                    xproto::PropMode::REPLACE,
-                    class.as_bytes(),
-                ))
-                .ignore_error();
+                leap!(window.set_class_inner(&instance, &class)).ignore_error();
             }
 
             if let Some(flusher) = leap!(window.set_pid()) {
@@ -404,7 +424,7 @@ impl UnownedWindow {
             shared_state.resize_increments = window_attrs.resize_increments;
             shared_state.base_size = pl_attribs.base_size;
 
-            let normal_hints = WmSizeHints {
+            let mut normal_hints = WmSizeHints {
                 position: position.map(|PhysicalPosition { x, y }| {
                     (WmSizeHintsSpecification::UserSpecified, x, y)
                 }),
@@ -424,6 +444,7 @@ impl UnownedWindow {
                 aspect: None,
                 win_gravity: None,
             };
+            pl_attribs.x11_size_hints.filter(&mut normal_hints);
             leap!(leap!(normal_hints.set(
                 xconn.xcb_connection(),
                 window.xwindow as xproto::Window,
@@ -520,9 +541,14 @@ impl UnownedWindow {
             leap!(window.set_window_level_inner(window_attrs.window_level)).ignore_error();
         }
 
-        // Remove the startup notification if we have one.
+        // Remove the startup notification if we have one. The launcher keeps showing its busy
+        // indicator until then, so for an invisible window wait until it's first shown.
         if let Some(startup) = pl_attribs.activation_token.as_ref() {
-            leap!(xconn.remove_activation_token(xwindow, &startup._token));
+            if window_attrs.visible {
+                leap!(xconn.remove_activation_token(xwindow, &startup._token));
+            } else {
+                *window.pending_activation_token.get_mut().unwrap() = Some(startup._token.clone());
+            }
         }
 
         // We never want to give the user a broken window, since by then, it's too late to handle.
@@ -723,7 +749,15 @@ impl UnownedWindow {
                     Fullscreen::Borderless(Some(PlatformMonitorHandle::X(monitor))) => {
                         (None, monitor)
                     }
-                    Fullscreen::Borderless(None) => (None, self.current_monitor()),
+                    Fullscreen::Borderless(None) => {
+                        // The cached monitor is only updated once the window manager moved the
+                        // window, so look it up from the current geometry.
+                        let window_rect = util::AaRect::new(
+                            self.outer_position_physical(),
+                            self.outer_size().into(),
+                        );
+                        (None, self.xconn.get_monitor_for_window(Some(window_rect)))
+                    }
                     #[cfg(wayland_platform)]
                     _ => unreachable!(),
                 };
@@ -765,7 +799,8 @@ impl UnownedWindow {
                 }
 
                 let window_position = self.outer_position_physical();
-                self.shared_state_lock().restore_position = Some(window_position);
+                self.shared_state_lock()
+                    .save_restore_position(&old_fullscreen, window_position);
                 let monitor_origin: (i32, i32) = monitor.position().into();
                 self.set_position_inner(monitor_origin.0, monitor_origin.1)
                     .expect_then_ignore_error("Failed to set window position");
@@ -930,6 +965,11 @@ impl UnownedWindow {
         self.invalidate_cached_frame_extents();
     }
 
+    #[inline]
+    pub fn restored_inner_size(&self) -> Option<PhysicalSize<u32>> {
+        None
+    }
+
     fn set_title_inner(&self, title: &str) -> Result<VoidCookie<'_>, X11Error> {
         let atoms = self.xconn.atoms();
 
@@ -962,9 +1002,31 @@ impl UnownedWindow {
             .expect("Failed to set window title");
     }
 
+    fn set_class_inner(&self, instance: &str, class: &str) -> Result<VoidCookie<'_>, X11Error> {
+        let class = format!("{instance}\0{class}\0");
+        self.xconn.change_property(
+            self.xwindow,
+            xproto::Atom::from(xproto::AtomEnum::WM_CLASS),
+            xproto::Atom::from(xproto::AtomEnum::STRING),
+            xproto::PropMode::REPLACE,
+            class.as_bytes(),
+        )
+    }
+
+    #[inline]
+    pub fn set_class(&self, instance: &str, class: &str) {
+        self.set_class_inner(instance, class)
+            .expect_then_ignore_error("Failed to set WM_CLASS");
+
+        self.xconn.flush_requests().expect("Failed to set WM_CLASS");
+    }
+
     #[inline]
     pub fn set_transparent(&self, _transparent: bool) {}
 
+    #[inline]
+    pub fn set_opaque_region(&self, _region: Option<&[Rect]>) {}
+
     fn set_decorations_inner(&self, decorations: bool) -> Result<VoidCookie<'_>, X11Error> {
         self.shared_state_lock().is_decorated = decorations;
         let mut hints = self.xconn.get_motif_hints(self.xwindow);
@@ -1082,6 +1144,15 @@ impl UnownedWindow {
                 .flush_requests()
                 .expect("Failed to call XMapRaised");
             shared_state.visibility = Visibility::YesWait;
+
+            if let Some(startup_id) = self.pending_activation_token.lock().unwrap().take() {
+                if let Err(err) = self
+                    .xconn
+                    .remove_activation_token(self.xwindow, &startup_id)
+                {
+                    warn!("Failed to complete the startup notification: {err}");
+                }
+            }
         } else {
             self.xconn
                 .xcb_connection()
@@ -1250,6 +1321,7 @@ impl UnownedWindow {
         )?
         .reply()?;
         callback(&mut normal_hints);
+        self.size_hints.lock().unwrap().filter(&mut normal_hints);
         normal_hints
             .set(
                 self.xconn.xcb_connection(),
@@ -1292,6 +1364,43 @@ impl UnownedWindow {
         self.set_max_inner_size_physical(physical_dimensions);
     }
 
+    #[inline]
+    pub fn set_size_hints(&self, size_hints: util::SizeHints) {
+        *self.size_hints.lock().unwrap() = size_hints;
+
+        let scale_factor = self.scale_factor();
+        let window_size = Size::from(self.inner_size());
+        let shared_state = self.shared_state_lock();
+        let min_size = if shared_state.is_resizable {
+            shared_state.min_inner_size
+        } else {
+            Some(window_size)
+        };
+        let max_size = if shared_state.is_resizable {
+            shared_state.max_inner_size
+        } else {
+            min_size
+        };
+        let min_size = min_size.map(|size| cast_size_to_hint(size, scale_factor));
+        let max_size = max_size.map(|size| cast_size_to_hint(size, scale_factor));
+        let resize_increments = shared_state
+            .resize_increments
+            .map(|size| cast_size_to_hint(size, scale_factor));
+        let base_size = shared_state
+            .base_size
+            .map(|size| cast_size_to_hint(size, scale_factor));
+        drop(shared_state);
+
+        // Resend all the hints, so the ones which were previously filtered out are restored.
+        self.update_normal_hints(|normal_hints| {
+            normal_hints.min_size = min_size;
+            normal_hints.max_size = max_size;
+            normal_hints.size_increment = resize_increments;
+            normal_hints.base_size = base_size;
+        })
+        .expect("Failed to call `XSetWMNormalHints`");
+    }
+
     #[inline]
     pub fn resize_increments(&self) -> Option<PhysicalSize<u32>> {
         WmSizeHints::get(
@@ -1337,6 +1446,12 @@ impl UnownedWindow {
         })
         .expect("Failed to update normal hints");
 
+        if let Some(rects) = shared_state.input_region.as_deref() {
+            if let Err(err) = self.apply_input_region(Some(rects), new_scale_factor) {
+                warn!("Failed to update the input region: {err}");
+            }
+        }
+
         let new_width = (width as f64 * scale_factor).round() as u32;
         let new_height = (height as f64 * scale_factor).round() as u32;
 
@@ -1419,6 +1534,13 @@ impl UnownedWindow {
         }
     }
 
+    #[inline]
+    pub fn preload_cursors(&self, cursors: &[CursorIcon]) {
+        for &cursor in cursors {
+            self.xconn.preload_cursor(Some(cursor));
+        }
+    }
+
     #[inline]
     pub fn set_cursor_grab(&self, mode: CursorGrabMode) -> Result<(), ExternalError> {
         let mut grabbed_lock = self.cursor_grabbed_mode.lock().unwrap();
@@ -1437,54 +1559,7 @@ impl UnownedWindow {
             CursorGrabMode::None => self.xconn.flush_requests().map_err(|err| {
                 ExternalError::Os(os_error!(OsError::XError(X11Error::Xlib(err).into())))
             }),
-            CursorGrabMode::Confined => {
-                let result = {
-                    self.xconn
-                        .xcb_connection()
-                        .grab_pointer(
-                            true as _,
-                            self.xwindow,
-                            xproto::EventMask::BUTTON_PRESS
-                                | xproto::EventMask::BUTTON_RELEASE
-                                | xproto::EventMask::ENTER_WINDOW
-                                | xproto::EventMask::LEAVE_WINDOW
-                                | xproto::EventMask::POINTER_MOTION
-                                | xproto::EventMask::POINTER_MOTION_HINT
-                                | xproto::EventMask::BUTTON1_MOTION
-                                | xproto::EventMask::BUTTON2_MOTION
-                                | xproto::EventMask::BUTTON3_MOTION
-                                | xproto::EventMask::BUTTON4_MOTION
-                                | xproto::EventMask::BUTTON5_MOTION
-                                | xproto::EventMask::KEYMAP_STATE,
-                            xproto::GrabMode::ASYNC,
-                            xproto::GrabMode::ASYNC,
-                            self.xwindow,
-                            0u32,
-                            x11rb::CURRENT_TIME,
-                        )
-                        .expect("Failed to call `grab_pointer`")
-                        .reply()
-                        .expect("Failed to receive reply from `grab_pointer`")
-                };
-
-                match result.status {
-                    xproto::GrabStatus::SUCCESS => Ok(()),
-                    xproto::GrabStatus::ALREADY_GRABBED => {
-                        Err("Cursor could not be confined: already confined by another client")
-                    }
-                    xproto::GrabStatus::INVALID_TIME => {
-                        Err("Cursor could not be confined: invalid time")
-                    }
-                    xproto::GrabStatus::NOT_VIEWABLE => {
-                        Err("Cursor could not be confined: confine location not viewable")
-                    }
-                    xproto::GrabStatus::FROZEN => {
-                        Err("Cursor could not be confined: frozen by another client")
-                    }
-                    _ => unreachable!(),
-                }
-                .map_err(|err| ExternalError::Os(os_error!(OsError::XMisc(err))))
-            }
+            CursorGrabMode::Confined => self.grab_pointer(),
             CursorGrabMode::Locked => {
                 return Err(ExternalError::NotSupported(NotSupportedError::new()));
             }
@@ -1497,6 +1572,74 @@ impl UnownedWindow {
         result
     }
 
+    /// Confine the pointer to the window with an active grab.
+    fn grab_pointer(&self) -> Result<(), ExternalError> {
+        let result = {
+            self.xconn
+                .xcb_connection()
+                .grab_pointer(
+                    true as _,
+                    self.xwindow,
+                    xproto::EventMask::BUTTON_PRESS
+                        | xproto::EventMask::BUTTON_RELEASE
+                        | xproto::EventMask::ENTER_WINDOW
+                        | xproto::EventMask::LEAVE_WINDOW
+                        | xproto::EventMask::POINTER_MOTION
+                        | xproto::EventMask::POINTER_MOTION_HINT
+                        | xproto::EventMask::BUTTON1_MOTION
+                        | xproto::EventMask::BUTTON2_MOTION
+                        | xproto::EventMask::BUTTON3_MOTION
+                        | xproto::EventMask::BUTTON4_MOTION
+                        | xproto::EventMask::BUTTON5_MOTION
+                        | xproto::EventMask::KEYMAP_STATE,
+                    xproto::GrabMode::ASYNC,
+                    xproto::GrabMode::ASYNC,
+                    self.xwindow,
+                    0u32,
+                    x11rb::CURRENT_TIME,
+                )
+                .expect("Failed to call `grab_pointer`")
+                .reply()
+                .expect("Failed to receive reply from `grab_pointer`")
+        };
+
+        match result.status {
+            xproto::GrabStatus::SUCCESS => Ok(()),
+            xproto::GrabStatus::ALREADY_GRABBED => {
+                Err("Cursor could not be confined: already confined by another client")
+            }
+            xproto::GrabStatus::INVALID_TIME => Err("Cursor could not be confined: invalid time"),
+            xproto::GrabStatus::NOT_VIEWABLE => {
+                Err("Cursor could not be confined: confine location not viewable")
+            }
+            xproto::GrabStatus::FROZEN => {
+                Err("Cursor could not be confined: frozen by another client")
+            }
+            _ => unreachable!(),
+        }
+        .map_err(|err| ExternalError::Os(os_error!(OsError::XMisc(err))))
+    }
+
+    /// Grab the pointer again if the user confined it, as the server releases the grab while
+    /// the window isn't viewable.
+    ///
+    /// Returns `false` if the grab couldn't be restored, in which case it's reset.
+    pub(crate) fn restore_cursor_grab(&self) -> bool {
+        let mut grabbed_lock = self.cursor_grabbed_mode.lock().unwrap();
+        if *grabbed_lock != CursorGrabMode::Confined {
+            return true;
+        }
+
+        match self.grab_pointer() {
+            Ok(()) => true,
+            Err(err) => {
+                warn!("Failed to restore the cursor grab: {err}");
+                *grabbed_lock = CursorGrabMode::None;
+                false
+            }
+        }
+    }
+
     #[inline]
     pub fn set_cursor_visible(&self, visible: bool) {
         #[allow(clippy::mutex_atomic)]
@@ -1544,6 +1687,62 @@ impl UnownedWindow {
         Err(ExternalError::NotSupported(NotSupportedError::new()))
     }
 
+    #[inline]
+    pub fn set_input_region(&self, region: Option<&[Rect]>) -> Result<(), ExternalError> {
+        let scale_factor = self.scale_factor();
+        self.shared_state_lock().input_region = region.map(<[_]>::to_vec);
+        self.apply_input_region(region, scale_factor)
+    }
+
+    fn apply_input_region(
+        &self,
+        region: Option<&[Rect]>,
+        scale_factor: f64,
+    ) -> Result<(), ExternalError> {
+        let conn = self.xconn.xcb_connection();
+        let cookie = match region {
+            // Removing the input shape makes it follow the bounding shape of the window again.
+            None => conn.shape_mask(
+                shape::SO::SET,
+                shape::SK::INPUT,
+                self.xwindow,
+                0,
+                0,
+                x11rb::NONE,
+            ),
+            Some(rects) => {
+                let rects: Vec<_> = rects
+                    .iter()
+                    .map(|rect| {
+                        let position: PhysicalPosition<i16> =
+                            rect.position.to_physical(scale_factor);
+                        let size: PhysicalSize<u16> = rect.size.to_physical(scale_factor);
+                        xproto::Rectangle {
+                            x: position.x,
+                            y: position.y,
+                            width: size.width,
+                            height: size.height,
+                        }
+                    })
+                    .collect();
+                conn.shape_rectangles(
+                    shape::SO::SET,
+                    shape::SK::INPUT,
+                    xproto::ClipOrdering::UNSORTED,
+                    self.xwindow,
+                    0,
+                    0,
+                    &rects,
+                )
+            }
+        };
+
+        cookie
+            .map_err(|e| ExternalError::Os(os_error!(OsError::XError(X11Error::from(e).into()))))?
+            .check()
+            .map_err(|e| ExternalError::Os(os_error!(OsError::XError(X11Error::from(e).into()))))
+    }
+
     /// Moves the window while it is being dragged.
     pub fn drag_window(&self) -> Result<(), ExternalError> {
         self.drag_initiate(util::MOVERESIZE_MOVE)
@@ -1563,6 +1762,9 @@ impl UnownedWindow {
         })
     }
 
+    #[inline]
+    pub fn set_hittest_regions(&self, _regions: &[HitTestRegion]) {}
+
     /// Initiates a drag operation while the left mouse button is pressed.
     fn drag_initiate(&self, action: isize) -> Result<(), ExternalError> {
         let pointer = self
@@ -1637,6 +1839,9 @@ impl UnownedWindow {
     #[inline]
     pub fn set_ime_purpose(&self, _purpose: ImePurpose) {}
 
+    #[inline]
+    pub fn set_ime_surrounding_text(&self, _text: &str, _cursor: usize, _anchor: usize) {}
+
     #[inline]
     pub fn focus_window(&self) {
         let atoms = self.xconn.atoms();
@@ -1710,6 +1915,96 @@ impl UnownedWindow {
         Ok(token)
     }
 
+    #[inline]
+    pub fn set_primary_selection_text(&self, text: &str) {
+        let atoms = self.xconn.atoms();
+        let data = vec![
+            (atoms[UTF8_STRING], text.as_bytes().to_vec()),
+            (atoms[TextPlainUtf8], text.as_bytes().to_vec()),
+        ];
+        self.xconn
+            .set_selection(self.xwindow, xproto::AtomEnum::PRIMARY.into(), data)
+            .expect("Failed to set the primary selection");
+    }
+
+    #[inline]
+    pub fn request_primary_selection_text(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        let serial = AsyncRequestSerial::get();
+        self.xconn
+            .request_selection(
+                self.xwindow,
+                xproto::AtomEnum::PRIMARY.into(),
+                self.xconn.atoms()[UTF8_STRING],
+                SelectionRequest::PrimaryText(serial),
+            )
+            .expect("Failed to request the primary selection");
+        Ok(serial)
+    }
+
+    #[inline]
+    pub fn set_clipboard_data(&self, data: Vec<(String, Vec<u8>)>) {
+        let atoms = self.xconn.atoms();
+        let mut targets = Vec::with_capacity(data.len());
+        for (mime_type, bytes) in data {
+            let target = self
+                .xconn
+                .target_atom(&mime_type)
+                .expect("Failed to intern the mime type");
+
+            // Most X11 applications only ask for UTF-8 text with the legacy target.
+            if target == atoms[TextPlainUtf8] {
+                targets.push((atoms[UTF8_STRING], bytes.clone()));
+            }
+            targets.push((target, bytes));
+        }
+
+        self.xconn
+            .set_selection(self.xwindow, atoms[Clipboard], targets)
+            .expect("Failed to set the clipboard");
+    }
+
+    #[inline]
+    pub fn request_clipboard_data(
+        &self,
+        mime_type: &str,
+    ) -> Result<AsyncRequestSerial, NotSupportedError> {
+        let serial = AsyncRequestSerial::get();
+        let target = self
+            .xconn
+            .target_atom(mime_type)
+            .expect("Failed to intern the mime type");
+        self.xconn
+            .request_selection(
+                self.xwindow,
+                self.xconn.atoms()[Clipboard],
+                target,
+                SelectionRequest::ClipboardData(serial),
+            )
+            .expect("Failed to request the clipboard");
+        Ok(serial)
+    }
+
+    #[inline]
+    pub fn request_clipboard_mime_types(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        let serial = AsyncRequestSerial::get();
+        let atoms = self.xconn.atoms();
+        self.xconn
+            .request_selection(
+                self.xwindow,
+                atoms[Clipboard],
+                atoms[Targets],
+                SelectionRequest::ClipboardTargets(serial),
+            )
+            .expect("Failed to request the clipboard");
+        Ok(serial)
+    }
+
+    #[inline]
+    pub fn set_drag_accept(&self, _mime_type: Option<&str>) {}
+
+    #[inline]
+    pub fn set_drag_action(&self, _action: DragAction) {}
+
     #[inline]
     pub fn request_activation_token(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
         let serial = AsyncRequestSerial::get();
@@ -1736,6 +2031,21 @@ impl UnownedWindow {
         // TODO timer
     }
 
+    #[inline]
+    pub fn pre_present_notify_with_damage(
+        &self,
+        _damage: &[(PhysicalPosition<i32>, PhysicalSize<u32>)],
+    ) {
+        self.pre_present_notify();
+    }
+
+    #[inline]
+    pub fn flush(&self) -> Result<(), ExternalError> {
+        self.xconn.flush_requests().map_err(|err| {
+            ExternalError::Os(os_error!(OsError::XError(X11Error::Xlib(err).into())))
+        })
+    }
+
     #[inline]
     pub fn raw_window_handle(&self) -> RawWindowHandle {
         let mut window_handle = XlibWindowHandle::empty();
@@ -1761,6 +2071,24 @@ impl UnownedWindow {
         self.shared_state_lock().has_focus
     }
 
+    #[inline]
+    pub fn set_key_repeat(&self, key_repeat: bool) {
+        self.shared_state_lock().key_repeat = key_repeat;
+    }
+
+    #[inline]
+    pub fn lock_key_state(&self) -> LockKeyState {
+        self.xconn.query_lock_keys().unwrap_or_else(|err| {
+            warn!("Failed to query the lock key state: {err}");
+            LockKeyState::default()
+        })
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.has_focus()
+    }
+
     pub fn title(&self) -> String {
         String::new()
     }
@@ -1787,3 +2115,24 @@ fn cast_size_to_hint(size: Size, scale_factor: f64) -> (i32, i32) {
         Size::Logical(size) => size.to_physical::<i32>(scale_factor).into(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaving_fullscreen_restores_the_windowed_position() {
+        let mut shared_state =
+            SharedState::new(X11MonitorHandle::dummy(), &WindowAttributes::default())
+                .into_inner()
+                .unwrap();
+
+        // Entering fullscreen from a window.
+        shared_state.save_restore_position(&None, (10, 20));
+        // Switching between fullscreen modes, at the origin of the monitor.
+        let borderless = Some(Fullscreen::Borderless(None));
+        shared_state.save_restore_position(&borderless, (0, 0));
+
+        assert_eq!(shared_state.restore_position.take(), Some((10, 20)));
+    }
+}
@@ -10,7 +10,7 @@ use std::{
 
 use crate::window::CursorIcon;
 
-use super::{atoms::Atoms, ffi};
+use super::{atoms::Atoms, ffi, selection::Selections};
 use x11rb::{connection::Connection, protocol::xproto, xcb_ffi::XCBConnection};
 
 /// A connection to an X server.
@@ -40,6 +40,9 @@ pub(crate) struct XConnection {
 
     pub latest_error: Mutex<Option<XError>>,
     pub cursor_cache: Mutex<HashMap<Option<CursorIcon>, ffi::Cursor>>,
+
+    /// The selections owned by the windows of this connection.
+    pub(super) selections: Mutex<Selections>,
 }
 
 unsafe impl Send for XConnection {}
@@ -104,6 +107,7 @@ impl XConnection {
             timestamp: AtomicU32::new(0),
             latest_error: Mutex::new(None),
             cursor_cache: Default::default(),
+            selections: Default::default(),
         })
     }
 
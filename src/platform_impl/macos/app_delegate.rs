@@ -1,6 +1,7 @@
+use std::path::PathBuf;
 use std::ptr::NonNull;
 
-use icrate::Foundation::NSObject;
+use icrate::Foundation::{NSArray, NSObject, NSURL};
 use objc2::declare::{IvarBool, IvarEncode};
 use objc2::rc::Id;
 use objc2::runtime::AnyObject;
@@ -8,6 +9,8 @@ use objc2::{declare_class, msg_send, msg_send_id, mutability, ClassType};
 
 use super::app_state::AppState;
 use super::appkit::NSApplicationActivationPolicy;
+use super::event::EventWrapper;
+use crate::event::Event;
 
 declare_class!(
     #[derive(Debug)]
@@ -52,6 +55,29 @@ declare_class!(
             );
         }
 
+        #[method(application:openURLs:)]
+        fn open_urls(&self, _sender: &AnyObject, urls: &NSArray<NSURL>) {
+            trace_scope!("application:openURLs:");
+            let mut files = Vec::new();
+            let mut other_urls = Vec::new();
+            for url in urls.iter() {
+                if url.isFileURL() {
+                    files.extend(url.path().map(|path| PathBuf::from(path.to_string())));
+                } else {
+                    other_urls.extend(url.absoluteString().map(|url| url.to_string()));
+                }
+            }
+
+            // This is also called before `applicationDidFinishLaunching:` when the application is
+            // launched to open something, the queued events are delivered once it is running.
+            if !files.is_empty() {
+                AppState::queue_event(EventWrapper::StaticEvent(Event::OpenFiles(files)));
+            }
+            if !other_urls.is_empty() {
+                AppState::queue_event(EventWrapper::StaticEvent(Event::OpenUrls(other_urls)));
+            }
+        }
+
         #[method(applicationWillTerminate:)]
         fn will_terminate(&self, _sender: Option<&AnyObject>) {
             trace_scope!("applicationWillTerminate:");
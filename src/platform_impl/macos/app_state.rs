@@ -1,8 +1,9 @@
 use std::{
     cell::{RefCell, RefMut},
     collections::VecDeque,
+    ffi::c_void,
     fmt::{self, Debug},
-    mem,
+    mem, ptr,
     rc::{Rc, Weak},
     sync::{
         atomic::{AtomicBool, Ordering},
@@ -12,6 +13,7 @@ use std::{
 };
 
 use core_foundation::runloop::{CFRunLoopGetMain, CFRunLoopWakeUp};
+use core_graphics::display::CGDirectDisplayID;
 use icrate::Foundation::{is_main_thread, NSSize};
 use objc2::rc::autoreleasepool;
 use once_cell::sync::Lazy;
@@ -19,12 +21,14 @@ use once_cell::sync::Lazy;
 use super::appkit::{NSApp, NSApplication, NSApplicationActivationPolicy, NSEvent};
 use crate::{
     dpi::LogicalSize,
-    event::{Event, InnerSizeWriter, StartCause, WindowEvent},
+    event::{Event, InnerSizeWriter, MonitorEvent, StartCause, WindowEvent},
     event_loop::{ControlFlow, EventLoopWindowTarget as RootWindowTarget},
+    monitor::MonitorHandle as RootMonitorHandle,
     platform_impl::platform::{
         event::{EventProxy, EventWrapper},
         event_loop::PanicInfo,
-        menu,
+        ffi, menu,
+        monitor::MonitorHandle,
         observer::EventLoopWaker,
         util::Never,
         window::WinitWindow,
@@ -480,6 +484,9 @@ impl AppState {
 
         HANDLER.set_launched();
         HANDLER.waker().start();
+        unsafe {
+            ffi::CGDisplayRegisterReconfigurationCallback(display_reconfigured, ptr::null_mut())
+        };
         if create_default_menu {
             // The menubar initialization should be before the `NewEvents` event, to allow
             // overriding of the default menu even if it's created
@@ -564,8 +571,10 @@ impl AppState {
         // Redraw request might come out of order from the OS.
         // -> Don't go back into the callback when our callstack originates from there
         if !HANDLER.in_callback.swap(true, Ordering::AcqRel) {
-            HANDLER
-                .handle_nonuser_event(EventWrapper::StaticEvent(Event::RedrawRequested(window_id)));
+            HANDLER.handle_nonuser_event(EventWrapper::StaticEvent(Event::RedrawRequested {
+                window_id,
+                deadline: None,
+            }));
             HANDLER.set_in_callback(false);
 
             // `pump_events` will request to stop immediately _after_ dispatching RedrawRequested events
@@ -616,8 +625,10 @@ impl AppState {
         }
 
         for window_id in HANDLER.should_redraw() {
-            HANDLER
-                .handle_nonuser_event(EventWrapper::StaticEvent(Event::RedrawRequested(window_id)));
+            HANDLER.handle_nonuser_event(EventWrapper::StaticEvent(Event::RedrawRequested {
+                window_id,
+                deadline: None,
+            }));
         }
 
         HANDLER.handle_nonuser_event(EventWrapper::StaticEvent(Event::AboutToWait));
@@ -652,6 +663,33 @@ fn min_timeout(a: Option<Instant>, b: Option<Instant>) -> Option<Instant> {
     })
 }
 
+/// Called on the main thread by CoreGraphics, once before and once after each change of the
+/// display configuration.
+extern "C" fn display_reconfigured(
+    display: CGDirectDisplayID,
+    flags: ffi::CGDisplayChangeSummaryFlags,
+    _user_info: *mut c_void,
+) {
+    if flags & ffi::kCGDisplayBeginConfigurationFlag != 0 {
+        return;
+    }
+
+    let event = if flags & ffi::kCGDisplayAddFlag != 0 {
+        MonitorEvent::Connected
+    } else if flags & ffi::kCGDisplayRemoveFlag != 0 {
+        MonitorEvent::Disconnected
+    } else {
+        return;
+    };
+
+    AppState::queue_event(EventWrapper::StaticEvent(Event::MonitorEvent {
+        monitor: RootMonitorHandle {
+            inner: MonitorHandle::new(display),
+        },
+        event,
+    }));
+}
+
 /// A hack to make activation of multiple windows work when creating them before
 /// `applicationDidFinishLaunching:` / `Event::Event::NewEvents(StartCause::Init)`.
 ///
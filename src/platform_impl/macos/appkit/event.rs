@@ -96,9 +96,15 @@ extern_methods!(
         #[method(pressedMouseButtons)]
         pub fn pressedMouseButtons() -> NSUInteger;
 
+        #[method(doubleClickInterval)]
+        pub fn doubleClickInterval() -> NSTimeInterval;
+
         #[method(modifierFlags)]
         pub fn modifierFlags(&self) -> NSEventModifierFlags;
 
+        #[method(modifierFlags)]
+        pub fn currentModifierFlags() -> NSEventModifierFlags;
+
         #[method(type)]
         pub fn type_(&self) -> NSEventType;
 
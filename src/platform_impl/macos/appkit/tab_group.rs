@@ -27,5 +27,8 @@ extern_methods!(
 
         #[method(setSelectedWindow:)]
         pub fn setSelectedWindow(&self, window: &NSWindow);
+
+        #[method(isTabBarVisible)]
+        pub fn isTabBarVisible(&self) -> bool;
     }
 );
@@ -169,6 +169,9 @@ extern_methods!(
         #[method(setMovableByWindowBackground:)]
         pub(crate) fn setMovableByWindowBackground(&self, val: bool);
 
+        #[method(isMovableByWindowBackground)]
+        pub(crate) fn isMovableByWindowBackground(&self) -> bool;
+
         #[method(setLevel:)]
         pub(crate) fn setLevel(&self, level: NSWindowLevel);
 
@@ -214,6 +217,12 @@ extern_methods!(
         #[method(selectNextTab)]
         pub(crate) fn selectNextTab(&self);
 
+        #[method(mergeAllWindows:)]
+        pub(crate) fn mergeAllWindows(&self, sender: Option<&AnyObject>);
+
+        #[method(toggleTabBar:)]
+        pub(crate) fn toggleTabBar(&self, sender: Option<&AnyObject>);
+
         #[method_id(tabbingIdentifier)]
         pub(crate) fn tabbingIdentifier(&self) -> Id<NSString>;
 
@@ -223,6 +232,12 @@ extern_methods!(
         #[method(isDocumentEdited)]
         pub(crate) fn isDocumentEdited(&self) -> bool;
 
+        #[method(setRepresentedFilename:)]
+        pub(crate) fn setRepresentedFilename(&self, filename: &NSString);
+
+        #[method_id(representedFilename)]
+        pub(crate) fn representedFilename(&self) -> Id<NSString>;
+
         #[method(close)]
         pub(crate) fn close(&self);
 
@@ -13,7 +13,8 @@ use crate::{
     dpi::LogicalSize,
     event::{ElementState, Event, KeyEvent, Modifiers},
     keyboard::{
-        Key, KeyCode, KeyLocation, ModifiersKeys, ModifiersState, NativeKey, NativeKeyCode,
+        Key, KeyCode, KeyLocation, LockKeyState, ModifiersKeys, ModifiersState, NativeKey,
+        NativeKeyCode,
     },
     platform::{modifier_supplement::KeyEventExtModifierSupplement, scancode::KeyCodeExtScancode},
     platform_impl::platform::{
@@ -41,6 +42,14 @@ pub(crate) enum EventProxy {
 pub struct KeyEventExtra {
     pub text_with_all_modifiers: Option<SmolStr>,
     pub key_without_modifiers: Key,
+    pub raw_scancode: u32,
+}
+
+impl KeyEventExtra {
+    #[inline]
+    pub fn raw_scancode(&self) -> Option<u32> {
+        Some(self.raw_scancode)
+    }
 }
 
 impl KeyEventExtModifierSupplement for KeyEvent {
@@ -204,6 +213,7 @@ pub(crate) fn create_key_event(
         platform_specific: KeyEventExtra {
             key_without_modifiers,
             text_with_all_modifiers,
+            raw_scancode: scancode as u32,
         },
     }
 }
@@ -360,6 +370,15 @@ pub(super) fn event_mods(event: &NSEvent) -> Modifiers {
     }
 }
 
+pub(super) fn lock_keys(flags: NSEventModifierFlags) -> LockKeyState {
+    let mut lock_keys = LockKeyState::empty();
+    lock_keys.set(
+        LockKeyState::CAPS_LOCK,
+        flags.contains(NSEventModifierFlags::NSAlphaShiftKeyMask),
+    );
+    lock_keys
+}
+
 impl KeyCodeExtScancode for KeyCode {
     fn to_scancode(self) -> Option<u32> {
         match self {
@@ -93,6 +93,11 @@ impl<T: 'static> EventLoopWindowTarget<T> {
     pub fn raw_display_handle(&self) -> RawDisplayHandle {
         RawDisplayHandle::AppKit(AppKitDisplayHandle::empty())
     }
+
+    #[inline]
+    pub fn double_click_time(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f64(NSEvent::doubleClickInterval()))
+    }
 }
 
 impl<T> EventLoopWindowTarget<T> {
@@ -22,6 +22,17 @@ pub const kCGDisplayBlendSolidColor: f32 = 1.0;
 pub type CGDisplayFadeReservationToken = u32;
 pub const kCGDisplayFadeReservationInvalidToken: CGDisplayFadeReservationToken = 0;
 
+pub type CGDisplayChangeSummaryFlags = u32;
+pub const kCGDisplayBeginConfigurationFlag: CGDisplayChangeSummaryFlags = 1 << 0;
+pub const kCGDisplayAddFlag: CGDisplayChangeSummaryFlags = 1 << 4;
+pub const kCGDisplayRemoveFlag: CGDisplayChangeSummaryFlags = 1 << 5;
+
+pub type CGDisplayReconfigurationCallBack = extern "C" fn(
+    display: CGDirectDisplayID,
+    flags: CGDisplayChangeSummaryFlags,
+    userInfo: *mut c_void,
+);
+
 pub type Boolean = u8;
 pub const FALSE: Boolean = 0;
 pub const TRUE: Boolean = 1;
@@ -72,6 +83,10 @@ extern "C" {
 #[link(name = "CoreGraphics", kind = "framework")]
 extern "C" {
     pub fn CGRestorePermanentDisplayConfiguration();
+    pub fn CGDisplayRegisterReconfigurationCallback(
+        callback: CGDisplayReconfigurationCallBack,
+        userInfo: *mut c_void,
+    ) -> CGError;
     pub fn CGDisplayCapture(display: CGDirectDisplayID) -> CGError;
     pub fn CGDisplayRelease(display: CGDirectDisplayID) -> CGError;
     pub fn CGConfigureDisplayFadeEffect(
@@ -185,6 +185,28 @@ impl MonitorHandle {
         Some(format!("Monitor #{screen_num}"))
     }
 
+    pub fn friendly_name(&self) -> Option<String> {
+        None
+    }
+
+    pub fn make(&self) -> Option<String> {
+        None
+    }
+
+    pub fn model(&self) -> Option<String> {
+        None
+    }
+
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        let MonitorHandle(display_id) = *self;
+        let size = CGDisplay::new(display_id).screen_size();
+        if size.width > 0.0 && size.height > 0.0 {
+            Some((size.width.round() as u32, size.height.round() as u32))
+        } else {
+            None
+        }
+    }
+
     #[inline]
     pub fn native_identifier(&self) -> u32 {
         self.0
@@ -207,6 +229,35 @@ impl MonitorHandle {
         )
     }
 
+    pub fn work_area(&self) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+        let screen = self.ns_screen()?;
+        let frame = screen.frame();
+        let visible_frame = screen.visibleFrame();
+        let bounds = unsafe { CGDisplayBounds(self.native_identifier()) };
+        let scale_factor = screen.backingScaleFactor() as f64;
+
+        // `visibleFrame` uses bottom-left coordinates, so compute the insets relative to the
+        // screen and apply them to the top-left based display bounds.
+        let left_inset = visible_frame.origin.x - frame.origin.x;
+        let top_inset = (frame.origin.y + frame.size.height)
+            - (visible_frame.origin.y + visible_frame.size.height);
+        let position = PhysicalPosition::from_logical::<_, f64>(
+            (
+                (bounds.origin.x + left_inset) as f64,
+                (bounds.origin.y + top_inset) as f64,
+            ),
+            scale_factor,
+        );
+        let size = PhysicalSize::from_logical::<_, f64>(
+            (
+                visible_frame.size.width as f64,
+                visible_frame.size.height as f64,
+            ),
+            scale_factor,
+        );
+        Some((position, size))
+    }
+
     pub fn scale_factor(&self) -> f64 {
         match self.ns_screen() {
             Some(screen) => screen.backingScaleFactor() as f64,
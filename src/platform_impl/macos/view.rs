@@ -26,12 +26,12 @@ use crate::{
         DeviceEvent, ElementState, Event, Ime, Modifiers, MouseButton, MouseScrollDelta,
         TouchPhase, WindowEvent,
     },
-    keyboard::{Key, KeyCode, KeyLocation, ModifiersState},
+    keyboard::{Key, KeyCode, KeyLocation, LockKeyState, ModifiersState},
     platform::macos::{OptionAsAlt, WindowExtMacOS},
     platform::scancode::KeyCodeExtScancode,
     platform_impl::platform::{
         app_state::AppState,
-        event::{create_key_event, event_mods, EventWrapper},
+        event::{create_key_event, event_mods, lock_keys, EventWrapper},
         util,
         window::WinitWindow,
         DEVICE_ID,
@@ -124,6 +124,7 @@ pub struct ViewState {
     ime_position: Cell<LogicalPosition<f64>>,
     ime_size: Cell<LogicalSize<f64>>,
     modifiers: Cell<Modifiers>,
+    lock_keys: Cell<LockKeyState>,
     phys_modifiers: RefCell<HashMap<Key, ModLocationMask>>,
     tracking_rect: Cell<Option<NSTrackingRectTag>>,
     ime_state: Cell<ImeState>,
@@ -138,6 +139,11 @@ pub struct ViewState {
     /// to the application, even during IME
     forward_key_to_app: Cell<bool>,
 
+    /// True if key repeat events are delivered to the application.
+    ///
+    /// Can be set using `set_key_repeat`
+    key_repeat: Cell<bool>,
+
     marked_text: RefCell<Id<NSMutableAttributedString>>,
     accepts_first_mouse: bool,
 }
@@ -171,6 +177,7 @@ declare_class!(
             this.map(|this| {
                 let state = ViewState {
                     accepts_first_mouse,
+                    key_repeat: Cell::new(true),
                     ..Default::default()
                 };
 
@@ -357,7 +364,7 @@ declare_class!(
             };
 
             // Send WindowEvent for updating marked text
-            self.queue_event(WindowEvent::Ime(Ime::Preedit(preedit_string, cursor_range)));
+            self.queue_event(WindowEvent::Ime(Ime::Preedit(preedit_string, cursor_range, Vec::new())));
         }
 
         #[method(unmarkText)]
@@ -368,7 +375,7 @@ declare_class!(
             let input_context = self.inputContext().expect("input context");
             input_context.discardMarkedText();
 
-            self.queue_event(WindowEvent::Ime(Ime::Preedit(String::new(), None)));
+            self.queue_event(WindowEvent::Ime(Ime::Preedit(String::new(), None, Vec::new())));
             if self.is_ime_enabled() {
                 // Leave the Preedit self.state
                 self.state.ime_state.set(ImeState::Ground);
@@ -435,7 +442,7 @@ declare_class!(
 
             // Commit only if we have marked text.
             if self.hasMarkedText() && self.is_ime_enabled() && !is_control {
-                self.queue_event(WindowEvent::Ime(Ime::Preedit(String::new(), None)));
+                self.queue_event(WindowEvent::Ime(Ime::Preedit(String::new(), None, Vec::new())));
                 self.queue_event(WindowEvent::Ime(Ime::Commit(string)));
                 self.state.ime_state.set(ImeState::Commited);
             }
@@ -512,7 +519,8 @@ declare_class!(
                 _ => old_ime_state != self.state.ime_state.get(),
             };
 
-            if !had_ime_input || self.state.forward_key_to_app.get() {
+            let dropped_repeat = event.is_a_repeat() && !self.state.key_repeat.get();
+            if (!had_ime_input || self.state.forward_key_to_app.get()) && !dropped_repeat {
                 let key_event = create_key_event(&event, true, event.is_a_repeat(), None);
                 self.queue_event(WindowEvent::KeyboardInput {
                     device_id: DEVICE_ID,
@@ -871,6 +879,10 @@ impl WinitView {
         }
     }
 
+    pub(super) fn set_key_repeat(&self, key_repeat: bool) {
+        self.state.key_repeat.set(key_repeat);
+    }
+
     pub(super) fn set_ime_allowed(&self, ime_allowed: bool) {
         if self.state.ime_allowed.get() == ime_allowed {
             return;
@@ -1007,6 +1019,11 @@ impl WinitView {
             }
         }
 
+        let lock_keys = lock_keys(ns_event.modifierFlags());
+        if self.state.lock_keys.replace(lock_keys) != lock_keys {
+            self.queue_event(WindowEvent::LockKeysChanged(lock_keys));
+        }
+
         if prev_modifiers == current_modifiers {
             return;
         }
@@ -4,6 +4,7 @@ use std::collections::VecDeque;
 use std::f64;
 use std::ops;
 use std::os::raw::c_void;
+use std::path::PathBuf;
 use std::ptr::NonNull;
 use std::sync::{Mutex, MutexGuard};
 
@@ -17,11 +18,14 @@ use crate::{
     },
     error::{ExternalError, NotSupportedError, OsError as RootOsError},
     event::WindowEvent,
+    event_loop::AsyncRequestSerial,
     icon::Icon,
+    keyboard::LockKeyState,
     platform::macos::{OptionAsAlt, WindowExtMacOS},
     platform_impl::platform::{
         app_state::AppState,
         appkit::NSWindowOrderingMode,
+        event::lock_keys,
         ffi,
         monitor::{self, MonitorHandle, VideoMode},
         util,
@@ -30,8 +34,9 @@ use crate::{
         Fullscreen, OsError,
     },
     window::{
-        CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, UserAttentionType,
-        WindowAttributes, WindowButtons, WindowId as RootWindowId, WindowLevel,
+        CursorGrabMode, CursorIcon, DragAction, HitTestRegion, ImePurpose, Rect, ResizeDirection,
+        Theme, UserAttentionType, WindowAttributes, WindowButtons, WindowId as RootWindowId,
+        WindowLevel,
     },
 };
 use core_graphics::display::{CGDisplay, CGPoint};
@@ -45,9 +50,9 @@ use objc2::{declare_class, msg_send, msg_send_id, mutability, sel, ClassType};
 
 use super::appkit::{
     NSApp, NSAppKitVersion, NSAppearance, NSApplicationPresentationOptions, NSBackingStoreType,
-    NSColor, NSCursor, NSFilenamesPboardType, NSRequestUserAttentionType, NSResponder, NSScreen,
-    NSView, NSWindow, NSWindowButton, NSWindowLevel, NSWindowSharingType, NSWindowStyleMask,
-    NSWindowTabbingMode, NSWindowTitleVisibility,
+    NSColor, NSCursor, NSEvent, NSFilenamesPboardType, NSRequestUserAttentionType, NSResponder,
+    NSScreen, NSView, NSWindow, NSWindowButton, NSWindowLevel, NSWindowSharingType,
+    NSWindowStyleMask, NSWindowTabbingMode, NSWindowTitleVisibility,
 };
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -379,17 +384,7 @@ impl WinitWindow {
                 this.setTitleVisibility(NSWindowTitleVisibility::Hidden);
             }
             if pl_attrs.titlebar_buttons_hidden {
-                for titlebar_button in &[
-                    #[allow(deprecated)]
-                    NSWindowButton::FullScreen,
-                    NSWindowButton::Miniaturize,
-                    NSWindowButton::Close,
-                    NSWindowButton::Zoom,
-                ] {
-                    if let Some(button) = this.standardWindowButton(*titlebar_button) {
-                        button.setHidden(true);
-                    }
-                }
+                this.set_titlebar_buttons_hidden(true);
             }
             if pl_attrs.movable_by_window_background {
                 this.setMovableByWindowBackground(true);
@@ -553,6 +548,9 @@ impl WinitWindow {
         self.setOpaque(!transparent)
     }
 
+    #[inline]
+    pub fn set_opaque_region(&self, _region: Option<&[Rect]>) {}
+
     pub fn set_visible(&self, visible: bool) {
         match visible {
             true => util::make_key_and_order_front_sync(self),
@@ -572,6 +570,18 @@ impl WinitWindow {
     #[inline]
     pub fn pre_present_notify(&self) {}
 
+    #[inline]
+    pub fn pre_present_notify_with_damage(
+        &self,
+        _damage: &[(PhysicalPosition<i32>, PhysicalSize<u32>)],
+    ) {
+    }
+
+    #[inline]
+    pub fn flush(&self) -> Result<(), ExternalError> {
+        Ok(())
+    }
+
     pub fn outer_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
         let frame_rect = self.frame();
         let position = LogicalPosition::new(
@@ -788,6 +798,39 @@ impl WinitWindow {
         self.invalidateCursorRectsForView(&view);
     }
 
+    #[inline]
+    pub fn preload_cursors(&self, _cursors: &[CursorIcon]) {}
+
+    #[inline]
+    pub fn set_primary_selection_text(&self, _text: &str) {}
+
+    #[inline]
+    pub fn request_primary_selection_text(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn set_clipboard_data(&self, _data: Vec<(String, Vec<u8>)>) {}
+
+    #[inline]
+    pub fn request_clipboard_data(
+        &self,
+        _mime_type: &str,
+    ) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn request_clipboard_mime_types(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn set_drag_accept(&self, _mime_type: Option<&str>) {}
+
+    #[inline]
+    pub fn set_drag_action(&self, _action: DragAction) {}
+
     #[inline]
     pub fn set_cursor_grab(&self, mode: CursorGrabMode) -> Result<(), ExternalError> {
         let associate_mouse_cursor = match mode {
@@ -847,12 +890,20 @@ impl WinitWindow {
         Err(ExternalError::NotSupported(NotSupportedError::new()))
     }
 
+    #[inline]
+    pub fn set_hittest_regions(&self, _regions: &[HitTestRegion]) {}
+
     #[inline]
     pub fn set_cursor_hittest(&self, hittest: bool) -> Result<(), ExternalError> {
         util::set_ignore_mouse_events_sync(self, !hittest);
         Ok(())
     }
 
+    #[inline]
+    pub fn set_input_region(&self, _region: Option<&[Rect]>) -> Result<(), ExternalError> {
+        Err(ExternalError::NotSupported(NotSupportedError::new()))
+    }
+
     pub(crate) fn is_zoomed(&self) -> bool {
         // because `isZoomed` doesn't work if the window's borderless,
         // we make it resizable temporalily.
@@ -932,6 +983,11 @@ impl WinitWindow {
         util::set_maximized_sync(self, is_zoomed, maximized);
     }
 
+    #[inline]
+    pub fn restored_inner_size(&self) -> Option<PhysicalSize<u32>> {
+        None
+    }
+
     #[inline]
     pub(crate) fn fullscreen(&self) -> Option<Fullscreen> {
         let shared_state_lock = self.lock_shared_state("fullscreen");
@@ -1199,9 +1255,17 @@ impl WinitWindow {
         self.view().set_ime_allowed(allowed);
     }
 
+    #[inline]
+    pub fn set_key_repeat(&self, key_repeat: bool) {
+        self.view().set_key_repeat(key_repeat);
+    }
+
     #[inline]
     pub fn set_ime_purpose(&self, _purpose: ImePurpose) {}
 
+    #[inline]
+    pub fn set_ime_surrounding_text(&self, _text: &str, _cursor: usize, _anchor: usize) {}
+
     #[inline]
     pub fn focus_window(&self) {
         let is_minimized = self.isMiniaturized();
@@ -1279,6 +1343,16 @@ impl WinitWindow {
         self.isKeyWindow()
     }
 
+    #[inline]
+    pub fn lock_key_state(&self) -> LockKeyState {
+        lock_keys(NSEvent::currentModifierFlags())
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.has_focus()
+    }
+
     pub fn set_theme(&self, theme: Option<Theme>) {
         set_ns_theme(theme);
         self.lock_shared_state("set_theme").current_theme = theme.or_else(|| Some(get_ns_theme()));
@@ -1401,6 +1475,41 @@ impl WindowExtMacOS for WinitWindow {
         self.setHasShadow(has_shadow)
     }
 
+    #[inline]
+    fn is_movable_by_window_background(&self) -> bool {
+        self.isMovableByWindowBackground()
+    }
+
+    #[inline]
+    fn set_movable_by_window_background(&self, movable: bool) {
+        self.setMovableByWindowBackground(movable)
+    }
+
+    #[inline]
+    fn set_titlebar_transparent(&self, transparent: bool) {
+        self.setTitlebarAppearsTransparent(transparent)
+    }
+
+    #[inline]
+    fn set_fullsize_content_view(&self, fullsize: bool) {
+        self.toggle_style_mask(NSWindowStyleMask::NSFullSizeContentViewWindowMask, fullsize);
+    }
+
+    #[inline]
+    fn set_titlebar_buttons_hidden(&self, hidden: bool) {
+        for titlebar_button in &[
+            #[allow(deprecated)]
+            NSWindowButton::FullScreen,
+            NSWindowButton::Miniaturize,
+            NSWindowButton::Close,
+            NSWindowButton::Zoom,
+        ] {
+            if let Some(button) = self.standardWindowButton(*titlebar_button) {
+                button.setHidden(hidden);
+            }
+        }
+    }
+
     #[inline]
     fn set_tabbing_identifier(&self, identifier: &str) {
         self.setTabbingIdentifier(&NSString::from_str(identifier))
@@ -1436,6 +1545,24 @@ impl WindowExtMacOS for WinitWindow {
         tab_group.tabbedWindows().len()
     }
 
+    #[inline]
+    fn merge_all_windows(&self) {
+        self.mergeAllWindows(None);
+    }
+
+    #[inline]
+    fn is_tab_bar_visible(&self) -> bool {
+        self.tabGroup().isTabBarVisible()
+    }
+
+    #[inline]
+    fn set_tab_bar_visible(&self, visible: bool) {
+        // AppKit only exposes a toggle, so check the current state first.
+        if self.is_tab_bar_visible() != visible {
+            self.toggleTabBar(None);
+        }
+    }
+
     fn is_document_edited(&self) -> bool {
         self.isDocumentEdited()
     }
@@ -1444,6 +1571,24 @@ impl WindowExtMacOS for WinitWindow {
         self.setDocumentEdited(edited)
     }
 
+    fn set_represented_filename(&self, path: Option<PathBuf>) {
+        // AppKit removes the proxy icon when given an empty string.
+        let filename = path
+            .as_deref()
+            .map(|path| path.to_string_lossy())
+            .unwrap_or_default();
+        self.setRepresentedFilename(&NSString::from_str(&filename))
+    }
+
+    fn represented_filename(&self) -> Option<PathBuf> {
+        let filename = self.representedFilename().to_string();
+        if filename.is_empty() {
+            None
+        } else {
+            Some(PathBuf::from(filename))
+        }
+    }
+
     fn set_option_as_alt(&self, option_as_alt: OptionAsAlt) {
         let mut shared_state_lock = self.lock_shared_state("set_option_as_alt");
         shared_state_lock.option_as_alt = option_as_alt;
@@ -332,7 +332,9 @@ impl<T: 'static> EventLoop<T> {
                                 repeat: false,
                                 text: None,
 
-                                platform_specific: KeyEventExtra {},
+                                platform_specific: KeyEventExtra {
+                                    raw_scancode: Some(scancode as u32),
+                                },
                             },
                             is_synthetic: false,
                         },
@@ -350,7 +352,7 @@ impl<T: 'static> EventLoop<T> {
             EventOption::TextInput(TextInputEvent { character }) => {
                 event_handler(event::Event::WindowEvent {
                     window_id: RootWindowId(window_id),
-                    event: event::WindowEvent::Ime(Ime::Preedit("".into(), None)),
+                    event: event::WindowEvent::Ime(Ime::Preedit("".into(), None, Vec::new())),
                 });
                 event_handler(event::Event::WindowEvent {
                     window_id: RootWindowId(window_id),
@@ -600,7 +602,10 @@ impl<T: 'static> EventLoop<T> {
                 redraws.pop_front()
             } {
                 event_handler(
-                    event::Event::RedrawRequested(RootWindowId(window_id)),
+                    event::Event::RedrawRequested {
+                        window_id: RootWindowId(window_id),
+                        deadline: None,
+                    },
                     &self.window_target,
                     &mut control_flow,
                 );
@@ -196,6 +196,22 @@ impl MonitorHandle {
         Some("Redox Device".to_owned())
     }
 
+    pub fn friendly_name(&self) -> Option<String> {
+        None
+    }
+
+    pub fn make(&self) -> Option<String> {
+        None
+    }
+
+    pub fn model(&self) -> Option<String> {
+        None
+    }
+
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        None
+    }
+
     pub fn size(&self) -> PhysicalSize<u32> {
         PhysicalSize::new(0, 0) // TODO
     }
@@ -204,6 +220,10 @@ impl MonitorHandle {
         (0, 0).into()
     }
 
+    pub fn work_area(&self) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+        None
+    }
+
     pub fn scale_factor(&self) -> f64 {
         1.0 // TODO
     }
@@ -253,4 +273,13 @@ impl VideoMode {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct KeyEventExtra {}
+pub struct KeyEventExtra {
+    pub raw_scancode: Option<u32>,
+}
+
+impl KeyEventExtra {
+    #[inline]
+    pub fn raw_scancode(&self) -> Option<u32> {
+        self.raw_scancode
+    }
+}
@@ -10,6 +10,8 @@ use raw_window_handle::{
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize, Position, Size},
     error,
+    event_loop::AsyncRequestSerial,
+    keyboard::LockKeyState,
     platform_impl::Fullscreen,
     window,
     window::ImePurpose,
@@ -169,6 +171,18 @@ impl Window {
     #[inline]
     pub fn pre_present_notify(&self) {}
 
+    #[inline]
+    pub fn pre_present_notify_with_damage(
+        &self,
+        _damage: &[(PhysicalPosition<i32>, PhysicalSize<u32>)],
+    ) {
+    }
+
+    #[inline]
+    pub fn flush(&self) -> Result<(), error::ExternalError> {
+        Ok(())
+    }
+
     #[inline]
     pub fn reset_dead_keys(&self) {
         // TODO?
@@ -253,6 +267,9 @@ impl Window {
     #[inline]
     pub fn set_transparent(&self, _transparent: bool) {}
 
+    #[inline]
+    pub fn set_opaque_region(&self, _region: Option<&[window::Rect]>) {}
+
     #[inline]
     pub fn set_visible(&self, _visibility: bool) {}
 
@@ -299,6 +316,11 @@ impl Window {
         false
     }
 
+    #[inline]
+    pub fn restored_inner_size(&self) -> Option<PhysicalSize<u32>> {
+        None
+    }
+
     #[inline]
     pub(crate) fn set_fullscreen(&self, _monitor: Option<Fullscreen>) {}
 
@@ -336,6 +358,9 @@ impl Window {
     #[inline]
     pub fn set_ime_purpose(&self, _purpose: ImePurpose) {}
 
+    #[inline]
+    pub fn set_ime_surrounding_text(&self, _text: &str, _cursor: usize, _anchor: usize) {}
+
     #[inline]
     pub fn focus_window(&self) {}
 
@@ -345,6 +370,43 @@ impl Window {
     #[inline]
     pub fn set_cursor_icon(&self, _: window::CursorIcon) {}
 
+    #[inline]
+    pub fn preload_cursors(&self, _: &[window::CursorIcon]) {}
+
+    #[inline]
+    pub fn set_primary_selection_text(&self, _text: &str) {}
+
+    #[inline]
+    pub fn request_primary_selection_text(
+        &self,
+    ) -> Result<AsyncRequestSerial, error::NotSupportedError> {
+        Err(error::NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn set_clipboard_data(&self, _data: Vec<(String, Vec<u8>)>) {}
+
+    #[inline]
+    pub fn request_clipboard_data(
+        &self,
+        _mime_type: &str,
+    ) -> Result<AsyncRequestSerial, error::NotSupportedError> {
+        Err(error::NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn request_clipboard_mime_types(
+        &self,
+    ) -> Result<AsyncRequestSerial, error::NotSupportedError> {
+        Err(error::NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn set_drag_accept(&self, _mime_type: Option<&str>) {}
+
+    #[inline]
+    pub fn set_drag_action(&self, _action: window::DragAction) {}
+
     #[inline]
     pub fn set_cursor_position(&self, _: Position) -> Result<(), error::ExternalError> {
         Err(error::ExternalError::NotSupported(
@@ -379,6 +441,9 @@ impl Window {
         ))
     }
 
+    #[inline]
+    pub fn set_hittest_regions(&self, _regions: &[window::HitTestRegion]) {}
+
     #[inline]
     pub fn set_cursor_hittest(&self, _hittest: bool) -> Result<(), error::ExternalError> {
         Err(error::ExternalError::NotSupported(
@@ -386,6 +451,16 @@ impl Window {
         ))
     }
 
+    #[inline]
+    pub fn set_input_region(
+        &self,
+        _region: Option<&[window::Rect]>,
+    ) -> Result<(), error::ExternalError> {
+        Err(error::ExternalError::NotSupported(
+            error::NotSupportedError::new(),
+        ))
+    }
+
     #[inline]
     pub fn raw_window_handle(&self) -> RawWindowHandle {
         let mut handle = OrbitalWindowHandle::empty();
@@ -416,6 +491,19 @@ impl Window {
         false
     }
 
+    #[inline]
+    pub fn set_key_repeat(&self, _key_repeat: bool) {}
+
+    #[inline]
+    pub fn lock_key_state(&self) -> LockKeyState {
+        LockKeyState::empty()
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.has_focus()
+    }
+
     #[inline]
     pub fn set_theme(&self, _theme: Option<window::Theme>) {}
 }
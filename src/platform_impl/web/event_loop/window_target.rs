@@ -128,6 +128,7 @@ impl<T> EventLoopWindowTarget<T> {
 
         let runner = self.runner.clone();
         let modifiers = self.modifiers.clone();
+        let key_repeat = canvas.key_repeat.clone();
         canvas.on_keyboard_press(
             move |physical_key, logical_key, text, location, repeat, active_modifiers| {
                 let modifiers_changed = (modifiers.get() != active_modifiers).then(|| {
@@ -148,25 +149,28 @@ impl<T> EventLoopWindowTarget<T> {
                     }),
                 });
 
+                let keyboard_input =
+                    (!repeat || key_repeat.load(Ordering::Relaxed)).then(|| Event::WindowEvent {
+                        window_id: RootWindowId(id),
+                        event: WindowEvent::KeyboardInput {
+                            device_id,
+                            event: KeyEvent {
+                                physical_key,
+                                logical_key,
+                                text,
+                                location,
+                                state: ElementState::Pressed,
+                                repeat,
+                                platform_specific: KeyEventExtra,
+                            },
+                            is_synthetic: false,
+                        },
+                    });
+
                 runner.send_events(
                     device_event
                         .into_iter()
-                        .chain(iter::once(Event::WindowEvent {
-                            window_id: RootWindowId(id),
-                            event: WindowEvent::KeyboardInput {
-                                device_id,
-                                event: KeyEvent {
-                                    physical_key,
-                                    logical_key,
-                                    text,
-                                    location,
-                                    state: ElementState::Pressed,
-                                    repeat,
-                                    platform_specific: KeyEventExtra,
-                                },
-                                is_synthetic: false,
-                            },
-                        }))
+                        .chain(keyboard_input)
                         .chain(modifiers_changed),
                 );
             },
@@ -690,6 +694,14 @@ impl<T> EventLoopWindowTarget<T> {
             });
         });
 
+        let runner = self.runner.clone();
+        canvas.on_pointer_lock_change(move |grabbed| {
+            runner.send_event(Event::WindowEvent {
+                window_id: RootWindowId(id),
+                event: WindowEvent::PointerGrabChanged { grabbed },
+            });
+        });
+
         canvas.on_resize_scale(
             {
                 let runner = self.runner.clone();
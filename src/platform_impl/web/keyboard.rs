@@ -5,6 +5,13 @@ use crate::keyboard::{Key, KeyCode, NativeKey, NativeKeyCode};
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub(crate) struct KeyEventExtra;
 
+impl KeyEventExtra {
+    #[inline]
+    pub fn raw_scancode(&self) -> Option<u32> {
+        None
+    }
+}
+
 impl Key {
     pub(crate) fn from_key_attribute_value(kav: &str) -> Self {
         match kav {
@@ -465,6 +472,11 @@ impl KeyCode {
             "AudioVolumeMute" => KeyCode::AudioVolumeMute,
             "AudioVolumeUp" => KeyCode::AudioVolumeUp,
             "WakeUp" => KeyCode::WakeUp,
+            "MediaPlay" => KeyCode::MediaPlay,
+            "MediaPause" => KeyCode::MediaPause,
+            "MediaRecord" => KeyCode::MediaRecord,
+            "MediaFastForward" => KeyCode::MediaFastForward,
+            "MediaRewind" => KeyCode::MediaRewind,
             "Hyper" => KeyCode::Hyper,
             "Turbo" => KeyCode::Turbo,
             "Abort" => KeyCode::Abort,
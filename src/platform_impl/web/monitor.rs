@@ -12,10 +12,30 @@ impl MonitorHandle {
         PhysicalPosition { x: 0, y: 0 }
     }
 
+    pub fn work_area(&self) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+        None
+    }
+
     pub fn name(&self) -> Option<String> {
         None
     }
 
+    pub fn friendly_name(&self) -> Option<String> {
+        None
+    }
+
+    pub fn make(&self) -> Option<String> {
+        None
+    }
+
+    pub fn model(&self) -> Option<String> {
+        None
+    }
+
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        None
+    }
+
     pub fn refresh_rate_millihertz(&self) -> Option<u32> {
         None
     }
@@ -8,7 +8,8 @@ use smol_str::SmolStr;
 use wasm_bindgen::{closure::Closure, JsCast};
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{
-    CssStyleDeclaration, Document, Event, FocusEvent, HtmlCanvasElement, KeyboardEvent, WheelEvent,
+    CssStyleDeclaration, Document, Element, Event, FocusEvent, HtmlCanvasElement, KeyboardEvent,
+    WheelEvent,
 };
 
 use crate::dpi::{LogicalPosition, PhysicalPosition, PhysicalSize};
@@ -31,6 +32,7 @@ pub struct Canvas {
     common: Common,
     id: WindowId,
     pub has_focus: Arc<AtomicBool>,
+    pub key_repeat: Arc<AtomicBool>,
     pub is_intersecting: Option<bool>,
     on_touch_start: Option<EventListenerHandle<dyn FnMut(Event)>>,
     on_touch_end: Option<EventListenerHandle<dyn FnMut(Event)>>,
@@ -40,6 +42,7 @@ pub struct Canvas {
     on_keyboard_press: Option<EventListenerHandle<dyn FnMut(KeyboardEvent)>>,
     on_mouse_wheel: Option<EventListenerHandle<dyn FnMut(WheelEvent)>>,
     on_dark_mode: Option<MediaQueryListHandle>,
+    on_pointer_lock_change: Option<EventListenerHandle<dyn FnMut(Event)>>,
     pointer_handler: PointerHandler,
     on_resize_scale: Option<ResizeScaleHandle>,
     on_intersect: Option<IntersectionObserverHandle>,
@@ -141,6 +144,7 @@ impl Canvas {
             common,
             id,
             has_focus: Arc::new(AtomicBool::new(false)),
+            key_repeat: Arc::new(AtomicBool::new(true)),
             is_intersecting: None,
             on_touch_start: None,
             on_touch_end: None,
@@ -150,6 +154,7 @@ impl Canvas {
             on_keyboard_press: None,
             on_mouse_wheel: None,
             on_dark_mode: None,
+            on_pointer_lock_change: None,
             pointer_handler: PointerHandler::new(),
             on_resize_scale: None,
             on_intersect: None,
@@ -422,6 +427,28 @@ impl Canvas {
         ));
     }
 
+    pub fn on_pointer_lock_change<F>(&mut self, mut handler: F)
+    where
+        F: 'static + FnMut(bool),
+    {
+        let document = self.common.document.clone();
+        let canvas: Element = self.common.raw.clone().into();
+        let mut locked = false;
+
+        // The event is only sent to the document, for any element being locked or unlocked.
+        self.on_pointer_lock_change = Some(EventListenerHandle::new(
+            &self.common.document,
+            "pointerlockchange",
+            Closure::new(move |_: Event| {
+                let is_locked = document.pointer_lock_element().as_ref() == Some(&canvas);
+                if is_locked != locked {
+                    locked = is_locked;
+                    handler(locked);
+                }
+            }),
+        ));
+    }
+
     pub(crate) fn on_resize_scale<S, R>(&mut self, scale_handler: S, size_handler: R)
     where
         S: 'static + FnMut(PhysicalSize<u32>, f64),
@@ -514,6 +541,7 @@ impl Canvas {
         self.on_keyboard_press = None;
         self.on_mouse_wheel = None;
         self.on_dark_mode = None;
+        self.on_pointer_lock_change = None;
         self.pointer_handler.remove_listeners();
         self.on_resize_scale = None;
         self.on_intersect = None;
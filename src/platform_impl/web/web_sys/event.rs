@@ -29,7 +29,7 @@ impl From<ButtonsState> for MouseButton {
             ButtonsState::MIDDLE => MouseButton::Middle,
             ButtonsState::BACK => MouseButton::Back,
             ButtonsState::FORWARD => MouseButton::Forward,
-            _ => MouseButton::Other(value.bits()),
+            _ => MouseButton::Other(value.bits().trailing_zeros() as u16),
         }
     }
 }
@@ -42,7 +42,9 @@ impl From<MouseButton> for ButtonsState {
             MouseButton::Middle => ButtonsState::MIDDLE,
             MouseButton::Back => ButtonsState::BACK,
             MouseButton::Forward => ButtonsState::FORWARD,
-            MouseButton::Other(value) => ButtonsState::from_bits_retain(value),
+            MouseButton::Other(value) => {
+                ButtonsState::from_bits_retain(1u16.checked_shl(value.into()).unwrap_or(0))
+            }
         }
     }
 }
@@ -1,9 +1,11 @@
 use crate::dpi::{PhysicalPosition, PhysicalSize, Position, Size};
 use crate::error::{ExternalError, NotSupportedError, OsError as RootOE};
+use crate::event_loop::AsyncRequestSerial;
 use crate::icon::Icon;
+use crate::keyboard::LockKeyState;
 use crate::window::{
-    CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, UserAttentionType,
-    WindowAttributes, WindowButtons, WindowId as RootWI, WindowLevel,
+    CursorGrabMode, CursorIcon, DragAction, HitTestRegion, ImePurpose, Rect, ResizeDirection,
+    Theme, UserAttentionType, WindowAttributes, WindowButtons, WindowId as RootWI, WindowLevel,
 };
 
 use raw_window_handle::{RawDisplayHandle, RawWindowHandle, WebDisplayHandle, WebWindowHandle};
@@ -22,6 +24,7 @@ use std::sync::Arc;
 pub struct Window {
     id: WindowId,
     has_focus: Arc<AtomicBool>,
+    key_repeat: Arc<AtomicBool>,
     pub inner: Dispatcher<Inner>,
 }
 
@@ -55,9 +58,11 @@ impl Window {
         let destroy_fn = Box::new(move || runner.notify_destroy_window(RootWI(id)));
 
         let has_focus = canvas.borrow().has_focus.clone();
+        let key_repeat = canvas.borrow().key_repeat.clone();
         let window = Window {
             id,
             has_focus,
+            key_repeat,
             inner: Dispatcher::new(Inner {
                 window: window.clone(),
                 document: document.clone(),
@@ -94,6 +99,9 @@ impl Window {
 
     pub fn set_transparent(&self, _transparent: bool) {}
 
+    #[inline]
+    pub fn set_opaque_region(&self, _region: Option<&[Rect]>) {}
+
     pub fn set_visible(&self, _visible: bool) {
         // Intentionally a no-op
     }
@@ -111,6 +119,18 @@ impl Window {
 
     pub fn pre_present_notify(&self) {}
 
+    #[inline]
+    pub fn pre_present_notify_with_damage(
+        &self,
+        _damage: &[(PhysicalPosition<i32>, PhysicalSize<u32>)],
+    ) {
+    }
+
+    #[inline]
+    pub fn flush(&self) -> Result<(), ExternalError> {
+        Ok(())
+    }
+
     pub fn outer_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
         self.inner.queue(|inner| {
             Ok(inner
@@ -231,6 +251,39 @@ impl Window {
         });
     }
 
+    #[inline]
+    pub fn preload_cursors(&self, _cursors: &[CursorIcon]) {}
+
+    #[inline]
+    pub fn set_primary_selection_text(&self, _text: &str) {}
+
+    #[inline]
+    pub fn request_primary_selection_text(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn set_clipboard_data(&self, _data: Vec<(String, Vec<u8>)>) {}
+
+    #[inline]
+    pub fn request_clipboard_data(
+        &self,
+        _mime_type: &str,
+    ) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn request_clipboard_mime_types(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn set_drag_accept(&self, _mime_type: Option<&str>) {}
+
+    #[inline]
+    pub fn set_drag_action(&self, _action: DragAction) {}
+
     #[inline]
     pub fn set_cursor_position(&self, _position: Position) -> Result<(), ExternalError> {
         Err(ExternalError::NotSupported(NotSupportedError::new()))
@@ -280,11 +333,19 @@ impl Window {
         Err(ExternalError::NotSupported(NotSupportedError::new()))
     }
 
+    #[inline]
+    pub fn set_hittest_regions(&self, _regions: &[HitTestRegion]) {}
+
     #[inline]
     pub fn set_cursor_hittest(&self, _hittest: bool) -> Result<(), ExternalError> {
         Err(ExternalError::NotSupported(NotSupportedError::new()))
     }
 
+    #[inline]
+    pub fn set_input_region(&self, _region: Option<&[Rect]>) -> Result<(), ExternalError> {
+        Err(ExternalError::NotSupported(NotSupportedError::new()))
+    }
+
     #[inline]
     pub fn set_minimized(&self, _minimized: bool) {
         // Intentionally a no-op, as canvases cannot be 'minimized'
@@ -301,6 +362,11 @@ impl Window {
         // Intentionally a no-op, as canvases cannot be 'maximized'
     }
 
+    #[inline]
+    pub fn restored_inner_size(&self) -> Option<PhysicalSize<u32>> {
+        None
+    }
+
     #[inline]
     pub fn is_maximized(&self) -> bool {
         // Canvas cannot be 'maximized'
@@ -363,6 +429,11 @@ impl Window {
         // Currently not implemented
     }
 
+    #[inline]
+    pub fn set_ime_surrounding_text(&self, _text: &str, _cursor: usize, _anchor: usize) {
+        // Currently not implemented
+    }
+
     #[inline]
     pub fn focus_window(&self) {
         self.inner.dispatch(|inner| {
@@ -428,6 +499,21 @@ impl Window {
         self.has_focus.load(Ordering::Relaxed)
     }
 
+    #[inline]
+    pub fn set_key_repeat(&self, key_repeat: bool) {
+        self.key_repeat.store(key_repeat, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn lock_key_state(&self) -> LockKeyState {
+        LockKeyState::empty()
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.has_focus()
+    }
+
     pub fn title(&self) -> String {
         String::new()
     }
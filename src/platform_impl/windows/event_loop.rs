@@ -3,7 +3,7 @@
 mod runner;
 
 use std::{
-    cell::Cell,
+    cell::{Cell, RefCell},
     collections::VecDeque,
     ffi::c_void,
     marker::PhantomData,
@@ -24,8 +24,9 @@ use windows_sys::Win32::{
     Devices::HumanInterfaceDevice::MOUSE_MOVE_RELATIVE,
     Foundation::{BOOL, HANDLE, HWND, LPARAM, LRESULT, POINT, RECT, WPARAM},
     Graphics::Gdi::{
-        GetMonitorInfoW, MonitorFromRect, MonitorFromWindow, RedrawWindow, ScreenToClient,
-        ValidateRect, MONITORINFO, MONITOR_DEFAULTTONULL, RDW_INTERNALPAINT, SC_SCREENSAVE,
+        GetMonitorInfoW, GetSysColor, MonitorFromRect, MonitorFromWindow, RedrawWindow,
+        ScreenToClient, ValidateRect, COLOR_HIGHLIGHT, MONITORINFO, MONITOR_DEFAULTTONULL,
+        RDW_INTERNALPAINT, SC_SCREENSAVE,
     },
     System::{
         Ole::RevokeDragDrop,
@@ -36,8 +37,8 @@ use windows_sys::Win32::{
         Input::{
             Ime::{GCS_COMPSTR, GCS_RESULTSTR, ISC_SHOWUICOMPOSITIONWINDOW},
             KeyboardAndMouse::{
-                MapVirtualKeyW, ReleaseCapture, SetCapture, TrackMouseEvent, MAPVK_VK_TO_VSC_EX,
-                TME_LEAVE, TRACKMOUSEEVENT, VK_NUMLOCK, VK_SHIFT,
+                GetDoubleClickTime, MapVirtualKeyW, ReleaseCapture, SetCapture, TrackMouseEvent,
+                MAPVK_VK_TO_VSC_EX, TME_LEAVE, TRACKMOUSEEVENT, VK_NUMLOCK, VK_SHIFT,
             },
             Pointer::{
                 POINTER_FLAG_DOWN, POINTER_FLAG_UP, POINTER_FLAG_UPDATE, POINTER_INFO,
@@ -50,25 +51,26 @@ use windows_sys::Win32::{
             RAWINPUT, RIM_TYPEKEYBOARD, RIM_TYPEMOUSE,
         },
         WindowsAndMessaging::{
-            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetCursorPos,
-            GetMenu, GetMessageW, KillTimer, LoadCursorW, PeekMessageW, PostMessageW,
-            RegisterClassExW, RegisterWindowMessageA, SetCursor, SetTimer, SetWindowPos,
-            TranslateMessage, CREATESTRUCTW, GIDC_ARRIVAL, GIDC_REMOVAL, GWL_STYLE, GWL_USERDATA,
-            HTCAPTION, HTCLIENT, MINMAXINFO, MNC_CLOSE, MSG, NCCALCSIZE_PARAMS, PM_REMOVE, PT_PEN,
-            PT_TOUCH, RI_KEY_E0, RI_KEY_E1, RI_MOUSE_WHEEL, SC_MINIMIZE, SC_RESTORE,
-            SIZE_MAXIMIZED, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER, WHEEL_DELTA,
-            WINDOWPOS, WM_CAPTURECHANGED, WM_CLOSE, WM_CREATE, WM_DESTROY, WM_DPICHANGED,
-            WM_ENTERSIZEMOVE, WM_EXITSIZEMOVE, WM_GETMINMAXINFO, WM_IME_COMPOSITION,
-            WM_IME_ENDCOMPOSITION, WM_IME_SETCONTEXT, WM_IME_STARTCOMPOSITION, WM_INPUT,
-            WM_INPUT_DEVICE_CHANGE, WM_KEYDOWN, WM_KEYUP, WM_KILLFOCUS, WM_LBUTTONDOWN,
-            WM_LBUTTONUP, WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MENUCHAR, WM_MOUSEHWHEEL, WM_MOUSEMOVE,
-            WM_MOUSEWHEEL, WM_NCACTIVATE, WM_NCCALCSIZE, WM_NCCREATE, WM_NCDESTROY,
-            WM_NCLBUTTONDOWN, WM_PAINT, WM_POINTERDOWN, WM_POINTERUP, WM_POINTERUPDATE,
-            WM_RBUTTONDOWN, WM_RBUTTONUP, WM_SETCURSOR, WM_SETFOCUS, WM_SETTINGCHANGE, WM_SIZE,
-            WM_SYSCOMMAND, WM_SYSKEYDOWN, WM_SYSKEYUP, WM_TOUCH, WM_WINDOWPOSCHANGED,
-            WM_WINDOWPOSCHANGING, WM_XBUTTONDOWN, WM_XBUTTONUP, WNDCLASSEXW, WS_EX_LAYERED,
-            WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_EX_TRANSPARENT, WS_OVERLAPPED, WS_POPUP,
-            WS_VISIBLE,
+            CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, GetCaretBlinkTime,
+            GetCursorPos, GetMenu, GetMessageW, GetSystemMetrics, KillTimer, LoadCursorW,
+            PeekMessageW, PostMessageW, RegisterClassExW, RegisterWindowMessageA, SetCursor,
+            SetTimer, SetWindowPos, TranslateMessage, CREATESTRUCTW, GIDC_ARRIVAL, GIDC_REMOVAL,
+            GWL_STYLE, GWL_USERDATA, HTCAPTION, HTCLIENT, MINMAXINFO, MNC_CLOSE, MSG,
+            NCCALCSIZE_PARAMS, PM_REMOVE, PT_PEN, PT_TOUCH, RI_KEY_E0, RI_KEY_E1, RI_MOUSE_HWHEEL,
+            RI_MOUSE_WHEEL, SC_MINIMIZE, SC_RESTORE, SIZE_MAXIMIZED, SM_CXDOUBLECLK, SM_CXDRAG,
+            SM_CYDOUBLECLK, SM_CYDRAG, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE, SWP_NOZORDER,
+            SYSTEM_METRICS_INDEX, WHEEL_DELTA, WINDOWPOS, WM_CAPTURECHANGED, WM_CLOSE, WM_CREATE,
+            WM_DESTROY, WM_DISPLAYCHANGE, WM_DPICHANGED, WM_ENDSESSION, WM_ENTERSIZEMOVE,
+            WM_EXITSIZEMOVE, WM_GETMINMAXINFO, WM_IME_COMPOSITION, WM_IME_ENDCOMPOSITION,
+            WM_IME_SETCONTEXT, WM_IME_STARTCOMPOSITION, WM_INPUT, WM_INPUT_DEVICE_CHANGE,
+            WM_KEYDOWN, WM_KEYUP, WM_KILLFOCUS, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_MBUTTONDOWN,
+            WM_MBUTTONUP, WM_MENUCHAR, WM_MOUSEHWHEEL, WM_MOUSEMOVE, WM_MOUSEWHEEL, WM_NCACTIVATE,
+            WM_NCCALCSIZE, WM_NCCREATE, WM_NCDESTROY, WM_NCHITTEST, WM_NCLBUTTONDOWN, WM_PAINT,
+            WM_POINTERDOWN, WM_POINTERUP, WM_POINTERUPDATE, WM_RBUTTONDOWN, WM_RBUTTONUP,
+            WM_SETCURSOR, WM_SETFOCUS, WM_SETTINGCHANGE, WM_SIZE, WM_SYSCOMMAND, WM_SYSKEYDOWN,
+            WM_SYSKEYUP, WM_TOUCH, WM_WINDOWPOSCHANGED, WM_WINDOWPOSCHANGING, WM_XBUTTONDOWN,
+            WM_XBUTTONUP, WNDCLASSEXW, WS_EX_LAYERED, WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW,
+            WS_EX_TRANSPARENT, WS_OVERLAPPED, WS_POPUP, WS_VISIBLE,
         },
     },
 };
@@ -77,32 +79,33 @@ use crate::{
     dpi::{PhysicalPosition, PhysicalSize},
     error::RunLoopError,
     event::{
-        DeviceEvent, Event, Force, Ime, InnerSizeWriter, RawKeyEvent, Touch, TouchPhase,
-        WindowEvent,
+        DeviceEvent, DeviceKind, Event, Force, Ime, InnerSizeWriter, MonitorEvent, RawKeyEvent,
+        Touch, TouchPhase, WindowEvent,
     },
     event_loop::{ControlFlow, DeviceEvents, EventLoopClosed, EventLoopWindowTarget as RootELW},
     keyboard::{KeyCode, ModifiersState},
-    platform::{pump_events::PumpStatus, scancode::KeyCodeExtScancode},
+    monitor::MonitorHandle as RootMonitorHandle,
+    platform::{pump_events::PumpStatus, scancode::KeyCodeExtScancode, windows::Color},
     platform_impl::platform::{
         dark_mode::try_theme,
         dpi::{become_dpi_aware, dpi_to_scale_factor},
         drop_handler::FileDropHandler,
         ime::ImeContext,
         keyboard::KeyEventBuilder,
-        keyboard_layout::LAYOUT_CACHE,
+        keyboard_layout::{get_lock_keys, LAYOUT_CACHE},
         monitor::{self, MonitorHandle},
         raw_input, util,
         window::InitData,
         window_state::{CursorFlags, ImeState, WindowFlags, WindowState},
         wrap_device_id, Fullscreen, WindowId, DEVICE_ID,
     },
-    window::WindowId as RootWindowId,
+    window::{HitTestKind, HitTestRegion, WindowId as RootWindowId},
 };
 use runner::{EventLoopRunner, EventLoopRunnerShared};
 
 use self::runner::RunnerState;
 
-use super::window::set_skip_taskbar;
+use super::window::{resize_direction_hittest, set_skip_taskbar};
 
 type GetPointerFrameInfoHistory = unsafe extern "system" fn(
     pointerId: u32,
@@ -157,6 +160,9 @@ impl<T> WindowData<T> {
 struct ThreadMsgTargetData<T: 'static> {
     event_loop_runner: EventLoopRunnerShared<T>,
     user_event_receiver: Receiver<T>,
+    /// The monitors available at the last display change, with their names precomputed since
+    /// those can't be queried anymore once a monitor has been disconnected.
+    monitors: RefCell<Vec<(Option<String>, MonitorHandle)>>,
 }
 
 impl<T> ThreadMsgTargetData<T> {
@@ -545,6 +551,43 @@ impl<T> EventLoopWindowTarget<T> {
     pub fn listen_device_events(&self, allowed: DeviceEvents) {
         raw_input::register_all_mice_and_keyboards_for_raw_input(self.thread_msg_target, allowed);
     }
+
+    pub fn double_click_time(&self) -> Option<Duration> {
+        Some(Duration::from_millis(unsafe { GetDoubleClickTime() } as u64))
+    }
+
+    pub fn caret_blink_time(&self) -> Option<Duration> {
+        match unsafe { GetCaretBlinkTime() } {
+            0 => None,
+            INFINITE => Some(Duration::ZERO),
+            time => Some(Duration::from_millis(time as u64)),
+        }
+    }
+
+    pub fn selection_color(&self) -> Color {
+        let color = unsafe { GetSysColor(COLOR_HIGHLIGHT) };
+        Color::from_rgb(color as u8, (color >> 8) as u8, (color >> 16) as u8)
+    }
+
+    pub fn double_click_distance(&self) -> Option<PhysicalSize<u32>> {
+        system_metrics_size(SM_CXDOUBLECLK, SM_CYDOUBLECLK)
+    }
+
+    pub fn drag_threshold(&self) -> Option<PhysicalSize<u32>> {
+        system_metrics_size(SM_CXDRAG, SM_CYDRAG)
+    }
+}
+
+fn system_metrics_size(
+    x: SYSTEM_METRICS_INDEX,
+    y: SYSTEM_METRICS_INDEX,
+) -> Option<PhysicalSize<u32>> {
+    let (width, height) = unsafe { (GetSystemMetrics(x), GetSystemMetrics(y)) };
+    if width > 0 && height > 0 {
+        Some(PhysicalSize::new(width as u32, height as u32))
+    } else {
+        None
+    }
 }
 
 /// Returns the id of the main thread.
@@ -856,6 +899,7 @@ fn insert_event_target_window_data<T>(
     let userdata = ThreadMsgTargetData {
         event_loop_runner,
         user_event_receiver: rx,
+        monitors: RefCell::new(named_monitors()),
     };
     let input_ptr = Box::into_raw(Box::new(userdata));
 
@@ -892,20 +936,24 @@ fn normalize_pointer_pressure(pressure: u32) -> Option<Force> {
 /// Emit a `ModifiersChanged` event whenever modifiers have changed.
 /// Returns the current modifier state
 fn update_modifiers<T>(window: HWND, userdata: &WindowData<T>) {
-    use crate::event::WindowEvent::ModifiersChanged;
+    use crate::event::WindowEvent::{LockKeysChanged, ModifiersChanged};
 
     let modifiers = {
         let mut layouts = LAYOUT_CACHE.lock().unwrap();
         layouts.get_agnostic_mods()
     };
+    let lock_keys = get_lock_keys();
 
     let mut window_state = userdata.window_state.lock().unwrap();
-    if window_state.modifiers_state != modifiers {
-        window_state.modifiers_state = modifiers;
+    let modifiers_changed = window_state.modifiers_state != modifiers;
+    let lock_keys_changed = window_state.lock_keys != lock_keys;
+    window_state.modifiers_state = modifiers;
+    window_state.lock_keys = lock_keys;
 
-        // Drop lock
-        drop(window_state);
+    // Drop lock
+    drop(window_state);
 
+    if modifiers_changed {
         unsafe {
             userdata.send_event(Event::WindowEvent {
                 window_id: RootWindowId(WindowId(window)),
@@ -913,6 +961,15 @@ fn update_modifiers<T>(window: HWND, userdata: &WindowData<T>) {
             });
         }
     }
+
+    if lock_keys_changed {
+        unsafe {
+            userdata.send_event(Event::WindowEvent {
+                window_id: RootWindowId(WindowId(window)),
+                event: LockKeysChanged(lock_keys),
+            });
+        }
+    }
 }
 
 unsafe fn gain_active_focus<T>(window: HWND, userdata: &WindowData<T>) {
@@ -920,6 +977,13 @@ unsafe fn gain_active_focus<T>(window: HWND, userdata: &WindowData<T>) {
 
     update_modifiers(window, userdata);
 
+    // Restore the cursor grab and visibility that were released when the focus was lost.
+    userdata
+        .window_state_lock()
+        .mouse
+        .set_cursor_flags(window, |_| ())
+        .ok();
+
     userdata.send_event(Event::WindowEvent {
         window_id: RootWindowId(WindowId(window)),
         event: Focused(true),
@@ -929,7 +993,18 @@ unsafe fn gain_active_focus<T>(window: HWND, userdata: &WindowData<T>) {
 unsafe fn lose_active_focus<T>(window: HWND, userdata: &WindowData<T>) {
     use crate::event::WindowEvent::{Focused, ModifiersChanged};
 
-    userdata.window_state_lock().modifiers_state = ModifiersState::empty();
+    {
+        let mut w = userdata.window_state_lock();
+        w.modifiers_state = ModifiersState::empty();
+
+        // The cursor clip is global, so release it while another window has the focus, and show
+        // the cursor since it won't be hidden again until the focus comes back.
+        if w.mouse.cursor_flags().contains(CursorFlags::GRABBED) {
+            util::set_cursor_clip(None).ok();
+        }
+        util::set_cursor_hidden(false);
+    }
+
     userdata.send_event(Event::WindowEvent {
         window_id: RootWindowId(WindowId(window)),
         event: ModifiersChanged(ModifiersState::empty().into()),
@@ -1035,7 +1110,12 @@ unsafe fn public_window_callback_inner<T: 'static>(
             userdata
                 .key_event_builder
                 .process_message(window, msg, wparam, lparam, &mut result);
+        let key_repeat = userdata.window_state_lock().key_repeat;
         for event in events {
+            if event.event.repeat && !key_repeat {
+                continue;
+            }
+
             userdata.send_event(Event::WindowEvent {
                 window_id: RootWindowId(WindowId(window)),
                 event: KeyboardInput {
@@ -1150,7 +1230,10 @@ unsafe fn public_window_callback_inner<T: 'static>(
                 // redraw the window outside the normal flow of the event loop.
                 RedrawWindow(window, ptr::null(), 0, RDW_INTERNALPAINT);
             } else {
-                userdata.send_event(Event::RedrawRequested(RootWindowId(WindowId(window))));
+                userdata.send_event(Event::RedrawRequested {
+                    window_id: RootWindowId(WindowId(window)),
+                    deadline: util::next_vblank(),
+                });
             }
             result = ProcResult::DefWindowProc(wparam);
         }
@@ -1307,7 +1390,7 @@ unsafe fn public_window_callback_inner<T: 'static>(
                 if lparam == 0 {
                     userdata.send_event(Event::WindowEvent {
                         window_id: RootWindowId(WindowId(window)),
-                        event: WindowEvent::Ime(Ime::Preedit(String::new(), None)),
+                        event: WindowEvent::Ime(Ime::Preedit(String::new(), None, Vec::new())),
                     });
                 }
 
@@ -1319,7 +1402,7 @@ unsafe fn public_window_callback_inner<T: 'static>(
 
                         userdata.send_event(Event::WindowEvent {
                             window_id: RootWindowId(WindowId(window)),
-                            event: WindowEvent::Ime(Ime::Preedit(String::new(), None)),
+                            event: WindowEvent::Ime(Ime::Preedit(String::new(), None, Vec::new())),
                         });
                         userdata.send_event(Event::WindowEvent {
                             window_id: RootWindowId(WindowId(window)),
@@ -1330,13 +1413,15 @@ unsafe fn public_window_callback_inner<T: 'static>(
 
                 // Next, receive preedit range for next composing if exist.
                 if (lparam as u32 & GCS_COMPSTR) != 0 {
-                    if let Some((text, first, last)) = ime_context.get_composing_text_and_cursor() {
+                    if let Some((text, first, last, spans)) =
+                        ime_context.get_composing_text_and_cursor()
+                    {
                         userdata.window_state_lock().ime_state = ImeState::Preedit;
                         let cursor_range = first.map(|f| (f, last.unwrap_or(f)));
 
                         userdata.send_event(Event::WindowEvent {
                             window_id: RootWindowId(WindowId(window)),
-                            event: WindowEvent::Ime(Ime::Preedit(text, cursor_range)),
+                            event: WindowEvent::Ime(Ime::Preedit(text, cursor_range, spans)),
                         });
                     }
                 }
@@ -1359,7 +1444,7 @@ unsafe fn public_window_callback_inner<T: 'static>(
                     if let Some(text) = ime_context.get_composed_text() {
                         userdata.send_event(Event::WindowEvent {
                             window_id: RootWindowId(WindowId(window)),
-                            event: WindowEvent::Ime(Ime::Preedit(String::new(), None)),
+                            event: WindowEvent::Ime(Ime::Preedit(String::new(), None, Vec::new())),
                         });
                         userdata.send_event(Event::WindowEvent {
                             window_id: RootWindowId(WindowId(window)),
@@ -1676,7 +1761,7 @@ unsafe fn public_window_callback_inner<T: 'static>(
                     button: match xbutton {
                         1 => Back,
                         2 => Forward,
-                        _ => Other(xbutton),
+                        _ => Other(xbutton + 2),
                     },
                 },
             });
@@ -1702,7 +1787,7 @@ unsafe fn public_window_callback_inner<T: 'static>(
                     button: match xbutton {
                         1 => Back,
                         2 => Forward,
-                        _ => Other(xbutton),
+                        _ => Other(xbutton + 2),
                     },
                 },
             });
@@ -1940,6 +2025,41 @@ unsafe fn public_window_callback_inner<T: 'static>(
             result = ProcResult::Value(0);
         }
 
+        WM_NCHITTEST => {
+            let mut point = POINT {
+                x: super::get_x_lparam(lparam as u32) as i32,
+                y: super::get_y_lparam(lparam as u32) as i32,
+            };
+
+            let hit = if ScreenToClient(window, &mut point) == false.into() {
+                None
+            } else {
+                let position = PhysicalPosition::new(point.x as f64, point.y as f64);
+
+                // The lock isn't held while the callback runs, as it may use the window.
+                let callback = userdata.window_state_lock().hittest_callback.clone();
+                callback
+                    .and_then(|callback| callback(position))
+                    .or_else(|| {
+                        let window_state = userdata.window_state_lock();
+                        HitTestRegion::hit(
+                            &window_state.hittest_regions,
+                            position,
+                            window_state.scale_factor,
+                        )
+                    })
+            };
+
+            // Reporting the regions as the frame gives them the behavior of the system's.
+            result = match hit {
+                Some(HitTestKind::TitleBar) => ProcResult::Value(HTCAPTION as isize),
+                Some(HitTestKind::Resize(direction)) => {
+                    ProcResult::Value(resize_direction_hittest(direction) as isize)
+                }
+                None => ProcResult::DefWindowProc(wparam),
+            };
+        }
+
         WM_SETCURSOR => {
             let set_cursor_to = {
                 let window_state = userdata.window_state_lock();
@@ -2294,8 +2414,12 @@ unsafe extern "system" fn thread_event_target_callback<T: 'static>(
 
         WM_INPUT_DEVICE_CHANGE => {
             let event = match wparam as u32 {
-                GIDC_ARRIVAL => DeviceEvent::Added,
-                GIDC_REMOVAL => DeviceEvent::Removed,
+                GIDC_ARRIVAL => DeviceEvent::Added {
+                    kind: raw_input::get_raw_input_device_kind(lparam as _),
+                },
+                GIDC_REMOVAL => DeviceEvent::Removed {
+                    kind: DeviceKind::Other,
+                },
                 _ => unreachable!(),
             };
 
@@ -2315,6 +2439,44 @@ unsafe extern "system" fn thread_event_target_callback<T: 'static>(
             DefWindowProcW(window, msg, wparam, lparam)
         }
 
+        // The process may be terminated any time after returning from `WM_ENDSESSION`, so this
+        // is the only place to tell the application, rather than `WM_QUERYENDSESSION`, which
+        // could still be cancelled by another application.
+        WM_ENDSESSION => {
+            if wparam != 0 {
+                userdata.send_event(Event::SessionEnd);
+            }
+            0
+        }
+
+        // Broadcast to all top-level windows, which the thread event target is, whenever the
+        // display configuration changes, including when a monitor is connected or disconnected.
+        WM_DISPLAYCHANGE => {
+            let monitors = named_monitors();
+            let prev = userdata.monitors.replace(Vec::new());
+            let (disconnected, connected) =
+                crate::monitor::monitor_changes(&prev, &monitors, |a, b| a.0 == b.0);
+            let events = disconnected
+                .into_iter()
+                .map(|(_, monitor)| (monitor, MonitorEvent::Disconnected))
+                .chain(
+                    connected
+                        .into_iter()
+                        .map(|(_, monitor)| (monitor, MonitorEvent::Connected)),
+                );
+            for (monitor, event) in events {
+                userdata.send_event(Event::MonitorEvent {
+                    monitor: RootMonitorHandle {
+                        inner: monitor.clone(),
+                    },
+                    event,
+                });
+            }
+            *userdata.monitors.borrow_mut() = monitors;
+
+            DefWindowProcW(window, msg, wparam, lparam)
+        }
+
         _ if msg == USER_EVENT_MSG_ID.get() => {
             if let Ok(event) = userdata.user_event_receiver.recv() {
                 userdata.send_event(Event::UserEvent(event));
@@ -2393,14 +2555,30 @@ unsafe fn handle_raw_input<T: 'static>(userdata: &ThreadMsgTargetData<T>, data:
             });
         }
 
+        if util::has_flag(button_flags as u32, RI_MOUSE_HWHEEL) {
+            let button_data = mouse.Anonymous.Anonymous.usButtonData;
+            // NOTE: inverted, like `WM_MOUSEHWHEEL`.
+            let delta = -(button_data as i16 as f32) / WHEEL_DELTA as f32;
+            userdata.send_event(Event::DeviceEvent {
+                device_id,
+                event: MouseWheel {
+                    delta: LineDelta(delta, 0.0),
+                },
+            });
+        }
+
         let button_state = raw_input::get_raw_mouse_button_state(button_flags as u32);
-        // Left, middle, and right, respectively.
+        // Left, middle, right, back and forward, respectively.
         for (index, state) in button_state.iter().enumerate() {
             if let Some(state) = *state {
                 // This gives us consistency with X11, since there doesn't
                 // seem to be anything else reasonable to do for a mouse
-                // button ID.
-                let button = (index + 1) as _;
+                // button ID. X11 reports the scroll wheel as buttons 4 to 7,
+                // so back and forward are 8 and 9.
+                let button = match index {
+                    0..=2 => index + 1,
+                    _ => index + 5,
+                } as _;
                 userdata.send_event(Event::DeviceEvent {
                     device_id,
                     event: Button { button, state },
@@ -20,6 +20,7 @@ use windows_sys::Win32::{
 
 use crate::{
     dpi::{Position, Size},
+    event::{PreeditSpan, PreeditStyle},
     platform::windows::HWND,
 };
 
@@ -36,12 +37,13 @@ impl ImeContext {
 
     pub unsafe fn get_composing_text_and_cursor(
         &self,
-    ) -> Option<(String, Option<usize>, Option<usize>)> {
+    ) -> Option<(String, Option<usize>, Option<usize>, Vec<PreeditSpan>)> {
         let text = self.get_composition_string(GCS_COMPSTR)?;
         let attrs = self.get_composition_data(GCS_COMPATTR).unwrap_or_default();
 
         let mut first = None;
         let mut last = None;
+        let mut spans: Vec<PreeditSpan> = Vec::new();
         let mut boundary_before_char = 0;
 
         for (attr, chr) in attrs.into_iter().zip(text.chars()) {
@@ -54,7 +56,23 @@ impl ImeContext {
                 last = Some(boundary_before_char);
             }
 
-            boundary_before_char += chr.len_utf8();
+            let style = if char_is_targetted {
+                PreeditStyle::Highlight
+            } else {
+                PreeditStyle::Underline
+            };
+            let char_end = boundary_before_char + chr.len_utf8();
+            match spans.last_mut() {
+                Some(span) if span.style == style && span.range.end == boundary_before_char => {
+                    span.range.end = char_end;
+                }
+                _ => spans.push(PreeditSpan {
+                    range: boundary_before_char..char_end,
+                    style,
+                }),
+            }
+
+            boundary_before_char = char_end;
         }
 
         if first.is_some() && last.is_none() {
@@ -66,7 +84,7 @@ impl ImeContext {
             last = cursor;
         }
 
-        Some((text, first, last))
+        Some((text, first, last, spans))
     }
 
     pub unsafe fn get_composed_text(&self) -> Option<String> {
@@ -76,12 +76,18 @@ pub struct MessageAsKeyEvent {
 pub struct KeyEventBuilder {
     event_info: Mutex<Option<PartialKeyEventInfo>>,
     pending: PendingEventQueue<MessageAsKeyEvent>,
+    /// A high surrogate whose low surrogate arrives with the next key event.
+    ///
+    /// This is how `VK_PACKET` input, e.g. from the emoji picker, delivers characters outside of
+    /// the basic multilingual plane: each UTF-16 code unit comes with its own key down message.
+    high_surrogate: Mutex<Option<u16>>,
 }
 impl Default for KeyEventBuilder {
     fn default() -> Self {
         KeyEventBuilder {
             event_info: Mutex::new(None),
             pending: Default::default(),
+            high_surrogate: Mutex::new(None),
         }
     }
 }
@@ -193,34 +199,14 @@ impl KeyEventBuilder {
                     }
                     let pending_token = self.pending.add_pending();
                     *result = ProcResult::Value(0);
-                    let is_high_surrogate = (0xD800..=0xDBFF).contains(&wparam);
-                    let is_low_surrogate = (0xDC00..=0xDFFF).contains(&wparam);
-
-                    let is_utf16 = is_high_surrogate || is_low_surrogate;
-
-                    if is_utf16 {
-                        if let Some(ev_info) = event_info.as_mut() {
-                            ev_info.utf16parts.push(wparam as u16);
-                        }
-                    } else {
-                        // In this case, wparam holds a UTF-32 character.
-                        // Let's encode it as UTF-16 and append it to the end of `utf16parts`
-                        let utf16parts = match event_info.as_mut() {
-                            Some(ev_info) => &mut ev_info.utf16parts,
-                            None => {
-                                warn!("The event_info was None when it was expected to be some");
-                                return MatchResult::TokenToRemove(pending_token);
-                            }
-                        };
-                        let start_offset = utf16parts.len();
-                        let new_size = utf16parts.len() + 2;
-                        utf16parts.resize(new_size, 0);
-                        if let Some(ch) = char::from_u32(wparam as u32) {
-                            let encode_len = ch.encode_utf16(&mut utf16parts[start_offset..]).len();
-                            let new_size = start_offset + encode_len;
-                            utf16parts.resize(new_size, 0);
+                    let utf16parts = match event_info.as_mut() {
+                        Some(ev_info) => &mut ev_info.utf16parts,
+                        None => {
+                            warn!("The event_info was None when it was expected to be some");
+                            return MatchResult::TokenToRemove(pending_token);
                         }
-                    }
+                    };
+                    push_char_message(&mut self.high_surrogate.lock().unwrap(), utf16parts, wparam);
                     // It's important that we unlock the mutex, and create the pending event token before
                     // calling `next_msg`
                     std::mem::drop(event_info);
@@ -241,6 +227,11 @@ impl KeyEventBuilder {
                                 return MatchResult::TokenToRemove(pending_token);
                             }
                         };
+                        // A lone high surrogate can't be turned into text, so hand it over to the
+                        // key event that carries the matching low surrogate.
+                        if matches!(event_info.utf16parts.last(), Some(0xD800..=0xDBFF)) {
+                            *self.high_surrogate.lock().unwrap() = event_info.utf16parts.pop();
+                        }
                         let mut layouts = LAYOUT_CACHE.lock().unwrap();
                         // It's okay to call `ToUnicode` here, because at this point the dead key
                         // is already consumed by the character.
@@ -475,6 +466,7 @@ impl KeyEventBuilder {
             key_state,
             is_repeat: false,
             code,
+            scancode,
             location: get_location(scancode, locale_id),
             utf16parts: Vec::with_capacity(8),
             text: PartialText::Text(text.clone()),
@@ -512,6 +504,7 @@ struct PartialKeyEventInfo {
     key_state: ElementState,
     is_repeat: bool,
     code: KeyCode,
+    scancode: ExScancode,
     location: KeyLocation,
     logical_key: PartialLogicalKey,
 
@@ -610,6 +603,7 @@ impl PartialKeyEventInfo {
             key_without_modifiers,
             is_repeat: lparam_struct.is_repeat,
             code,
+            scancode,
             location,
             utf16parts: Vec::with_capacity(8),
             text: PartialText::System(Vec::new()),
@@ -665,6 +659,7 @@ impl PartialKeyEventInfo {
             platform_specific: KeyEventExtra {
                 text_with_all_modifers: char_with_all_modifiers,
                 key_without_modifiers: self.key_without_modifiers,
+                raw_scancode: self.scancode as u32,
             },
         }
     }
@@ -710,6 +705,34 @@ fn get_kbd_state() -> [u8; 256] {
     }
 }
 
+/// Append the text of a WM_CHAR / WM_SYSCHAR message to the text of the current key event.
+///
+/// A low surrogate that starts the text is joined with the high surrogate kept from the previous
+/// key event, any other message drops that high surrogate.
+fn push_char_message(high_surrogate: &mut Option<u16>, utf16parts: &mut Vec<u16>, wparam: WPARAM) {
+    let high = high_surrogate.take();
+    let is_high_surrogate = (0xD800..=0xDBFF).contains(&wparam);
+    let is_low_surrogate = (0xDC00..=0xDFFF).contains(&wparam);
+
+    if is_high_surrogate || is_low_surrogate {
+        if is_low_surrogate && utf16parts.is_empty() {
+            utf16parts.extend(high);
+        }
+        utf16parts.push(wparam as u16);
+    } else {
+        // In this case, wparam holds a UTF-32 character.
+        // Let's encode it as UTF-16 and append it to the end of `utf16parts`
+        let start_offset = utf16parts.len();
+        let new_size = utf16parts.len() + 2;
+        utf16parts.resize(new_size, 0);
+        if let Some(ch) = char::from_u32(wparam as u32) {
+            let encode_len = ch.encode_utf16(&mut utf16parts[start_offset..]).len();
+            let new_size = start_offset + encode_len;
+            utf16parts.resize(new_size, 0);
+        }
+    }
+}
+
 /// Gets the current keyboard state regardless of whether the corresponding keyboard events have
 /// been removed from the event queue. See also: get_kbd_state
 #[allow(clippy::uninit_assumed_init)]
@@ -1270,3 +1293,45 @@ impl KeyCodeExtScancode for KeyCode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_all(high_surrogate: &mut Option<u16>, messages: &[WPARAM]) -> Vec<u16> {
+        let mut utf16parts = Vec::new();
+        for &wparam in messages {
+            push_char_message(high_surrogate, &mut utf16parts, wparam);
+        }
+        utf16parts
+    }
+
+    #[test]
+    fn push_char_message_joins_the_kept_high_surrogate() {
+        let mut high_surrogate = Some(0xD83D);
+        let utf16parts = push_all(&mut high_surrogate, &[0xDE00]);
+
+        assert_eq!(String::from_utf16(&utf16parts).unwrap(), "\u{1F600}");
+        assert_eq!(high_surrogate, None);
+    }
+
+    #[test]
+    fn push_char_message_drops_the_kept_high_surrogate_on_other_text() {
+        let mut high_surrogate = Some(0xD83D);
+        let utf16parts = push_all(&mut high_surrogate, &['a' as WPARAM]);
+        assert_eq!(utf16parts, ['a' as u16]);
+        assert_eq!(high_surrogate, None);
+
+        // The low surrogate of a later key event mustn't pick up the stale high surrogate.
+        let utf16parts = push_all(&mut high_surrogate, &[0xDE00]);
+        assert_eq!(utf16parts, [0xDE00]);
+    }
+
+    #[test]
+    fn push_char_message_keeps_surrogate_pairs_of_the_same_event() {
+        let mut high_surrogate = None;
+        let utf16parts = push_all(&mut high_surrogate, &[0xD83D, 0xDE00]);
+
+        assert_eq!(String::from_utf16(&utf16parts).unwrap(), "\u{1F600}");
+    }
+}
@@ -52,7 +52,7 @@ use windows_sys::Win32::{
 };
 
 use crate::{
-    keyboard::{Key, KeyCode, ModifiersState, NativeKey},
+    keyboard::{Key, KeyCode, LockKeyState, ModifiersState, NativeKey},
     platform::scancode::KeyCodeExtScancode,
     platform_impl::{loword, primarylangid},
 };
@@ -64,6 +64,41 @@ fn key_pressed(vkey: VIRTUAL_KEY) -> bool {
     unsafe { (GetKeyState(vkey as i32) & (1 << 15)) == (1 << 15) }
 }
 
+/// The modifiers held according to `key_pressed`, on a layout which may have an AltGr key.
+fn agnostic_mods(has_alt_graph: bool, key_pressed: impl Fn(VIRTUAL_KEY) -> bool) -> ModifiersState {
+    let alt_graph = has_alt_graph && key_pressed(VK_RMENU);
+    let mut mods = ModifiersState::empty();
+    mods.set(ModifiersState::SHIFT, key_pressed(VK_SHIFT));
+    // AltGr comes with a fake left Ctrl press, so only the keys it doesn't use count while
+    // it's held.
+    mods.set(
+        ModifiersState::CONTROL,
+        key_pressed(VK_RCONTROL) || (key_pressed(VK_LCONTROL) && !alt_graph),
+    );
+    mods.set(
+        ModifiersState::ALT,
+        key_pressed(VK_LMENU) || (key_pressed(VK_RMENU) && !alt_graph),
+    );
+    mods.set(ModifiersState::ALT_GRAPH, alt_graph);
+    mods.set(
+        ModifiersState::SUPER,
+        key_pressed(VK_LWIN) || key_pressed(VK_RWIN),
+    );
+    mods
+}
+
+fn key_toggled(vkey: VIRTUAL_KEY) -> bool {
+    unsafe { (GetKeyState(vkey as i32) & 1) == 1 }
+}
+
+pub(crate) fn get_lock_keys() -> LockKeyState {
+    let mut lock_keys = LockKeyState::empty();
+    lock_keys.set(LockKeyState::CAPS_LOCK, key_toggled(VK_CAPITAL));
+    lock_keys.set(LockKeyState::NUM_LOCK, key_toggled(VK_NUMLOCK));
+    lock_keys.set(LockKeyState::SCROLL_LOCK, key_toggled(VK_SCROLL));
+    lock_keys
+}
+
 const NUMPAD_VKEYS: [VIRTUAL_KEY; 16] = [
     VK_NUMPAD0,
     VK_NUMPAD1,
@@ -284,22 +319,7 @@ impl LayoutCache {
 
     pub fn get_agnostic_mods(&mut self) -> ModifiersState {
         let (_, layout) = self.get_current_layout();
-        let filter_out_altgr = layout.has_alt_graph && key_pressed(VK_RMENU);
-        let mut mods = ModifiersState::empty();
-        mods.set(ModifiersState::SHIFT, key_pressed(VK_SHIFT));
-        mods.set(
-            ModifiersState::CONTROL,
-            key_pressed(VK_CONTROL) && !filter_out_altgr,
-        );
-        mods.set(
-            ModifiersState::ALT,
-            key_pressed(VK_MENU) && !filter_out_altgr,
-        );
-        mods.set(
-            ModifiersState::SUPER,
-            key_pressed(VK_LWIN) || key_pressed(VK_RWIN),
-        );
-        mods
+        agnostic_mods(layout.has_alt_graph, key_pressed)
     }
 
     fn prepare_layout(locale_id: u64) -> Layout {
@@ -996,3 +1016,42 @@ fn vkey_to_non_char_key(
         _ => Key::Unidentified(native_code),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pressed(keys: &[VIRTUAL_KEY]) -> impl Fn(VIRTUAL_KEY) -> bool + '_ {
+        // The generic keys are pressed along with their left and right variants.
+        move |vkey| {
+            keys.iter().any(|&key| {
+                key == vkey
+                    || (vkey == VK_SHIFT && matches!(key, VK_LSHIFT | VK_RSHIFT))
+                    || (vkey == VK_CONTROL && matches!(key, VK_LCONTROL | VK_RCONTROL))
+                    || (vkey == VK_MENU && matches!(key, VK_LMENU | VK_RMENU))
+            })
+        }
+    }
+
+    #[test]
+    fn altgr_is_not_ctrl_alt_on_german_layout() {
+        // AltGr comes with a fake left Ctrl press.
+        let mods = agnostic_mods(true, pressed(&[VK_LCONTROL, VK_RMENU]));
+        assert_eq!(mods, ModifiersState::ALT_GRAPH);
+    }
+
+    #[test]
+    fn ctrl_alt_is_not_altgr_on_german_layout() {
+        let mods = agnostic_mods(true, pressed(&[VK_LCONTROL, VK_LMENU]));
+        assert_eq!(mods, ModifiersState::CONTROL | ModifiersState::ALT);
+
+        let mods = agnostic_mods(true, pressed(&[VK_RCONTROL, VK_RMENU]));
+        assert_eq!(mods, ModifiersState::CONTROL | ModifiersState::ALT_GRAPH);
+    }
+
+    #[test]
+    fn right_alt_is_alt_on_us_layout() {
+        let mods = agnostic_mods(false, pressed(&[VK_LCONTROL, VK_RMENU]));
+        assert_eq!(mods, ModifiersState::CONTROL | ModifiersState::ALT);
+    }
+}
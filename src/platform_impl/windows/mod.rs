@@ -15,12 +15,16 @@ pub(crate) use self::{
     window::Window,
 };
 
+#[cfg(feature = "single-instance")]
+pub(crate) use self::single_instance::SingleInstance;
+
 pub use self::icon::WinIcon as PlatformIcon;
 pub(self) use crate::platform_impl::Fullscreen;
 
 use crate::event::DeviceId as RootDeviceId;
 use crate::icon::Icon;
 use crate::keyboard::Key;
+use crate::platform::windows::Color;
 
 #[derive(Clone)]
 pub struct PlatformSpecificWindowBuilderAttributes {
@@ -32,6 +36,9 @@ pub struct PlatformSpecificWindowBuilderAttributes {
     pub skip_taskbar: bool,
     pub class_name: String,
     pub decoration_shadow: bool,
+    pub border_color: Option<Color>,
+    pub title_bar_color: Option<Color>,
+    pub title_text_color: Option<Color>,
 }
 
 impl Default for PlatformSpecificWindowBuilderAttributes {
@@ -45,6 +52,9 @@ impl Default for PlatformSpecificWindowBuilderAttributes {
             skip_taskbar: false,
             class_name: "Window Class".to_string(),
             decoration_shadow: false,
+            border_color: None,
+            title_bar_color: None,
+            title_text_color: None,
         }
     }
 }
@@ -90,6 +100,14 @@ pub type OsError = std::io::Error;
 pub struct KeyEventExtra {
     pub text_with_all_modifers: Option<SmolStr>,
     pub key_without_modifiers: Key,
+    pub raw_scancode: u32,
+}
+
+impl KeyEventExtra {
+    #[inline]
+    pub fn raw_scancode(&self) -> Option<u32> {
+        Some(self.raw_scancode)
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -184,5 +202,7 @@ mod keyboard;
 mod keyboard_layout;
 mod monitor;
 mod raw_input;
+#[cfg(feature = "single-instance")]
+mod single_instance;
 mod window;
 mod window_state;
@@ -5,7 +5,13 @@ use std::{
 };
 
 use windows_sys::Win32::{
-    Foundation::{BOOL, HWND, LPARAM, POINT, RECT},
+    Devices::Display::{
+        DisplayConfigGetDeviceInfo, GetDisplayConfigBufferSizes, QueryDisplayConfig,
+        DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME, DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+        DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_SOURCE_DEVICE_NAME,
+        DISPLAYCONFIG_TARGET_DEVICE_NAME, QDC_ONLY_ACTIVE_PATHS,
+    },
+    Foundation::{BOOL, ERROR_SUCCESS, HWND, LPARAM, POINT, RECT},
     Graphics::Gdi::{
         EnumDisplayMonitors, EnumDisplaySettingsExW, GetMonitorInfoW, MonitorFromPoint,
         MonitorFromWindow, DEVMODEW, DM_BITSPERPEL, DM_DISPLAYFREQUENCY, DM_PELSHEIGHT,
@@ -17,7 +23,7 @@ use windows_sys::Win32::{
 use super::util::decode_wide;
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize},
-    monitor::VideoMode as RootVideoMode,
+    monitor::{decode_pnp_id, VideoMode as RootVideoMode},
     platform_impl::platform::{
         dpi::{dpi_to_scale_factor, get_monitor_dpi},
         util::has_flag,
@@ -140,6 +146,62 @@ impl Window {
     }
 }
 
+/// Finds the display configuration target, i.e. the monitor itself, that the GDI device of
+/// `hmonitor` is connected to.
+fn get_target_device_name(hmonitor: HMONITOR) -> Option<DISPLAYCONFIG_TARGET_DEVICE_NAME> {
+    let device = decode_wide(&get_monitor_info(hmonitor).ok()?.szDevice);
+
+    let mut path_count = 0;
+    let mut mode_count = 0;
+    let status = unsafe {
+        GetDisplayConfigBufferSizes(QDC_ONLY_ACTIVE_PATHS, &mut path_count, &mut mode_count)
+    };
+    if status != ERROR_SUCCESS {
+        return None;
+    }
+
+    let mut paths = Vec::with_capacity(path_count as usize);
+    let mut modes = Vec::with_capacity(mode_count as usize);
+    let status = unsafe {
+        QueryDisplayConfig(
+            QDC_ONLY_ACTIVE_PATHS,
+            &mut path_count,
+            paths.as_mut_ptr(),
+            &mut mode_count,
+            modes.as_mut_ptr(),
+            ptr::null_mut(),
+        )
+    };
+    if status != ERROR_SUCCESS {
+        return None;
+    }
+    unsafe { paths.set_len(path_count as usize) };
+
+    paths.iter().find_map(|path| {
+        let mut source: DISPLAYCONFIG_SOURCE_DEVICE_NAME = unsafe { mem::zeroed() };
+        source.header = DISPLAYCONFIG_DEVICE_INFO_HEADER {
+            r#type: DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME,
+            size: mem::size_of::<DISPLAYCONFIG_SOURCE_DEVICE_NAME>() as u32,
+            adapterId: path.sourceInfo.adapterId,
+            id: path.sourceInfo.id,
+        };
+        if unsafe { DisplayConfigGetDeviceInfo(&mut source.header) } != 0
+            || decode_wide(&source.viewGdiDeviceName) != device
+        {
+            return None;
+        }
+
+        let mut target: DISPLAYCONFIG_TARGET_DEVICE_NAME = unsafe { mem::zeroed() };
+        target.header = DISPLAYCONFIG_DEVICE_INFO_HEADER {
+            r#type: DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+            size: mem::size_of::<DISPLAYCONFIG_TARGET_DEVICE_NAME>() as u32,
+            adapterId: path.targetInfo.adapterId,
+            id: path.targetInfo.id,
+        };
+        (unsafe { DisplayConfigGetDeviceInfo(&mut target.header) } == 0).then_some(target)
+    })
+}
+
 pub(crate) fn get_monitor_info(hmonitor: HMONITOR) -> Result<MONITORINFOEXW, io::Error> {
     let mut monitor_info: MONITORINFOEXW = unsafe { mem::zeroed() };
     monitor_info.monitorInfo.cbSize = mem::size_of::<MONITORINFOEXW>() as u32;
@@ -171,6 +233,37 @@ impl MonitorHandle {
         )
     }
 
+    #[inline]
+    pub fn friendly_name(&self) -> Option<String> {
+        self.model()
+    }
+
+    #[inline]
+    pub fn make(&self) -> Option<String> {
+        let target = get_target_device_name(self.0)?;
+        // `edidIdsValid`
+        if unsafe { target.flags.Anonymous.value } & 0b100 == 0 {
+            return None;
+        }
+
+        // Unlike in the EDID, the ID is stored in little endian.
+        decode_pnp_id(target.edidManufactureId.swap_bytes())
+    }
+
+    #[inline]
+    pub fn model(&self) -> Option<String> {
+        let target = get_target_device_name(self.0)?;
+        let name = decode_wide(&target.monitorFriendlyDeviceName)
+            .to_string_lossy()
+            .to_string();
+        (!name.is_empty()).then_some(name)
+    }
+
+    #[inline]
+    pub fn physical_size_mm(&self) -> Option<(u32, u32)> {
+        None
+    }
+
     #[inline]
     pub fn native_identifier(&self) -> String {
         self.name().unwrap()
@@ -216,6 +309,18 @@ impl MonitorHandle {
         }
     }
 
+    #[inline]
+    pub fn work_area(&self) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>)> {
+        let rc_work = get_monitor_info(self.0).ok()?.monitorInfo.rcWork;
+        Some((
+            PhysicalPosition::new(rc_work.left, rc_work.top),
+            PhysicalSize::new(
+                (rc_work.right - rc_work.left) as u32,
+                (rc_work.bottom - rc_work.top) as u32,
+            ),
+        ))
+    }
+
     #[inline]
     pub fn scale_factor(&self) -> f64 {
         dpi_to_scale_factor(get_monitor_dpi(self.0).unwrap_or(96))
@@ -17,13 +17,19 @@ use windows_sys::Win32::{
             RID_DEVICE_INFO_MOUSE, RID_INPUT, RIM_TYPEHID, RIM_TYPEKEYBOARD, RIM_TYPEMOUSE,
         },
         WindowsAndMessaging::{
-            RI_MOUSE_LEFT_BUTTON_DOWN, RI_MOUSE_LEFT_BUTTON_UP, RI_MOUSE_MIDDLE_BUTTON_DOWN,
-            RI_MOUSE_MIDDLE_BUTTON_UP, RI_MOUSE_RIGHT_BUTTON_DOWN, RI_MOUSE_RIGHT_BUTTON_UP,
+            RI_MOUSE_BUTTON_4_DOWN, RI_MOUSE_BUTTON_4_UP, RI_MOUSE_BUTTON_5_DOWN,
+            RI_MOUSE_BUTTON_5_UP, RI_MOUSE_LEFT_BUTTON_DOWN, RI_MOUSE_LEFT_BUTTON_UP,
+            RI_MOUSE_MIDDLE_BUTTON_DOWN, RI_MOUSE_MIDDLE_BUTTON_UP, RI_MOUSE_RIGHT_BUTTON_DOWN,
+            RI_MOUSE_RIGHT_BUTTON_UP,
         },
     },
 };
 
-use crate::{event::ElementState, event_loop::DeviceEvents, platform_impl::platform::util};
+use crate::{
+    event::{DeviceKind, ElementState},
+    event_loop::DeviceEvents,
+    platform_impl::platform::util,
+};
 
 #[allow(dead_code)]
 pub fn get_raw_input_device_list() -> Option<Vec<RAWINPUTDEVICELIST>> {
@@ -72,7 +78,25 @@ impl From<RID_DEVICE_INFO> for RawDeviceInfo {
     }
 }
 
-#[allow(dead_code)]
+/// Get the kind of device from the raw input device info.
+pub fn get_raw_input_device_kind(handle: HANDLE) -> DeviceKind {
+    // The HID usage of touch screens in the digitizer usage page.
+    const HID_USAGE_PAGE_DIGITIZER: u16 = 0x0D;
+    const HID_USAGE_DIGITIZER_TOUCH_SCREEN: u16 = 0x04;
+
+    match get_raw_input_device_info(handle) {
+        Some(RawDeviceInfo::Mouse(_)) => DeviceKind::Pointer,
+        Some(RawDeviceInfo::Keyboard(_)) => DeviceKind::Keyboard,
+        Some(RawDeviceInfo::Hid(hid))
+            if hid.usUsagePage == HID_USAGE_PAGE_DIGITIZER
+                && hid.usUsage == HID_USAGE_DIGITIZER_TOUCH_SCREEN =>
+        {
+            DeviceKind::Touch
+        }
+        _ => DeviceKind::Other,
+    }
+}
+
 pub fn get_raw_input_device_info(handle: HANDLE) -> Option<RawDeviceInfo> {
     let mut info: RID_DEVICE_INFO = unsafe { mem::zeroed() };
     let info_size = size_of::<RID_DEVICE_INFO>() as u32;
@@ -209,7 +233,7 @@ fn button_flags_to_element_state(
     }
 }
 
-pub fn get_raw_mouse_button_state(button_flags: u32) -> [Option<ElementState>; 3] {
+pub fn get_raw_mouse_button_state(button_flags: u32) -> [Option<ElementState>; 5] {
     [
         button_flags_to_element_state(
             button_flags,
@@ -226,5 +250,7 @@ pub fn get_raw_mouse_button_state(button_flags: u32) -> [Option<ElementState>; 3
             RI_MOUSE_RIGHT_BUTTON_DOWN,
             RI_MOUSE_RIGHT_BUTTON_UP,
         ),
+        button_flags_to_element_state(button_flags, RI_MOUSE_BUTTON_4_DOWN, RI_MOUSE_BUTTON_4_UP),
+        button_flags_to_element_state(button_flags, RI_MOUSE_BUTTON_5_DOWN, RI_MOUSE_BUTTON_5_UP),
     ]
 }
@@ -0,0 +1,143 @@
+//! Single-instance support through a named mutex and a named pipe.
+
+use std::ffi::OsStr;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::os::windows::io::{FromRawHandle, RawHandle};
+use std::time::Duration;
+use std::{ptr, thread};
+
+use windows_sys::Win32::{
+    Foundation::{
+        CloseHandle, GetLastError, ERROR_ALREADY_EXISTS, ERROR_PIPE_BUSY, ERROR_PIPE_CONNECTED,
+        HANDLE, INVALID_HANDLE_VALUE,
+    },
+    Storage::FileSystem::PIPE_ACCESS_INBOUND,
+    System::{
+        Pipes::{
+            ConnectNamedPipe, CreateNamedPipeW, PIPE_READMODE_BYTE, PIPE_REJECT_REMOTE_CLIENTS,
+            PIPE_TYPE_BYTE, PIPE_UNLIMITED_INSTANCES, PIPE_WAIT,
+        },
+        RemoteDesktop::ProcessIdToSessionId,
+        Threading::{CreateMutexW, GetCurrentProcessId},
+    },
+};
+
+use super::util::encode_wide;
+
+/// How long a later instance waits for the primary one to start listening.
+const CONNECT_ATTEMPTS: u32 = 20;
+const CONNECT_INTERVAL: Duration = Duration::from_millis(50);
+
+const PIPE_BUFFER_SIZE: u32 = 64 * 1024;
+
+#[derive(Debug)]
+pub struct SingleInstance {
+    // Holds the claim as long as it is open.
+    mutex: HANDLE,
+    pipe_name: Vec<u16>,
+}
+
+// The mutex handle is only closed again.
+unsafe impl Send for SingleInstance {}
+
+impl SingleInstance {
+    pub fn acquire(id: &str, message: &[u8]) -> io::Result<Option<Self>> {
+        // The `Local` namespace of the mutex is per session, but pipe names are global to the
+        // machine, so the session is made part of the pipe name.
+        let mut session = 0;
+        if unsafe { ProcessIdToSessionId(GetCurrentProcessId(), &mut session) } == false.into() {
+            return Err(io::Error::last_os_error());
+        }
+        let mutex_name = encode_wide(format!("Local\\winit-{id}"));
+        let pipe_name = format!(r"\\.\pipe\winit-{session}-{id}");
+
+        let mutex = unsafe { CreateMutexW(ptr::null(), false.into(), mutex_name.as_ptr()) };
+        if mutex == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        if unsafe { GetLastError() } != ERROR_ALREADY_EXISTS {
+            return Ok(Some(Self {
+                mutex,
+                pipe_name: encode_wide(&pipe_name),
+            }));
+        }
+        unsafe { CloseHandle(mutex) };
+
+        // The primary instance may hold the mutex but not be listening yet.
+        let mut attempts = 0;
+        let mut pipe = loop {
+            match OpenOptions::new().write(true).open(OsStr::new(&pipe_name)) {
+                Ok(pipe) => break pipe,
+                Err(err)
+                    if attempts < CONNECT_ATTEMPTS
+                        && (err.kind() == io::ErrorKind::NotFound
+                            || err.raw_os_error() == Some(ERROR_PIPE_BUSY as i32)) =>
+                {
+                    attempts += 1;
+                    thread::sleep(CONNECT_INTERVAL);
+                }
+                Err(err) => return Err(err),
+            }
+        };
+        pipe.write_all(message)?;
+
+        Ok(None)
+    }
+
+    pub fn listen<F>(self, mut callback: F)
+    where
+        F: 'static + Send + FnMut(Vec<u8>) -> bool,
+    {
+        // The thread owns `self`, keeping the claim alive for as long as it accepts instances.
+        thread::spawn(move || loop {
+            let instance = &self;
+            let pipe = unsafe {
+                CreateNamedPipeW(
+                    instance.pipe_name.as_ptr(),
+                    PIPE_ACCESS_INBOUND,
+                    PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT | PIPE_REJECT_REMOTE_CLIENTS,
+                    PIPE_UNLIMITED_INSTANCES,
+                    0,
+                    PIPE_BUFFER_SIZE,
+                    0,
+                    ptr::null(),
+                )
+            };
+            if pipe == INVALID_HANDLE_VALUE {
+                log::warn!(
+                    "Failed to create the pipe for other instances: {}",
+                    io::Error::last_os_error()
+                );
+                return;
+            }
+
+            // This fails with `ERROR_PIPE_CONNECTED` if the client connected before the call.
+            let connected = unsafe { ConnectNamedPipe(pipe, ptr::null_mut()) } != 0
+                || unsafe { GetLastError() } == ERROR_PIPE_CONNECTED;
+
+            // The client closing its end is reported as the end of the file.
+            let mut pipe = unsafe { File::from_raw_handle(pipe as RawHandle) };
+            if !connected {
+                continue;
+            }
+
+            let mut message = Vec::new();
+            match pipe.read_to_end(&mut message) {
+                Ok(_) => {
+                    if !callback(message) {
+                        return;
+                    }
+                }
+                Err(err) => log::warn!("Failed to receive message from another instance: {err}"),
+            }
+        });
+    }
+}
+
+impl Drop for SingleInstance {
+    fn drop(&mut self) {
+        unsafe { CloseHandle(self.mutex) };
+    }
+}
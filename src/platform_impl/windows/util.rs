@@ -7,6 +7,7 @@ use std::{
     os::windows::prelude::{OsStrExt, OsStringExt},
     ptr,
     sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
 };
 
 use once_cell::sync::Lazy;
@@ -14,9 +15,13 @@ use windows_sys::{
     core::{HRESULT, PCWSTR},
     Win32::{
         Foundation::{BOOL, HMODULE, HWND, RECT},
-        Graphics::Gdi::{ClientToScreen, HMONITOR},
+        Graphics::{
+            Dwm::{DwmGetCompositionTimingInfo, DWM_TIMING_INFO},
+            Gdi::{ClientToScreen, HMONITOR},
+        },
         System::{
             LibraryLoader::{GetProcAddress, LoadLibraryA},
+            Performance::{QueryPerformanceCounter, QueryPerformanceFrequency},
             SystemServices::IMAGE_DOS_HEADER,
         },
         UI::{
@@ -149,6 +154,39 @@ pub fn is_minimized(window: HWND) -> bool {
     unsafe { IsIconic(window) != false.into() }
 }
 
+/// Estimate when the compositor shows its next frame, from the DWM composition timing.
+pub fn next_vblank() -> Option<Instant> {
+    let mut info: DWM_TIMING_INFO = unsafe { mem::zeroed() };
+    info.cbSize = mem::size_of::<DWM_TIMING_INFO>() as u32;
+    // The window handle must be null since Windows 8.1.
+    if unsafe { DwmGetCompositionTimingInfo(0, &mut info) } < 0 {
+        return None;
+    }
+
+    let mut now = 0;
+    let mut frequency = 0;
+    if unsafe { QueryPerformanceCounter(&mut now) } == false.into()
+        || unsafe { QueryPerformanceFrequency(&mut frequency) } == false.into()
+    {
+        return None;
+    }
+
+    let (now, frequency) = (now as u64, frequency as u64);
+    let (vblank, period) = (info.qpcVBlank, info.qpcRefreshPeriod);
+    if period == 0 || frequency == 0 {
+        return None;
+    }
+
+    // The reported vertical blank is usually the last one, so skip ahead to the next.
+    let next = if vblank > now {
+        vblank
+    } else {
+        vblank + ((now - vblank) / period + 1) * period
+    };
+    let nanos = (next - now) as u128 * 1_000_000_000 / frequency as u128;
+    Some(Instant::now() + Duration::from_nanos(nanos as u64))
+}
+
 pub fn get_instance_handle() -> HMODULE {
     // Gets the instance handle by taking the address of the
     // pseudo-variable created by the microsoft linker:
@@ -18,7 +18,11 @@ use windows_sys::Win32::{
         WPARAM,
     },
     Graphics::{
-        Dwm::{DwmEnableBlurBehindWindow, DWM_BB_BLURREGION, DWM_BB_ENABLE, DWM_BLURBEHIND},
+        Dwm::{
+            DwmEnableBlurBehindWindow, DwmSetWindowAttribute, DWMWA_BORDER_COLOR,
+            DWMWA_CAPTION_COLOR, DWMWA_TEXT_COLOR, DWMWINDOWATTRIBUTE, DWM_BB_BLURREGION,
+            DWM_BB_ENABLE, DWM_BLURBEHIND,
+        },
         Gdi::{
             ChangeDisplaySettingsExW, ClientToScreen, CreateRectRgn, DeleteObject, InvalidateRgn,
             RedrawWindow, CDS_FULLSCREEN, DISP_CHANGE_BADFLAGS, DISP_CHANGE_BADMODE,
@@ -49,7 +53,8 @@ use windows_sys::Win32::{
             FLASHW_ALL, FLASHW_STOP, FLASHW_TIMERNOFG, FLASHW_TRAY, GWLP_HINSTANCE, HTBOTTOM,
             HTBOTTOMLEFT, HTBOTTOMRIGHT, HTCAPTION, HTLEFT, HTRIGHT, HTTOP, HTTOPLEFT, HTTOPRIGHT,
             NID_READY, PM_NOREMOVE, SM_DIGITIZER, SWP_ASYNCWINDOWPOS, SWP_NOACTIVATE, SWP_NOSIZE,
-            SWP_NOZORDER, WDA_EXCLUDEFROMCAPTURE, WDA_NONE, WM_NCLBUTTONDOWN, WNDCLASSEXW,
+            SWP_NOZORDER, WDA_EXCLUDEFROMCAPTURE, WDA_NONE, WINDOWPLACEMENT, WM_NCLBUTTONDOWN,
+            WNDCLASSEXW,
         },
     },
 };
@@ -57,7 +62,10 @@ use windows_sys::Win32::{
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize, Position, Size},
     error::{ExternalError, NotSupportedError, OsError as RootOsError},
+    event_loop::AsyncRequestSerial,
     icon::Icon,
+    keyboard::LockKeyState,
+    platform::windows::{Color, HitTestCallback},
     platform_impl::platform::{
         dark_mode::try_theme,
         definitions::{
@@ -69,14 +77,15 @@ use crate::{
         icon::{self, IconType},
         ime::ImeContext,
         keyboard::KeyEventBuilder,
+        keyboard_layout::get_lock_keys,
         monitor::{self, MonitorHandle},
         util,
         window_state::{CursorFlags, SavedWindow, WindowFlags, WindowState},
         Fullscreen, PlatformSpecificWindowBuilderAttributes, WindowId,
     },
     window::{
-        CursorGrabMode, CursorIcon, ImePurpose, ResizeDirection, Theme, UserAttentionType,
-        WindowAttributes, WindowButtons, WindowLevel,
+        CursorGrabMode, CursorIcon, DragAction, HitTestRegion, ImePurpose, Rect, ResizeDirection,
+        Theme, UserAttentionType, WindowAttributes, WindowButtons, WindowLevel,
     },
 };
 
@@ -118,6 +127,9 @@ impl Window {
 
     pub fn set_transparent(&self, _transparent: bool) {}
 
+    #[inline]
+    pub fn set_opaque_region(&self, _region: Option<&[Rect]>) {}
+
     #[inline]
     pub fn set_visible(&self, visible: bool) {
         let window = self.window.clone();
@@ -145,6 +157,18 @@ impl Window {
     #[inline]
     pub fn pre_present_notify(&self) {}
 
+    #[inline]
+    pub fn pre_present_notify_with_damage(
+        &self,
+        _damage: &[(PhysicalPosition<i32>, PhysicalSize<u32>)],
+    ) {
+    }
+
+    #[inline]
+    pub fn flush(&self) -> Result<(), ExternalError> {
+        Ok(())
+    }
+
     #[inline]
     pub fn outer_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
         util::WindowArea::Outer.get_rect(self.hwnd())
@@ -349,6 +373,39 @@ impl Window {
         });
     }
 
+    #[inline]
+    pub fn preload_cursors(&self, _cursors: &[CursorIcon]) {}
+
+    #[inline]
+    pub fn set_primary_selection_text(&self, _text: &str) {}
+
+    #[inline]
+    pub fn request_primary_selection_text(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn set_clipboard_data(&self, _data: Vec<(String, Vec<u8>)>) {}
+
+    #[inline]
+    pub fn request_clipboard_data(
+        &self,
+        _mime_type: &str,
+    ) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn request_clipboard_mime_types(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        Err(NotSupportedError::new())
+    }
+
+    #[inline]
+    pub fn set_drag_accept(&self, _mime_type: Option<&str>) {}
+
+    #[inline]
+    pub fn set_drag_action(&self, _action: DragAction) {}
+
     #[inline]
     pub fn set_cursor_grab(&self, mode: CursorGrabMode) -> Result<(), ExternalError> {
         let confine = match mode {
@@ -451,21 +508,22 @@ impl Window {
     #[inline]
     pub fn drag_resize_window(&self, direction: ResizeDirection) -> Result<(), ExternalError> {
         unsafe {
-            self.handle_os_dragging(match direction {
-                ResizeDirection::East => HTRIGHT,
-                ResizeDirection::North => HTTOP,
-                ResizeDirection::NorthEast => HTTOPRIGHT,
-                ResizeDirection::NorthWest => HTTOPLEFT,
-                ResizeDirection::South => HTBOTTOM,
-                ResizeDirection::SouthEast => HTBOTTOMRIGHT,
-                ResizeDirection::SouthWest => HTBOTTOMLEFT,
-                ResizeDirection::West => HTLEFT,
-            } as WPARAM);
+            self.handle_os_dragging(resize_direction_hittest(direction) as WPARAM);
         }
 
         Ok(())
     }
 
+    #[inline]
+    pub fn set_hittest_regions(&self, regions: &[HitTestRegion]) {
+        self.window_state_lock().hittest_regions = regions.to_vec();
+    }
+
+    #[inline]
+    pub fn set_hittest_callback(&self, callback: Option<HitTestCallback>) {
+        self.window_state_lock().hittest_callback = callback.map(Arc::from);
+    }
+
     #[inline]
     pub fn set_cursor_hittest(&self, hittest: bool) -> Result<(), ExternalError> {
         let window = self.window.clone();
@@ -479,6 +537,14 @@ impl Window {
         Ok(())
     }
 
+    // `SetWindowRgn` would also clip the drawing of the window to the region, and answering
+    // `HTTRANSPARENT` to `WM_NCHITTEST` only forwards the events to the windows of the same
+    // thread, so neither lets the events pass through to the windows of other applications.
+    #[inline]
+    pub fn set_input_region(&self, _region: Option<&[Rect]>) -> Result<(), ExternalError> {
+        Err(ExternalError::NotSupported(NotSupportedError::new()))
+    }
+
     #[inline]
     pub fn id(&self) -> WindowId {
         WindowId(self.hwnd())
@@ -526,6 +592,33 @@ impl Window {
         window_state.window_flags.contains(WindowFlags::MAXIMIZED)
     }
 
+    #[inline]
+    pub fn restored_inner_size(&self) -> Option<PhysicalSize<u32>> {
+        let window_state = self.window_state_lock();
+
+        // While in fullscreen, the normal placement is the one saved when entering it.
+        let placement = match window_state.saved_window {
+            Some(SavedWindow { placement }) => placement,
+            None => unsafe {
+                let mut placement: WINDOWPLACEMENT = mem::zeroed();
+                placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+                if GetWindowPlacement(self.hwnd(), &mut placement) == false.into() {
+                    return None;
+                }
+                placement
+            },
+        };
+
+        let rect = placement.rcNormalPosition;
+        let frame_size = window_state
+            .window_flags
+            .adjust_size(self.hwnd(), PhysicalSize::new(0, 0));
+        Some(PhysicalSize::new(
+            ((rect.right - rect.left) as u32).saturating_sub(frame_size.width),
+            ((rect.bottom - rect.top) as u32).saturating_sub(frame_size.height),
+        ))
+    }
+
     #[inline]
     pub fn fullscreen(&self) -> Option<Fullscreen> {
         let window_state = self.window_state_lock();
@@ -626,14 +719,21 @@ impl Window {
             // Update window bounds
             match &fullscreen {
                 Some(fullscreen) => {
-                    // Save window bounds before entering fullscreen
-                    let placement = unsafe {
-                        let mut placement = mem::zeroed();
-                        GetWindowPlacement(window.0, &mut placement);
-                        placement
-                    };
-
-                    window_state.lock().unwrap().saved_window = Some(SavedWindow { placement });
+                    // Save window bounds before entering fullscreen, but not when switching
+                    // between fullscreen modes, which would overwrite them with the fullscreen
+                    // bounds.
+                    let mut window_state_lock = window_state.lock().unwrap();
+                    if window_state_lock.saved_window.is_none() {
+                        let placement = unsafe {
+                            let mut placement: WINDOWPLACEMENT = mem::zeroed();
+                            placement.length = mem::size_of::<WINDOWPLACEMENT>() as u32;
+                            GetWindowPlacement(window.0, &mut placement);
+                            placement
+                        };
+
+                        window_state_lock.saved_window = Some(SavedWindow { placement });
+                    }
+                    drop(window_state_lock);
 
                     let monitor = match &fullscreen {
                         Fullscreen::Exclusive(video_mode) => video_mode.monitor(),
@@ -764,6 +864,9 @@ impl Window {
     #[inline]
     pub fn set_ime_purpose(&self, _purpose: ImePurpose) {}
 
+    #[inline]
+    pub fn set_ime_surrounding_text(&self, _text: &str, _cursor: usize, _anchor: usize) {}
+
     #[inline]
     pub fn request_user_attention(&self, request_type: Option<UserAttentionType>) {
         let window = self.window.clone();
@@ -808,6 +911,21 @@ impl Window {
         window_state.has_active_focus()
     }
 
+    #[inline]
+    pub fn set_key_repeat(&self, key_repeat: bool) {
+        self.window_state_lock().key_repeat = key_repeat;
+    }
+
+    #[inline]
+    pub fn lock_key_state(&self) -> LockKeyState {
+        get_lock_keys()
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.has_focus()
+    }
+
     pub fn title(&self) -> String {
         let len = unsafe { GetWindowTextLengthW(self.window.0) } + 1;
         let mut buf = vec![0; len as usize];
@@ -834,6 +952,34 @@ impl Window {
         });
     }
 
+    #[inline]
+    pub fn set_border_color(&self, color: Color) {
+        self.set_color_attribute(DWMWA_BORDER_COLOR, color);
+    }
+
+    #[inline]
+    pub fn set_title_bar_color(&self, color: Color) {
+        self.set_color_attribute(DWMWA_CAPTION_COLOR, color);
+    }
+
+    #[inline]
+    pub fn set_title_text_color(&self, color: Color) {
+        self.set_color_attribute(DWMWA_TEXT_COLOR, color);
+    }
+
+    fn set_color_attribute(&self, attribute: DWMWINDOWATTRIBUTE, color: Color) {
+        // The color attributes are only understood by Windows 11 and newer, older versions
+        // reject them with an error which we ignore.
+        unsafe {
+            DwmSetWindowAttribute(
+                self.hwnd(),
+                attribute,
+                &color as *const _ as _,
+                mem::size_of::<Color>() as _,
+            );
+        }
+    }
+
     #[inline]
     pub fn focus_window(&self) {
         let window = self.window.clone();
@@ -1048,6 +1194,16 @@ impl<'a, T: 'static> InitData<'a, T> {
         win.set_window_icon(self.attributes.window_icon.clone());
         win.set_taskbar_icon(self.pl_attribs.taskbar_icon.clone());
 
+        if let Some(color) = self.pl_attribs.border_color {
+            win.set_border_color(color);
+        }
+        if let Some(color) = self.pl_attribs.title_bar_color {
+            win.set_title_bar_color(color);
+        }
+        if let Some(color) = self.pl_attribs.title_text_color {
+            win.set_title_text_color(color);
+        }
+
         let attributes = self.attributes.clone();
 
         if attributes.content_protected {
@@ -1361,3 +1517,17 @@ unsafe fn force_window_active(handle: HWND) {
 
     SetForegroundWindow(handle);
 }
+
+/// The hit test code of the border resizing in `direction`.
+pub(crate) fn resize_direction_hittest(direction: ResizeDirection) -> u32 {
+    match direction {
+        ResizeDirection::East => HTRIGHT,
+        ResizeDirection::North => HTTOP,
+        ResizeDirection::NorthEast => HTTOPRIGHT,
+        ResizeDirection::NorthWest => HTTOPLEFT,
+        ResizeDirection::South => HTBOTTOM,
+        ResizeDirection::SouthEast => HTBOTTOMRIGHT,
+        ResizeDirection::SouthWest => HTBOTTOMLEFT,
+        ResizeDirection::West => HTLEFT,
+    }
+}
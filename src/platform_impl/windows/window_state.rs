@@ -1,12 +1,12 @@
 use crate::{
     dpi::{PhysicalPosition, PhysicalSize, Size},
     icon::Icon,
-    keyboard::ModifiersState,
-    platform_impl::platform::{event_loop, util, Fullscreen},
-    window::{CursorIcon, Theme, WindowAttributes},
+    keyboard::{LockKeyState, ModifiersState},
+    platform_impl::platform::{event_loop, keyboard_layout::get_lock_keys, util, Fullscreen},
+    window::{CursorIcon, HitTestKind, HitTestRegion, Theme, WindowAttributes},
 };
 use std::io;
-use std::sync::MutexGuard;
+use std::sync::{Arc, MutexGuard};
 use windows_sys::Win32::{
     Foundation::{HWND, RECT},
     Graphics::Gdi::InvalidateRgn,
@@ -39,6 +39,7 @@ pub(crate) struct WindowState {
     pub scale_factor: f64,
 
     pub modifiers_state: ModifiersState,
+    pub lock_keys: LockKeyState,
     pub fullscreen: Option<Fullscreen>,
     pub current_theme: Theme,
     pub preferred_theme: Option<Theme>,
@@ -55,6 +56,13 @@ pub(crate) struct WindowState {
     pub dragging: bool,
 
     pub skip_taskbar: bool,
+
+    pub key_repeat: bool,
+
+    /// Used by `WM_NCHITTEST`.
+    pub hittest_regions: Vec<HitTestRegion>,
+    pub hittest_callback:
+        Option<Arc<dyn Fn(PhysicalPosition<f64>) -> Option<HitTestKind> + Send + Sync>>,
 }
 
 #[derive(Clone)]
@@ -156,6 +164,7 @@ impl WindowState {
             scale_factor,
 
             modifiers_state: ModifiersState::default(),
+            lock_keys: get_lock_keys(),
             fullscreen: None,
             current_theme,
             preferred_theme,
@@ -170,6 +179,10 @@ impl WindowState {
             dragging: false,
 
             skip_taskbar: false,
+
+            key_repeat: true,
+            hittest_regions: Vec::new(),
+            hittest_callback: None,
         }
     }
 
@@ -491,8 +504,9 @@ impl WindowFlags {
 impl CursorFlags {
     fn refresh_os_cursor(self, window: HWND) -> Result<(), io::Error> {
         let client_rect = util::WindowArea::Inner.get_rect(window)?;
+        let focused = util::is_focused(window);
 
-        if util::is_focused(window) {
+        if focused {
             let cursor_clip = match self.contains(CursorFlags::GRABBED) {
                 true => Some(client_rect),
                 false => None,
@@ -515,13 +529,44 @@ impl CursorFlags {
             }
         }
 
-        let cursor_in_client = self.contains(CursorFlags::IN_WINDOW);
-        if cursor_in_client {
-            util::set_cursor_hidden(self.contains(CursorFlags::HIDDEN));
-        } else {
-            util::set_cursor_hidden(false);
-        }
+        util::set_cursor_hidden(self.hides_os_cursor(focused));
 
         Ok(())
     }
+
+    /// Whether the cursor should be hidden, which is only the case within the window while it
+    /// has focus.
+    fn hides_os_cursor(self, focused: bool) -> bool {
+        focused && self.contains(CursorFlags::IN_WINDOW) && self.contains(CursorFlags::HIDDEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hidden_cursor_is_shown_while_unfocused() {
+        let flags = CursorFlags::HIDDEN | CursorFlags::IN_WINDOW;
+
+        // Hidden while focused, shown again as soon as the window loses focus.
+        assert!(flags.hides_os_cursor(true));
+        assert!(!flags.hides_os_cursor(false));
+    }
+
+    #[test]
+    fn hidden_cursor_is_shown_outside_the_window() {
+        assert!(!CursorFlags::HIDDEN.hides_os_cursor(true));
+    }
+
+    #[test]
+    fn cursor_visibility_is_independent_of_the_grab() {
+        let flags = CursorFlags::HIDDEN | CursorFlags::IN_WINDOW;
+
+        assert_eq!(
+            (flags | CursorFlags::GRABBED).hides_os_cursor(true),
+            flags.hides_os_cursor(true)
+        );
+        assert!(!(CursorFlags::IN_WINDOW | CursorFlags::GRABBED).hides_os_cursor(true));
+    }
 }
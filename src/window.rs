@@ -6,9 +6,10 @@ use raw_window_handle::{
 };
 
 use crate::{
-    dpi::{PhysicalPosition, PhysicalSize, Position, Size},
+    dpi::{LogicalSize, PhysicalPosition, PhysicalSize, Position, Size},
     error::{ExternalError, NotSupportedError, OsError},
-    event_loop::EventLoopWindowTarget,
+    event_loop::{AsyncRequestSerial, EventLoopWindowTarget},
+    keyboard::LockKeyState,
     monitor::{MonitorHandle, VideoMode},
     platform_impl,
 };
@@ -111,6 +112,9 @@ pub struct WindowBuilder {
 
     // Platform-specific configuration.
     pub(crate) platform_specific: platform_impl::PlatformSpecificWindowBuilderAttributes,
+
+    /// Center the window on the primary monitor when neither a position nor a monitor is set.
+    center: bool,
 }
 
 impl fmt::Debug for WindowBuilder {
@@ -128,6 +132,7 @@ pub struct WindowAttributes {
     pub min_inner_size: Option<Size>,
     pub max_inner_size: Option<Size>,
     pub position: Option<Position>,
+    pub monitor: Option<MonitorHandle>,
     pub resizable: bool,
     pub enabled_buttons: WindowButtons,
     pub title: String,
@@ -153,6 +158,7 @@ impl Default for WindowAttributes {
             min_inner_size: None,
             max_inner_size: None,
             position: None,
+            monitor: None,
             resizable: true,
             enabled_buttons: WindowButtons::all(),
             title: "winit window".to_owned(),
@@ -179,6 +185,44 @@ impl WindowBuilder {
         Default::default()
     }
 
+    /// Initializes a new builder preset for a splash screen.
+    ///
+    /// The window is undecorated, not resizable, has no window buttons and is always on top.
+    /// It's centered on the primary monitor, unless [`WindowBuilder::with_position`] or
+    /// [`WindowBuilder::with_monitor`] is used.
+    ///
+    /// All of these can still be changed on the returned builder, and you may want to lower
+    /// the level with [`Window::set_window_level`] once the application is up.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows:** The window is also not shown in the taskbar.
+    /// - **X11:** The window type is also set to `_NET_WM_WINDOW_TYPE_SPLASH`.
+    /// - **Wayland:** The window isn't centered, since the compositor places the windows.
+    #[inline]
+    pub fn splash() -> Self {
+        let mut builder = WindowBuilder::new()
+            .with_decorations(false)
+            .with_resizable(false)
+            .with_enabled_buttons(WindowButtons::empty())
+            .with_window_level(WindowLevel::AlwaysOnTop);
+        builder.center = true;
+
+        #[cfg(windows_platform)]
+        {
+            use crate::platform::windows::WindowBuilderExtWindows;
+            builder = builder.with_skip_taskbar(true);
+        }
+
+        #[cfg(x11_platform)]
+        {
+            use crate::platform::x11::{WindowBuilderExtX11, XWindowType};
+            builder = builder.with_x11_window_type(vec![XWindowType::Splash]);
+        }
+
+        builder
+    }
+
     /// Get the current window attributes.
     pub fn window_attributes(&self) -> &WindowAttributes {
         &self.window
@@ -247,6 +291,23 @@ impl WindowBuilder {
         self
     }
 
+    /// Sets the monitor the window should initially be placed on.
+    ///
+    /// The window is centered within the [work area] of the monitor using the requested inner
+    /// size, or 800x600 logical pixels if none was set. This is the same as computing the
+    /// position and passing it to [`WindowBuilder::with_position`], so it's ignored when a
+    /// position is set explicitly, and on platforms that don't support positioning windows.
+    ///
+    /// To make the window fullscreen on a specific monitor, use
+    /// [`WindowBuilder::with_fullscreen`] instead.
+    ///
+    /// [work area]: MonitorHandle::work_area
+    #[inline]
+    pub fn with_monitor(mut self, monitor: MonitorHandle) -> Self {
+        self.window.monitor = Some(monitor);
+        self
+    }
+
     /// Sets whether the window is resizable or not.
     ///
     /// The default is `true`.
@@ -306,6 +367,10 @@ impl WindowBuilder {
     ///
     /// The default is to show the window.
     ///
+    /// Applications creating several windows at once can create them hidden, prepare their
+    /// contents and then show them together with [`Window::set_visible`] to avoid the windows
+    /// appearing one by one.
+    ///
     /// See [`Window::set_visible`] for details.
     #[inline]
     pub fn with_visible(mut self, visible: bool) -> Self {
@@ -458,13 +523,34 @@ impl WindowBuilder {
     ///
     /// ## Platform-specific
     ///
+    /// - **Wayland:** The surface is committed without a buffer and this function waits for the
+    ///   initial configure of the compositor before returning, so the returned window may be
+    ///   rendered to right away. The window is mapped once the first frame has been presented,
+    ///   which applications should do in response to the initial
+    ///   [`Event::RedrawRequested`] sent for every new window.
     /// - **Web:** The window is created but not inserted into the web page automatically. Please
     ///   see the web platform module for more information.
+    ///
+    /// [`Event::RedrawRequested`]: crate::event::Event::RedrawRequested
     #[inline]
     pub fn build<T: 'static>(
-        self,
+        mut self,
         window_target: &EventLoopWindowTarget<T>,
     ) -> Result<Window, OsError> {
+        if self.window.position.is_none() {
+            let monitor = match self.window.monitor.clone() {
+                Some(monitor) => Some(monitor),
+                None if self.center => window_target
+                    .primary_monitor()
+                    .or_else(|| window_target.available_monitors().next()),
+                None => None,
+            };
+
+            if let Some(monitor) = monitor.as_ref() {
+                self.window.position = Some(centered_on_monitor(monitor, self.window.inner_size));
+            }
+        }
+
         platform_impl::Window::new(&window_target.p, self.window, self.platform_specific).map(
             |window| {
                 window.request_redraw();
@@ -474,6 +560,23 @@ impl WindowBuilder {
     }
 }
 
+/// Computes the position for a window of the given size to be centered on the monitor, without
+/// letting it go past the top left corner of the monitor's work area.
+fn centered_on_monitor(monitor: &MonitorHandle, size: Option<Size>) -> Position {
+    let (area_position, area_size) = monitor
+        .work_area()
+        .unwrap_or_else(|| (monitor.position(), monitor.size()));
+
+    let scale_factor = monitor.scale_factor();
+    let size: PhysicalSize<i32> = size
+        .unwrap_or_else(|| LogicalSize::new(800.0, 600.0).into())
+        .to_physical(scale_factor);
+
+    let x = area_position.x + (area_size.width as i32 - size.width) / 2;
+    let y = area_position.y + (area_size.height as i32 - size.height) / 2;
+    PhysicalPosition::new(x.max(area_position.x), y.max(area_position.y)).into()
+}
+
 /// Base Window functions.
 impl Window {
     /// Creates a new Window for platforms where this is appropriate.
@@ -509,6 +612,10 @@ impl Window {
     /// moved to another screen); as such, tracking [`WindowEvent::ScaleFactorChanged`] events is
     /// the most robust way to track the DPI you need to use to draw.
     ///
+    /// Applications that want to render unscaled, like pixel-art games, can ignore this value by
+    /// only passing physical types, such as [`PhysicalSize`], to winit, since all of winit's
+    /// functions and events already report physical values.
+    ///
     /// ## Platform-specific
     ///
     /// - **X11:** This respects Xft.dpi, and can be overridden using the `WINIT_X11_SCALE_FACTOR` environment variable.
@@ -592,6 +699,53 @@ impl Window {
         self.window.pre_present_notify();
     }
 
+    /// Same as [`Window::pre_present_notify`], but also reports which regions of the buffer
+    /// changed since the last presentation.
+    ///
+    /// Each region is given as its top-left corner and size in physical pixels of the buffer.
+    /// Compositors can use this to only recompose the damaged parts of the window, which saves
+    /// power for applications that redraw small areas, like terminals and text editors.
+    ///
+    /// Note that graphics APIs may damage the whole surface on their own when presenting, in
+    /// which case the damage given here has no effect.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** The damage is applied with `wl_surface::damage_buffer` and accumulated
+    ///   until the next commit of the surface.
+    /// - **iOS / Android / Web / Windows / macOS / Orbital / X11:** The damage is ignored.
+    #[inline]
+    pub fn pre_present_notify_with_damage(
+        &self,
+        damage: &[(PhysicalPosition<i32>, PhysicalSize<u32>)],
+    ) {
+        self.window.pre_present_notify_with_damage(damage);
+    }
+
+    /// Sends the requests winit queued for the display server right away.
+    ///
+    /// Requests such as cursor changes or the frame callback scheduled by
+    /// [`Window::pre_present_notify`] are normally sent when the event loop goes back to waiting
+    /// for events. A renderer that blocks inside the event handler, e.g. on the GPU, can call this
+    /// to get them to the display server before blocking.
+    ///
+    /// Call it after presenting: winit's requests for the surface only take effect with the commit
+    /// done by presenting, and some graphics APIs don't flush the connection on their own when
+    /// they commit. Calling it before [`Window::pre_present_notify`] gains nothing, as the frame
+    /// callback isn't requested yet.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Flushes the connection. If the socket is full, the rest is sent by the event
+    ///   loop once it has room again.
+    /// - **X11:** Flushes the connection.
+    /// - **iOS / Android / Web / Windows / macOS / Orbital:** Does nothing, there is no request
+    ///   buffer.
+    #[inline]
+    pub fn flush(&self) -> Result<(), ExternalError> {
+        self.window.flush()
+    }
+
     /// Reset the dead key state of the keyboard.
     ///
     /// This is useful when a dead key is bound to trigger an action. Then
@@ -683,6 +837,37 @@ impl Window {
         self.window.set_outer_position(position.into())
     }
 
+    /// Moves the window so that it's centered on the given monitor.
+    ///
+    /// The window is centered within the [work area] of the monitor, or the whole monitor if the
+    /// work area is unknown. When the monitor uses a different scale factor than the window, the
+    /// size the window will have on that monitor is used. A window larger than the work area is
+    /// kept at its top left corner, like when building it with [`WindowBuilder::with_monitor`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS:** Can only be called on the main thread.
+    /// - **Android / Wayland:** Unsupported, since the window can't be positioned.
+    ///
+    /// [work area]: MonitorHandle::work_area
+    #[inline]
+    pub fn center_on_monitor(&self, monitor: &MonitorHandle) {
+        let outer_size = self.outer_size().to_logical::<f64>(self.scale_factor());
+        self.set_outer_position(centered_on_monitor(monitor, Some(outer_size.into())));
+    }
+
+    /// Moves the window so that it's centered on the monitor it's currently on.
+    ///
+    /// Does nothing if the current monitor can't be detected.
+    ///
+    /// See [`Window::center_on_monitor`] for details.
+    #[inline]
+    pub fn center_on_current_monitor(&self) {
+        if let Some(monitor) = self.current_monitor() {
+            self.center_on_monitor(&monitor);
+        }
+    }
+
     /// Returns the physical size of the window's client area.
     ///
     /// The client area is the content of the window, excluding the title bar and borders.
@@ -759,6 +944,9 @@ impl Window {
 
     /// Sets a minimum dimension size for the window.
     ///
+    /// The size applies to the client area, without decorations. Use
+    /// [`Window::set_min_outer_size`] to constrain the whole window instead.
+    ///
     /// ```no_run
     /// # use winit::dpi::{LogicalSize, PhysicalSize};
     /// # use winit::event_loop::EventLoop;
@@ -782,6 +970,9 @@ impl Window {
 
     /// Sets a maximum dimension size for the window.
     ///
+    /// The size applies to the client area, without decorations. Use
+    /// [`Window::set_max_outer_size`] to constrain the whole window instead.
+    ///
     /// ```no_run
     /// # use winit::dpi::{LogicalSize, PhysicalSize};
     /// # use winit::event_loop::EventLoop;
@@ -803,6 +994,51 @@ impl Window {
         self.window.set_max_inner_size(max_size.map(|s| s.into()))
     }
 
+    /// Sets a minimum dimension size for the window, including its decorations.
+    ///
+    /// This is the outer counterpart of [`Window::set_min_inner_size`]: the size of the
+    /// decorations, as the difference between [`Window::outer_size`] and [`Window::inner_size`],
+    /// is subtracted and the result is applied as the minimum inner size. Since the decorations
+    /// may change, e.g. when switching between client and server side decorations on Wayland,
+    /// the size should be set again after calling [`Window::set_decorations`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Orbital:** Unsupported.
+    #[inline]
+    pub fn set_min_outer_size<S: Into<Size>>(&self, min_size: Option<S>) {
+        let min_size = min_size.map(|size| self.outer_to_inner_size(size.into()));
+        self.window.set_min_inner_size(min_size)
+    }
+
+    /// Sets a maximum dimension size for the window, including its decorations.
+    ///
+    /// See [`Window::set_min_outer_size`] for how this relates to
+    /// [`Window::set_max_inner_size`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Orbital:** Unsupported.
+    #[inline]
+    pub fn set_max_outer_size<S: Into<Size>>(&self, max_size: Option<S>) {
+        let max_size = max_size.map(|size| self.outer_to_inner_size(size.into()));
+        self.window.set_max_inner_size(max_size)
+    }
+
+    /// Converts an outer size to an inner size using the current size of the decorations.
+    fn outer_to_inner_size(&self, size: Size) -> Size {
+        let size: PhysicalSize<u32> = size.to_physical(self.scale_factor());
+        let inner_size = self.inner_size();
+        let outer_size = self.outer_size();
+        let width = outer_size.width.saturating_sub(inner_size.width);
+        let height = outer_size.height.saturating_sub(inner_size.height);
+        PhysicalSize::new(
+            size.width.saturating_sub(width),
+            size.height.saturating_sub(height),
+        )
+        .into()
+    }
+
     /// Returns window resize increments if any were set.
     ///
     /// ## Platform-specific
@@ -859,13 +1095,30 @@ impl Window {
         self.window.set_transparent(transparent)
     }
 
+    /// Sets the areas of the window whose content is fully opaque, as a list of [`Rect`].
+    ///
+    /// This is a hint letting the compositor skip blending what is behind those areas, for
+    /// transparent windows with an opaque content in places. Marking as opaque an area which
+    /// isn't may result in visual artifacts. `None` derives the region from the transparency of
+    /// the window again, which is fully opaque unless it is transparent.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows / X11 / macOS / Web / iOS / Android / Orbital:** Unsupported.
+    #[inline]
+    pub fn set_opaque_region(&self, region: Option<&[Rect]>) {
+        self.window.set_opaque_region(region)
+    }
+
     /// Modifies the window's visibility.
     ///
     /// If `false`, this will hide the window. If `true`, this will show the window.
     ///
     /// ## Platform-specific
     ///
-    /// - **Android / Wayland / Web:** Unsupported.
+    /// - **Wayland:** Unsupported. A window is only shown once the first buffer has been
+    ///   presented to it, so the first frame already controls when the window appears.
+    /// - **Android / Web:** Unsupported.
     /// - **iOS:** Can only be called on the main thread.
     #[inline]
     pub fn set_visible(&self, visible: bool) {
@@ -973,6 +1226,23 @@ impl Window {
         self.window.set_maximized(maximized)
     }
 
+    /// Returns the inner size the window will have once it's no longer maximized or fullscreen.
+    ///
+    /// This is the size the window had before it got maximized or entered fullscreen, and what
+    /// applications usually want to persist as the window geometry. When the window is in the
+    /// normal state, it's the same as [`Window::inner_size`]. Once restored, the new size is also
+    /// reported with [`WindowEvent::Resized`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / X11 / macOS / Orbital:** Always returns `None`.
+    ///
+    /// [`WindowEvent::Resized`]: crate::event::WindowEvent::Resized
+    #[inline]
+    pub fn restored_inner_size(&self) -> Option<PhysicalSize<u32>> {
+        self.window.restored_inner_size()
+    }
+
     /// Gets the window's current maximized state.
     ///
     /// ## Platform-specific
@@ -985,6 +1255,10 @@ impl Window {
 
     /// Sets the window to fullscreen or back.
     ///
+    /// Leaving fullscreen with `None` returns the window to the position and size it had before
+    /// entering fullscreen, even when switching between fullscreen modes in the meantime. Use
+    /// [`Window::restored_inner_size`] to query that size while in fullscreen.
+    ///
     /// ## Platform-specific
     ///
     /// - **macOS:** [`Fullscreen::Exclusive`] provides true exclusive mode with a
@@ -1132,6 +1406,12 @@ impl Window {
     ///
     /// IME is **not** allowed by default.
     ///
+    /// Key presses are handed to the IME before winit reports them, and on some platforms, like
+    /// Wayland, the IME even runs outside of the application. So a key event can't be marked as
+    /// handled to keep the IME from composing it. Applications that want keys to bypass the IME,
+    /// e.g. while a command palette or game controls are focused, should disallow IME for that
+    /// time instead.
+    ///
     /// ## Platform-specific
     ///
     /// - **macOS:** IME must be enabled to receive text-input where dead-key sequences are combined.
@@ -1155,6 +1435,32 @@ impl Window {
         self.window.set_ime_purpose(purpose);
     }
 
+    /// Sets the text around the cursor to give the IME more context.
+    ///
+    /// Input methods use the surrounding text for features like reconversion, autocorrection and
+    /// better predictions. The `text` should be the paragraph around the cursor, without the
+    /// preedit text, and `cursor` and `anchor` are byte-wise indices into it. When there's no
+    /// selection, `cursor` and `anchor` are the same.
+    ///
+    /// The surrounding text should be updated whenever the text or the cursor changes.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Long texts are cut around the cursor to fit into the protocol limits.
+    /// - **iOS / Android / Web / Windows / X11 / macOS / Orbital:** Unsupported.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cursor` or `anchor` is not on a char boundary of `text`.
+    #[inline]
+    pub fn set_ime_surrounding_text(&self, text: &str, cursor: usize, anchor: usize) {
+        assert!(
+            text.is_char_boundary(cursor) && text.is_char_boundary(anchor),
+            "cursor and anchor must be on char boundaries"
+        );
+        self.window.set_ime_surrounding_text(text, cursor, anchor);
+    }
+
     /// Brings the window to the front and sets input focus. Has no effect if the window is
     /// already in focus, minimized, or not visible.
     ///
@@ -1162,9 +1468,19 @@ impl Window {
     /// you are certain that's what the user wants. Focus stealing can cause an extremely disruptive
     /// user experience.
     ///
+    /// Whether the focus is actually granted is up to the window manager, which may only mark
+    /// the window as demanding attention instead. Check for [`WindowEvent::Focused`] to know
+    /// the outcome.
+    ///
     /// ## Platform-specific
     ///
-    /// - **iOS / Android / Wayland / Orbital:** Unsupported.
+    /// - **Wayland:** Requires the `xdg_activation_v1` protocol. Compositors usually only
+    ///   grant focus when the application currently has it for another window.
+    /// - **Windows:** Bypasses the [`SetForegroundWindow`] restrictions.
+    /// - **iOS / Android / Orbital:** Unsupported.
+    ///
+    /// [`WindowEvent::Focused`]: crate::event::WindowEvent::Focused
+    /// [`SetForegroundWindow`]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setforegroundwindow#remarks
     #[inline]
     pub fn focus_window(&self) {
         self.window.focus_window()
@@ -1180,6 +1496,66 @@ impl Window {
         self.window.has_focus()
     }
 
+    /// Returns the state of the keyboard lock keys.
+    ///
+    /// Changes are reported with [`WindowEvent::LockKeysChanged`] while the window has focus.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Only updated while a window of the application has keyboard focus, since
+    ///   the compositor doesn't send the keyboard state otherwise. Scroll lock isn't reported.
+    /// - **macOS:** Only caps lock is reported.
+    /// - **iOS / Android / Web / Orbital:** Unsupported, always returns an empty state.
+    ///
+    /// [`WindowEvent::LockKeysChanged`]: crate::event::WindowEvent::LockKeysChanged
+    #[inline]
+    pub fn lock_key_state(&self) -> LockKeyState {
+        self.window.lock_key_state()
+    }
+
+    /// Sets whether key repeats are delivered to this window.
+    ///
+    /// When disabled, [`KeyboardInput`] events with [`KeyEvent::repeat`] set are dropped, which
+    /// is useful for games handling held keys themselves. Key repeats are enabled by default.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Orbital:** Unsupported.
+    ///
+    /// [`KeyboardInput`]: crate::event::WindowEvent::KeyboardInput
+    /// [`KeyEvent::repeat`]: crate::event::KeyEvent::repeat
+    #[inline]
+    pub fn set_key_repeat(&self, key_repeat: bool) {
+        self.window.set_key_repeat(key_repeat)
+    }
+
+    /// Gets whether the window is the active window.
+    ///
+    /// The active window is the one the user is currently interacting with, which is usually
+    /// highlighted by the window manager. This queries the same state information as
+    /// [`WindowEvent::Activated`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** A window can be activated by the compositor without having keyboard focus,
+    ///   e.g. when no keyboard is attached.
+    /// - **Others:** Always the same as [`Window::has_focus`].
+    ///
+    /// [`WindowEvent::Activated`]: crate::event::WindowEvent::Activated
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.window.is_active()
+    }
+
+    /// Gets whether the window has keyboard focus.
+    ///
+    /// This is the same as [`Window::has_focus`], spelled out to contrast it with
+    /// [`Window::is_active`].
+    #[inline]
+    pub fn has_keyboard_focus(&self) -> bool {
+        self.window.has_focus()
+    }
+
     /// Requests user attention to the window, this has no effect if the application
     /// is already focused. How requesting for user attention manifests is platform dependent,
     /// see [`UserAttentionType`] for details.
@@ -1246,6 +1622,130 @@ impl Window {
     pub fn title(&self) -> String {
         self.window.title()
     }
+
+    /// Sets the primary selection, the text pasted with the middle mouse button.
+    ///
+    /// The primary selection is separate from the clipboard, it usually holds the text the user
+    /// selected last. The `text` stays available for other applications as long as the window is
+    /// open and no other selection is made.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Requires the compositor to support `zwp_primary_selection_v1`. The selection
+    ///   can only be set after the window received keyboard or pointer input.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    #[inline]
+    pub fn set_primary_selection_text(&self, text: &str) {
+        self.window.set_primary_selection_text(text)
+    }
+
+    /// Requests the text of the primary selection, the text pasted with the middle mouse button.
+    ///
+    /// The text is read from the application owning the selection, and delivered inside
+    /// [`WindowEvent::PrimarySelectionText`] with the serial returned here.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Selections transferred in chunks, as some applications do for large texts, are
+    ///   delivered as `None`.
+    /// - **Wayland:** Requires the compositor to support `zwp_primary_selection_v1`.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    ///
+    /// [`WindowEvent::PrimarySelectionText`]: crate::event::WindowEvent::PrimarySelectionText
+    #[inline]
+    pub fn request_primary_selection_text(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        self.window.request_primary_selection_text()
+    }
+
+    /// Puts data in the clipboard, available in every mime type of `data`.
+    ///
+    /// Each entry holds the data for one mime type, e.g. an image editor can offer the same
+    /// picture as `image/png` and `image/bmp`. Text should be offered as
+    /// `text/plain;charset=utf-8`. The data stays available for other applications as long as
+    /// the window is open and nothing else is copied.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Text offered as `text/plain;charset=utf-8` is offered as `UTF8_STRING` as
+    ///   well, which most X11 applications ask for.
+    /// - **Wayland:** The clipboard can only be set after the window received keyboard or
+    ///   pointer input.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    #[inline]
+    pub fn set_clipboard_data(&self, data: Vec<(String, Vec<u8>)>) {
+        self.window.set_clipboard_data(data)
+    }
+
+    /// Requests the data of the clipboard in the given mime type.
+    ///
+    /// The data is read from the application owning the clipboard, and delivered inside
+    /// [`WindowEvent::ClipboardData`] with the serial returned here. The available mime types
+    /// can be requested with [`Window::request_clipboard_mime_types`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Data transferred in chunks, as some applications do for large data, is
+    ///   delivered as `None`.
+    /// - **Wayland:** The clipboard is only readable while the window has the keyboard focus.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    ///
+    /// [`WindowEvent::ClipboardData`]: crate::event::WindowEvent::ClipboardData
+    #[inline]
+    pub fn request_clipboard_data(
+        &self,
+        mime_type: &str,
+    ) -> Result<AsyncRequestSerial, NotSupportedError> {
+        self.window.request_clipboard_data(mime_type)
+    }
+
+    /// Requests the mime types the clipboard is available in.
+    ///
+    /// The mime types are delivered inside [`WindowEvent::ClipboardMimeTypes`] with the serial
+    /// returned here.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Applications may list X11 targets such as `UTF8_STRING` besides mime types.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Unsupported.
+    ///
+    /// [`WindowEvent::ClipboardMimeTypes`]: crate::event::WindowEvent::ClipboardMimeTypes
+    #[inline]
+    pub fn request_clipboard_mime_types(&self) -> Result<AsyncRequestSerial, NotSupportedError> {
+        self.window.request_clipboard_mime_types()
+    }
+
+    /// Accepts the drop of the drag over the window in `mime_type`, or refuses it with `None`.
+    ///
+    /// A drag entering the window is refused until this is called after
+    /// [`WindowEvent::DragEntered`], with one of the mime types it lists. The data is then
+    /// delivered inside [`WindowEvent::DragDropped`] when the drag is dropped.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Orbital / Windows / macOS / X11:** Unsupported.
+    ///
+    /// [`WindowEvent::DragEntered`]: crate::event::WindowEvent::DragEntered
+    /// [`WindowEvent::DragDropped`]: crate::event::WindowEvent::DragDropped
+    #[inline]
+    pub fn set_drag_accept(&self, mime_type: Option<&str>) {
+        self.window.set_drag_accept(mime_type)
+    }
+
+    /// Sets the action performed with the data of the drag over the window when it's dropped.
+    ///
+    /// The action defaults to [`DragAction::Copy`] for every drag entering the window. The
+    /// source may not allow it, in which case the drop is refused. The action picked for the
+    /// drop, if any, is reported inside [`WindowEvent::DragDropped`].
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **iOS / Android / Web / Orbital / Windows / macOS / X11:** Unsupported.
+    ///
+    /// [`WindowEvent::DragDropped`]: crate::event::WindowEvent::DragDropped
+    #[inline]
+    pub fn set_drag_action(&self, action: DragAction) {
+        self.window.set_drag_action(action)
+    }
 }
 
 /// Cursor functions.
@@ -1255,11 +1755,31 @@ impl Window {
     /// ## Platform-specific
     ///
     /// - **iOS / Android / Orbital:** Unsupported.
+    /// - **Wayland:** When the compositor supports `wp_cursor_shape_v1`, it draws the cursor
+    ///   from its own theme. Otherwise the cursor theme and size are read from the
+    ///   `XCURSOR_THEME` and `XCURSOR_SIZE` environment variables, and the images are loaded at
+    ///   the window's scale factor.
     #[inline]
     pub fn set_cursor_icon(&self, cursor: CursorIcon) {
         self.window.set_cursor_icon(cursor);
     }
 
+    /// Loads the images of the given cursor icons ahead of their first use.
+    ///
+    /// Loading a cursor image may read it from disk, which can cause a visible stutter the first
+    /// time the cursor changes. Preloading the icons an application is going to use avoids that.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11 / Wayland:** Loads the images from the cursor theme. On Wayland, icons the
+    ///   compositor draws itself are skipped.
+    /// - **Windows / macOS / iOS / Android / Web / Orbital:** Does nothing, the system cursors are
+    ///   already cached.
+    #[inline]
+    pub fn preload_cursors(&self, cursors: &[CursorIcon]) {
+        self.window.preload_cursors(cursors);
+    }
+
     /// Changes the position of the cursor in window coordinates.
     ///
     /// ```no_run
@@ -1298,6 +1818,21 @@ impl Window {
     ///             .or_else(|_e| window.set_cursor_grab(CursorGrabMode::Locked))
     ///             .unwrap();
     /// ```
+    ///
+    /// The grab doesn't affect the cursor's visibility, see [`Window::set_cursor_visible`].
+    ///
+    /// The grab is kept until it's changed, even while the window doesn't have focus. When the
+    /// system activates or releases it, [`WindowEvent::PointerGrabChanged`] is emitted.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Windows:** The grab is released while the window doesn't have focus, and restored when
+    ///   the focus comes back.
+    /// - **X11:** The grab is released while the window isn't viewable, e.g. when it's minimized,
+    ///   and restored when the focus comes back.
+    /// - **Wayland:** The compositor restores the grab by itself when the focus comes back.
+    ///
+    /// [`WindowEvent::PointerGrabChanged`]: crate::event::WindowEvent::PointerGrabChanged
     #[inline]
     pub fn set_cursor_grab(&self, mode: CursorGrabMode) -> Result<(), ExternalError> {
         self.window.set_cursor_grab(mode)
@@ -1309,7 +1844,8 @@ impl Window {
     ///
     /// ## Platform-specific
     ///
-    /// - **Windows:** The cursor is only hidden within the confines of the window.
+    /// - **Windows:** The cursor is only hidden within the confines of the window, and only while
+    ///   the window has focus.
     /// - **X11:** The cursor is only hidden within the confines of the window.
     /// - **Wayland:** The cursor is only hidden within the confines of the window.
     /// - **macOS:** The cursor is hidden as long as the window has input focus, even if the cursor is
@@ -1350,6 +1886,31 @@ impl Window {
         self.window.drag_resize_window(direction)
     }
 
+    /// Sets the regions of the window acting as the title bar or resize handles, for
+    /// applications drawing their own decorations.
+    ///
+    /// Pressing the left mouse button inside a region moves or resizes the window, like the
+    /// decorations of the system would, including the gestures of the window manager such as
+    /// snapping to the screen edges. Regions earlier in the slice take precedence, and an empty
+    /// slice clears them.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Double clicking the title bar toggles the maximized state, and clicking it
+    ///   with the right button shows the window menu. The presses on the regions aren't
+    ///   delivered as [`WindowEvent::PointerButton`].
+    /// - **Windows:** The regions are reported with `WM_NCHITTEST`, unless the callback set with
+    ///   [`WindowExtWindows::set_hittest_callback`] classified the point.
+    /// - **iOS / Android / Web / Orbital / macOS / X11:** Unsupported.
+    ///
+    /// [`WindowEvent::PointerButton`]: crate::event::WindowEvent::PointerButton
+    /// [`WindowExtWindows::set_hittest_callback`]: crate::platform::windows::WindowExtWindows::set_hittest_callback
+    #[cfg_attr(not(windows_platform), allow(rustdoc::broken_intra_doc_links))]
+    #[inline]
+    pub fn set_hittest_regions(&self, regions: &[HitTestRegion]) {
+        self.window.set_hittest_regions(regions)
+    }
+
     /// Modifies whether the window catches cursor events.
     ///
     /// If `true`, the window will catch the cursor events. If `false`, events are passed through
@@ -1362,6 +1923,27 @@ impl Window {
     pub fn set_cursor_hittest(&self, hittest: bool) -> Result<(), ExternalError> {
         self.window.set_cursor_hittest(hittest)
     }
+
+    /// Sets the areas of the window that catch the cursor events, as a list of [`Rect`].
+    ///
+    /// Events outside of the rectangles are passed through the window such that any other window
+    /// behind it receives them, and an empty slice makes the whole window transparent to them.
+    /// `None` makes the whole window catch the events again, which is the default.
+    ///
+    /// This replaces the region set by [`Window::set_cursor_hittest`], and the other way around.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** Requires the `SHAPE` extension.
+    /// - **Windows:** Always returns an [`ExternalError::NotSupported`]. `SetWindowRgn` also clips
+    ///   the drawing of the window, and `HTTRANSPARENT` only passes the events through to the
+    ///   windows of the same thread.
+    /// - **iOS / Android / Web / macOS / Orbital:** Always returns an
+    ///   [`ExternalError::NotSupported`].
+    #[inline]
+    pub fn set_input_region(&self, region: Option<&[Rect]>) -> Result<(), ExternalError> {
+        self.window.set_input_region(region)
+    }
 }
 
 /// Monitor info functions.
@@ -1509,12 +2091,99 @@ impl From<ResizeDirection> for CursorIcon {
     }
 }
 
+/// What a region of the window acts as, see [`Window::set_hittest_regions`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HitTestKind {
+    /// The title bar, which moves the window when dragged.
+    TitleBar,
+    /// A resize handle, which resizes the window in the given direction when dragged.
+    Resize(ResizeDirection),
+}
+
+/// A rectangle of the window's client area, given by its top-left corner and its size.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rect {
+    /// The position of the top-left corner, relative to the client area.
+    pub position: Position,
+    /// The size of the rectangle.
+    pub size: Size,
+}
+
+impl Rect {
+    /// Creates a rectangle from its top-left corner and its size.
+    pub fn new<P: Into<Position>, S: Into<Size>>(position: P, size: S) -> Self {
+        Self {
+            position: position.into(),
+            size: size.into(),
+        }
+    }
+
+    /// Whether the rectangle contains `position`, relative to the client area.
+    #[cfg_attr(not(any(wayland_platform, windows_platform)), allow(dead_code))]
+    pub(crate) fn contains(&self, position: PhysicalPosition<f64>, scale_factor: f64) -> bool {
+        let origin = self.position.to_physical::<f64>(scale_factor);
+        let size = self.size.to_physical::<f64>(scale_factor);
+        (origin.x..origin.x + size.width).contains(&position.x)
+            && (origin.y..origin.y + size.height).contains(&position.y)
+    }
+}
+
+/// A rectangle of the window's client area with a [`HitTestKind`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HitTestRegion {
+    pub rect: Rect,
+    pub kind: HitTestKind,
+}
+
+#[cfg_attr(not(any(wayland_platform, windows_platform)), allow(dead_code))]
+impl HitTestRegion {
+    /// The kind of the first region of `regions` containing `position`.
+    pub(crate) fn hit(
+        regions: &[Self],
+        position: PhysicalPosition<f64>,
+        scale_factor: f64,
+    ) -> Option<HitTestKind> {
+        regions
+            .iter()
+            .find(|region| region.rect.contains(position, scale_factor))
+            .map(|region| region.kind)
+    }
+}
+
+/// The action performed with the data of a drag when it's dropped.
+///
+/// The cursor shows the action agreed on during the drag, see [`Window::set_drag_action`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum DragAction {
+    /// The data is copied, and left in place by its source.
+    #[default]
+    Copy,
+    /// The data is moved, and deleted by its source after the drop.
+    Move,
+    /// The data is linked to, and left in place by its source.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **Wayland:** Unsupported, the drop is refused.
+    Link,
+}
+
 /// Fullscreen modes.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Fullscreen {
     Exclusive(VideoMode),
 
     /// Providing `None` to `Borderless` will fullscreen on the current monitor.
+    ///
+    /// The current monitor is the one containing the largest part of the window.
+    ///
+    /// ## Platform-specific
+    ///
+    /// - **X11:** When the window is split evenly, the primary monitor is preferred, otherwise
+    ///   the first of the tied monitors.
+    /// - **Windows:** The monitor is picked by `MonitorFromWindow`, which doesn't document how
+    ///   ties are broken.
+    /// - **Wayland:** The compositor picks the monitor.
     Borderless(Option<MonitorHandle>),
 }
 
@@ -1623,3 +2292,75 @@ impl ActivationToken {
         Self { _token }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dpi::LogicalPosition;
+
+    fn region(position: Position, size: Size, kind: HitTestKind) -> HitTestRegion {
+        HitTestRegion {
+            rect: Rect::new(position, size),
+            kind,
+        }
+    }
+
+    #[test]
+    fn rect_edges() {
+        let rect = Rect::new(PhysicalPosition::new(10, 20), PhysicalSize::new(100, 30));
+        assert!(rect.contains(PhysicalPosition::new(10.0, 20.0), 1.0));
+        assert!(rect.contains(PhysicalPosition::new(109.9, 49.9), 1.0));
+        assert!(!rect.contains(PhysicalPosition::new(110.0, 20.0), 1.0));
+        assert!(!rect.contains(PhysicalPosition::new(10.0, 50.0), 1.0));
+        assert!(!rect.contains(PhysicalPosition::new(9.9, 20.0), 1.0));
+        assert!(!rect.contains(PhysicalPosition::new(10.0, 19.9), 1.0));
+    }
+
+    #[test]
+    fn rect_scale_factor() {
+        let logical = Rect::new(
+            LogicalPosition::new(10.0, 10.0),
+            LogicalSize::new(20.0, 20.0),
+        );
+        assert!(logical.contains(PhysicalPosition::new(20.0, 20.0), 2.0));
+        assert!(logical.contains(PhysicalPosition::new(59.0, 59.0), 2.0));
+        assert!(!logical.contains(PhysicalPosition::new(60.0, 20.0), 2.0));
+        assert!(!logical.contains(PhysicalPosition::new(15.0, 15.0), 2.0));
+
+        let physical = Rect::new(PhysicalPosition::new(10, 10), PhysicalSize::new(20, 20));
+        assert!(physical.contains(PhysicalPosition::new(15.0, 15.0), 2.0));
+        assert!(!physical.contains(PhysicalPosition::new(30.0, 30.0), 2.0));
+    }
+
+    #[test]
+    fn hittest_first_region_wins() {
+        let regions = [
+            region(
+                PhysicalPosition::new(0, 0).into(),
+                PhysicalSize::new(10, 10).into(),
+                HitTestKind::Resize(ResizeDirection::NorthWest),
+            ),
+            region(
+                PhysicalPosition::new(0, 0).into(),
+                PhysicalSize::new(100, 30).into(),
+                HitTestKind::TitleBar,
+            ),
+        ];
+        assert_eq!(
+            HitTestRegion::hit(&regions, PhysicalPosition::new(5.0, 5.0), 1.0),
+            Some(HitTestKind::Resize(ResizeDirection::NorthWest))
+        );
+        assert_eq!(
+            HitTestRegion::hit(&regions, PhysicalPosition::new(50.0, 5.0), 1.0),
+            Some(HitTestKind::TitleBar)
+        );
+        assert_eq!(
+            HitTestRegion::hit(&regions, PhysicalPosition::new(50.0, 50.0), 1.0),
+            None
+        );
+        assert_eq!(
+            HitTestRegion::hit(&[], PhysicalPosition::new(5.0, 5.0), 1.0),
+            None
+        );
+    }
+}